@@ -8,35 +8,47 @@ let s:save_cpo = &cpoptions
 set cpoptions&vim
 
 let s:palette = {}
-let s:palette.input = { 'ctermfg': '104', 'guifg': '#929be5', 'ctermbg': '236', 'guibg': '#292c3f', 'gui': 'bold', 'cterm': 'bold' }
-let s:palette.display = { 'ctermfg': '146', 'guifg': '#9ea3c0', 'ctermbg': '237', 'guibg': '#32364c', 'gui': 'NONE', 'cterm': 'NONE' }
-let s:palette.spinner = { 'ctermfg': '104', 'guifg': '#929be5', 'ctermbg': '236', 'guibg': '#292c3f', 'gui': 'bold', 'cterm': 'bold' }
-let s:palette.search_text = { 'ctermfg': '146', 'guifg': '#9ea3c0', 'ctermbg': '236', 'guibg': '#292c3f', 'gui': 'NONE', 'cterm': 'NONE' }
-let s:palette.preview = { 'ctermfg': '146', 'guifg': '#9ea3c0', 'ctermbg': '61', 'guibg': '#363e7f', 'gui': 'NONE', 'cterm': 'NONE' }
-let s:palette.selected = { 'ctermfg': '73', 'guifg': '#59b6b6', 'gui': 'bold', 'cterm': 'bold' }
-let s:palette.current_selection = { 'ctermfg': '146', 'guifg': '#bdc3e6', 'gui': 'bold', 'cterm': 'bold' }
-hi ClapMatches guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapMatches1 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapMatches2 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapMatches3 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapMatches4 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapMatches5 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapMatches6 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapMatches7 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapMatches8 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapFuzzyMatches1 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapFuzzyMatches2 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapFuzzyMatches3 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapFuzzyMatches4 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapFuzzyMatches5 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapFuzzyMatches6 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapFuzzyMatches7 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapFuzzyMatches8 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapFuzzyMatches9 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapFuzzyMatches10 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapFuzzyMatches11 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapFuzzyMatches12 guifg=#73c1a9 ctermfg=79 guibg=NONE cterm=NONE gui=bold cterm=bold
-hi ClapNoMatchesFound guifg=#ac8b83 ctermfg=138 guibg=NONE cterm=NONE gui=bold cterm=bold
+if &background ==# 'light'
+  let s:palette.input = { 'ctermfg': '61', 'guifg': '#3c4c8d', 'ctermbg': '189', 'guibg': '#c6c7e0', 'gui': 'bold', 'cterm': 'bold' }
+  let s:palette.display = { 'ctermfg': '60', 'guifg': '#464b65', 'ctermbg': '146', 'guibg': '#babbd8', 'gui': 'NONE', 'cterm': 'NONE' }
+  let s:palette.spinner = { 'ctermfg': '61', 'guifg': '#3c4c8d', 'ctermbg': '189', 'guibg': '#c6c7e0', 'gui': 'bold', 'cterm': 'bold' }
+  let s:palette.search_text = { 'ctermfg': '60', 'guifg': '#464b65', 'ctermbg': '189', 'guibg': '#c6c7e0', 'gui': 'NONE', 'cterm': 'NONE' }
+  let s:palette.preview = { 'ctermfg': '60', 'guifg': '#464b65', 'ctermbg': '147', 'guibg': '#a8a9f0', 'gui': 'NONE', 'cterm': 'NONE' }
+  let s:palette.selected = { 'ctermfg': '23', 'guifg': '#005556', 'gui': 'bold', 'cterm': 'bold' }
+  let s:palette.current_selection = { 'ctermfg': '236', 'guifg': '#29314c', 'gui': 'bold', 'cterm': 'bold' }
+  hi ClapMatches guifg=#004b39 ctermfg=23 guibg=NONE ctermbg=NONE gui=bold cterm=bold
+  hi ClapNoMatchesFound guifg=#74564f ctermfg=95 guibg=NONE ctermbg=NONE gui=bold cterm=bold
+else
+  let s:palette.input = { 'ctermfg': '104', 'guifg': '#929be5', 'ctermbg': '236', 'guibg': '#2b2d40', 'gui': 'bold', 'cterm': 'bold' }
+  let s:palette.display = { 'ctermfg': '146', 'guifg': '#9ea3c0', 'ctermbg': '237', 'guibg': '#33364d', 'gui': 'NONE', 'cterm': 'NONE' }
+  let s:palette.spinner = { 'ctermfg': '104', 'guifg': '#929be5', 'ctermbg': '236', 'guibg': '#2b2d40', 'gui': 'bold', 'cterm': 'bold' }
+  let s:palette.search_text = { 'ctermfg': '146', 'guifg': '#9ea3c0', 'ctermbg': '236', 'guibg': '#2b2d40', 'gui': 'NONE', 'cterm': 'NONE' }
+  let s:palette.preview = { 'ctermfg': '146', 'guifg': '#9ea3c0', 'ctermbg': '60', 'guibg': '#363e7a', 'gui': 'NONE', 'cterm': 'NONE' }
+  let s:palette.selected = { 'ctermfg': '73', 'guifg': '#59b6b6', 'gui': 'bold', 'cterm': 'bold' }
+  let s:palette.current_selection = { 'ctermfg': '189', 'guifg': '#bdc3e6', 'gui': 'bold', 'cterm': 'bold' }
+  hi ClapMatches guifg=#73c1a9 ctermfg=79 guibg=NONE ctermbg=NONE gui=bold cterm=bold
+  hi ClapNoMatchesFound guifg=#ac8b83 ctermfg=138 guibg=NONE ctermbg=NONE gui=bold cterm=bold
+endif
+hi! link ClapMatches1 ClapMatches
+hi! link ClapMatches2 ClapMatches
+hi! link ClapMatches3 ClapMatches
+hi! link ClapMatches4 ClapMatches
+hi! link ClapMatches5 ClapMatches
+hi! link ClapMatches6 ClapMatches
+hi! link ClapMatches7 ClapMatches
+hi! link ClapMatches8 ClapMatches
+hi! link ClapFuzzyMatches1 ClapMatches
+hi! link ClapFuzzyMatches2 ClapMatches
+hi! link ClapFuzzyMatches3 ClapMatches
+hi! link ClapFuzzyMatches4 ClapMatches
+hi! link ClapFuzzyMatches5 ClapMatches
+hi! link ClapFuzzyMatches6 ClapMatches
+hi! link ClapFuzzyMatches7 ClapMatches
+hi! link ClapFuzzyMatches8 ClapMatches
+hi! link ClapFuzzyMatches9 ClapMatches
+hi! link ClapFuzzyMatches10 ClapMatches
+hi! link ClapFuzzyMatches11 ClapMatches
+hi! link ClapFuzzyMatches12 ClapMatches
 let g:clap#themes#dogrun#palette = s:palette
 
 let &cpoptions = s:save_cpo
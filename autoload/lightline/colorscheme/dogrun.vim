@@ -6,23 +6,44 @@
 
 let s:p = {'normal': {}, 'inactive': {}, 'insert': {}, 'replace': {}, 'visual': {}, 'tabline': {}}
 
-let s:p.normal.left = [[['#222433', 235], ['#929be5', 104]], [['#929be5', 104], ['#282a3a', 235]]]
-let s:p.normal.middle = [[['#4b4e6d', 60], ['#282a3a', 235]]]
-let s:p.normal.right = [[['#222433', 235], ['#929be5', 104]], [['#929be5', 104], ['#282a3a', 235]]]
-let s:p.normal.error = [[['#ff9494', 210], ['#282a3a', 235]]]
-let s:p.normal.warning = [[['#ac8b83', 138], ['#282a3a', 235]]]
-let s:p.inactive.left = [[['#4b4e6d', 60], ['#282a3a', 235]], [['#4b4e6d', 60], ['#282a3a', 235]]]
-let s:p.inactive.middle = [[['#4b4e6d', 60], ['#282a3a', 235]]]
-let s:p.inactive.right = [[['#4b4e6d', 60], ['#282a3a', 235]], [['#4b4e6d', 60], ['#282a3a', 235]]]
-let s:p.insert.left = [[['#222433', 235], ['#73c1a9', 79]], [['#73c1a9', 79], ['#282a3a', 235]]]
-let s:p.insert.right = [[['#222433', 235], ['#73c1a9', 79]], [['#73c1a9', 79], ['#282a3a', 235]]]
-let s:p.visual.left = [[['#222433', 235], ['#b871b8', 133]], [['#b871b8', 133], ['#282a3a', 235]]]
-let s:p.visual.right = [[['#222433', 235], ['#b871b8', 133]], [['#b871b8', 133], ['#282a3a', 235]]]
-let s:p.replace.left = [[['#222433', 235], ['#ff9494', 210]], [['#ff9494', 210], ['#282a3a', 235]]]
-let s:p.replace.right = [[['#222433', 235], ['#ff9494', 210]], [['#ff9494', 210], ['#282a3a', 235]]]
-let s:p.tabline.left = [[['#4b4e6d', 60], ['#282a3a', 235]]]
-let s:p.tabline.tabsel = [[['#222433', 235], ['#929be5', 104]]]
-let s:p.tabline.middle = [[['#4b4e6d', 60], ['#282a3a', 235]]]
-let s:p.tabline.right = [[['#4b4e6d', 60], ['#282a3a', 235]]]
+if &background ==# 'light'
+  let s:p.normal.left = [[['#d3d4e8', 189], ['#3c4c8d', 61]], [['#3c4c8d', 61], ['#c9cbdf', 189]]]
+  let s:p.normal.middle = [[['#999cbe', 146], ['#c9cbdf', 189]]]
+  let s:p.normal.right = [[['#d3d4e8', 189], ['#3c4c8d', 61]], [['#3c4c8d', 61], ['#c9cbdf', 189]]]
+  let s:p.normal.error = [[['#7a1e28', 52], ['#c9cbdf', 189]]]
+  let s:p.normal.warning = [[['#74564f', 95], ['#c9cbdf', 189]]]
+  let s:p.inactive.left = [[['#999cbe', 146], ['#c9cbdf', 189]], [['#999cbe', 146], ['#c9cbdf', 189]]]
+  let s:p.inactive.middle = [[['#999cbe', 146], ['#c9cbdf', 189]]]
+  let s:p.inactive.right = [[['#999cbe', 146], ['#c9cbdf', 189]], [['#999cbe', 146], ['#c9cbdf', 189]]]
+  let s:p.insert.left = [[['#d3d4e8', 189], ['#004b39', 23]], [['#004b39', 23], ['#c9cbdf', 189]]]
+  let s:p.insert.right = [[['#d3d4e8', 189], ['#004b39', 23]], [['#004b39', 23], ['#c9cbdf', 189]]]
+  let s:p.visual.left = [[['#d3d4e8', 189], ['#8f4b90', 96]], [['#8f4b90', 96], ['#c9cbdf', 189]]]
+  let s:p.visual.right = [[['#d3d4e8', 189], ['#8f4b90', 96]], [['#8f4b90', 96], ['#c9cbdf', 189]]]
+  let s:p.replace.left = [[['#d3d4e8', 189], ['#7a1e28', 52]], [['#7a1e28', 52], ['#c9cbdf', 189]]]
+  let s:p.replace.right = [[['#d3d4e8', 189], ['#7a1e28', 52]], [['#7a1e28', 52], ['#c9cbdf', 189]]]
+  let s:p.tabline.left = [[['#999cbe', 146], ['#c9cbdf', 189]]]
+  let s:p.tabline.tabsel = [[['#d3d4e8', 189], ['#3c4c8d', 61]]]
+  let s:p.tabline.middle = [[['#999cbe', 146], ['#c9cbdf', 189]]]
+  let s:p.tabline.right = [[['#999cbe', 146], ['#c9cbdf', 189]]]
+else
+  let s:p.normal.left = [[['#222433', 235], ['#929be5', 104]], [['#929be5', 104], ['#292b3b', 236]]]
+  let s:p.normal.middle = [[['#4c506e', 60], ['#292b3b', 236]]]
+  let s:p.normal.right = [[['#222433', 235], ['#929be5', 104]], [['#929be5', 104], ['#292b3b', 236]]]
+  let s:p.normal.error = [[['#ff9494', 210], ['#292b3b', 236]]]
+  let s:p.normal.warning = [[['#ac8b83', 138], ['#292b3b', 236]]]
+  let s:p.inactive.left = [[['#4c506e', 60], ['#292b3b', 236]], [['#4c506e', 60], ['#292b3b', 236]]]
+  let s:p.inactive.middle = [[['#4c506e', 60], ['#292b3b', 236]]]
+  let s:p.inactive.right = [[['#4c506e', 60], ['#292b3b', 236]], [['#4c506e', 60], ['#292b3b', 236]]]
+  let s:p.insert.left = [[['#222433', 235], ['#73c1a9', 79]], [['#73c1a9', 79], ['#292b3b', 236]]]
+  let s:p.insert.right = [[['#222433', 235], ['#73c1a9', 79]], [['#73c1a9', 79], ['#292b3b', 236]]]
+  let s:p.visual.left = [[['#222433', 235], ['#b871b8', 133]], [['#b871b8', 133], ['#292b3b', 236]]]
+  let s:p.visual.right = [[['#222433', 235], ['#b871b8', 133]], [['#b871b8', 133], ['#292b3b', 236]]]
+  let s:p.replace.left = [[['#222433', 235], ['#ff9494', 210]], [['#ff9494', 210], ['#292b3b', 236]]]
+  let s:p.replace.right = [[['#222433', 235], ['#ff9494', 210]], [['#ff9494', 210], ['#292b3b', 236]]]
+  let s:p.tabline.left = [[['#4c506e', 60], ['#292b3b', 236]]]
+  let s:p.tabline.tabsel = [[['#222433', 235], ['#929be5', 104]]]
+  let s:p.tabline.middle = [[['#4c506e', 60], ['#292b3b', 236]]]
+  let s:p.tabline.right = [[['#4c506e', 60], ['#292b3b', 236]]]
+endif
 
 let g:lightline#colorscheme#dogrun#palette = lightline#colorscheme#flatten(s:p)
@@ -4,10 +4,6 @@
 " License: MIT
 "   Copyright (c) 2020 wadackel
 
-if &background !=# 'dark'
-  set background=dark
-endif
-
 if exists('g:colors_name')
   hi clear
 endif
@@ -18,543 +14,1083 @@ endif
 
 let g:colors_name = 'dogrun'
 
-hi Normal guifg=#9ea3c0 ctermfg=146 guibg=#222433 ctermbg=235
-hi Delimiter guifg=#8085a6 ctermfg=103
-hi NonText guifg=#363859 ctermfg=60 guibg=NONE ctermbg=NONE
-hi VertSplit guifg=#32364c ctermfg=237 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
-hi LineNr guifg=#32364c ctermfg=237 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
-hi EndOfBuffer guifg=#363859 ctermfg=60 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
-hi Comment guifg=#545c8c ctermfg=60 gui=NONE cterm=NONE
-hi Cursor guifg=#222433 ctermfg=235 guibg=#9ea3c0 ctermbg=146
-hi CursorIM guifg=#222433 ctermfg=235 guibg=#9ea3c0 ctermbg=146
-hi SignColumn guifg=#545c8c ctermfg=60 guibg=NONE ctermbg=NONE
-hi ColorColumn guibg=#2a2c3f ctermbg=236 gui=NONE cterm=NONE
-hi CursorColumn guibg=#2a2c3f ctermbg=236 gui=NONE cterm=NONE
-hi CursorLine guibg=#2a2c3f ctermbg=236 gui=NONE cterm=NONE
-hi CursorLineNr guifg=#535f98 ctermfg=61 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
-hi Conceal guifg=#ac8b83 ctermfg=138 guibg=#222433 ctermbg=235 gui=NONE cterm=NONE
-hi NormalFloat guifg=#8085a6 ctermfg=103 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
-hi FloatBorder guifg=#545c8c ctermfg=60 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
-hi WinSeparator guifg=#363859 ctermfg=60 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
-hi Folded guifg=#666c99 ctermfg=60 guibg=#32364c ctermbg=237 gui=NONE cterm=NONE
-hi FoldColumn guifg=#32364c ctermfg=237 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
-hi MatchParen guibg=#2f3147 ctermbg=236
-hi Directory guifg=#929be5 ctermfg=104
-hi Underlined gui=underline cterm=underline
-hi String guifg=#7cbe8c ctermfg=108
-hi Statement guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
-hi Label guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
-hi Function guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
-hi Constant guifg=#73c1a9 ctermfg=79
-hi Boolean guifg=#73c1a9 ctermfg=79
-hi Number guifg=#73c1a9 ctermfg=79
-hi Float guifg=#73c1a9 ctermfg=79
-hi Title guifg=#a8a384 ctermfg=144 gui=bold cterm=bold
-hi Keyword guifg=#ac8b83 ctermfg=138
-hi Identifier guifg=#ac8b83 ctermfg=138
-hi Exception guifg=#a8a384 ctermfg=144
-hi Type guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
-hi TypeDef guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
-hi PreProc guifg=#929be5 ctermfg=104
-hi Special guifg=#b871b8 ctermfg=133
-hi SpecialKey guifg=#b871b8 ctermfg=133
-hi SpecialChar guifg=#b871b8 ctermfg=133
-hi SpecialComment guifg=#b871b8 ctermfg=133
-hi Error guifg=#ff9494 ctermfg=210 guibg=#222433 ctermbg=235 gui=bold cterm=bold
-hi ErrorMsg guifg=#ff9494 ctermfg=210 guibg=NONE ctermbg=NONE gui=bold cterm=bold
-hi WarningMsg guifg=#ac8b83 ctermfg=138 gui=bold cterm=bold
-hi MoreMsg guifg=#73c1a9 ctermfg=79
-hi ModeMsg guifg=#73c1a9 ctermfg=79
-hi Debug guifg=#9ea3c0 ctermfg=146
-hi Todo guifg=#a8a384 ctermfg=144 guibg=NONE ctermbg=NONE gui=bold cterm=bold
-hi Pmenu guifg=#9ea3c0 ctermfg=146 guibg=#32364c ctermbg=237
-hi PmenuSel guifg=#9ea3c0 ctermfg=146 guibg=#363e7f ctermbg=61 gui=NONE cterm=NONE
-hi PmenuMatch guifg=#929be5 ctermfg=104 gui=bold cterm=bold
-hi PmenuSbar guibg=#292c3f ctermbg=236
-hi PmenuThumb guibg=#464f7f ctermbg=60
-hi Visual guibg=#363e7f ctermbg=61 gui=NONE cterm=NONE
-hi Search guifg=#a6afff ctermfg=147 guibg=#6471e5 ctermbg=63
-hi CurSearch guifg=#a6afff ctermfg=147 guibg=#6471e5 ctermbg=63
-hi IncSearch guifg=#a4b2ff ctermfg=147 guibg=#4754cb ctermbg=62 gui=NONE cterm=NONE
-hi Question guifg=#73c1a9 ctermfg=79 gui=bold cterm=bold
-hi WildMenu guifg=#222433 ctermfg=235 guibg=#929be5 ctermbg=104
-hi SpellBad guifg=#ff9494 ctermfg=210 gui=underline cterm=underline
-hi SpellCap gui=underline cterm=underline
-hi SpellLocal guifg=#ff9494 ctermfg=210 gui=underline cterm=underline
-hi SpellRare guifg=#a8a384 ctermfg=144 gui=underline cterm=underline
-hi Added guifg=NONE ctermfg=NONE guibg=#1c394b ctermbg=237 gui=NONE cterm=NONE
-hi Removed guifg=#775c77 ctermfg=96 guibg=#513351 ctermbg=53 gui=NONE cterm=NONE
-hi Changed guifg=NONE ctermfg=NONE guibg=#1e3930 ctermbg=236 gui=NONE cterm=NONE
-hi DiffAdd guifg=NONE ctermfg=NONE guibg=#1c394b ctermbg=237
-hi DiffChange guifg=NONE ctermfg=NONE guibg=#1e3930 ctermbg=236
-hi DiffDelete guifg=#775c77 ctermfg=96 guibg=#513351 ctermbg=53
-hi DiffText guifg=NONE ctermfg=NONE guibg=#1f4a3c ctermbg=23
-hi QuickFixLine guifg=#9ea3c0 ctermfg=146 guibg=#363e7f ctermbg=61
-hi StatusLine guifg=#757aa5 ctermfg=103 guibg=#2a2c3f ctermbg=236 gui=bold cterm=bold
-hi StatusLineTerm guifg=#757aa5 ctermfg=103 guibg=#2a2c3f ctermbg=236 gui=bold cterm=bold
-hi StatusLineNC guifg=#4b4e6d ctermfg=60 guibg=#282a3a ctermbg=235 gui=NONE cterm=NONE
-hi StatusLineTermNC guifg=#4b4e6d ctermfg=60 guibg=#282a3a ctermbg=235 gui=NONE cterm=NONE
-hi TabLine guifg=#757aa5 ctermfg=103 guibg=#2a2c3f ctermbg=236 gui=NONE cterm=NONE
-hi TabLineFill guifg=#757aa5 ctermfg=103 guibg=#2a2c3f ctermbg=236 gui=NONE cterm=NONE
-hi TabLineSel guifg=#222433 ctermfg=235 guibg=#929be5 ctermbg=104 gui=bold cterm=bold
-hi qfFileName guifg=#73c1a9 ctermfg=79
-hi qfLineNr guifg=#545c8c ctermfg=60
-hi DiagnosticError guifg=#ff9494 ctermfg=210
-hi DiagnosticVirtualTextError guifg=#ff9494 ctermfg=210 gui=bold cterm=bold
-hi DiagnosticUnderlineError guifg=#ff9494 ctermfg=210 gui=underline cterm=underline
-hi DiagnosticWarn guifg=#ac8b83 ctermfg=138
-hi DiagnosticVirtualTextWarn guifg=#ac8b83 ctermfg=138 gui=bold cterm=bold
-hi DiagnosticUnderlineWarn guifg=#ac8b83 ctermfg=138 gui=underline cterm=underline
-hi DiagnosticInfo guifg=#82dabf ctermfg=115
-hi DiagnosticVirtualTextInfo guifg=#545c8c ctermfg=60 gui=bold cterm=bold
-hi DiagnosticUnderlineInfo gui=underline cterm=underline
-hi DiagnosticHint guifg=#82dabf ctermfg=115
-hi DiagnosticOk guifg=#82dabf ctermfg=115
-hi DiagnosticVirtualTextHint guifg=#545c8c ctermfg=60 gui=bold cterm=bold
-hi DiagnosticUnderlineHint gui=underline cterm=underline
-hi LspSignatureActiveParameter gui=italic cterm=italic
-hi LspReferenceText guibg=#2f3147 ctermbg=236
-hi LspReferenceRead guibg=#2f3147 ctermbg=236
-hi LspReferenceWrite guibg=#2f3147 ctermbg=236
-hi htmlTag guifg=#8085a6 ctermfg=103
-hi htmlEndTag guifg=#8085a6 ctermfg=103
-hi htmlSpecialTagName guifg=#ac8b83 ctermfg=138
-hi htmlArg guifg=#8085a6 ctermfg=103
-hi jsonQuote guifg=#8085a6 ctermfg=103
-hi yamlBlockMappingKey guifg=#929be5 ctermfg=104
-hi yamlAnchor guifg=#b871b8 ctermfg=133
-hi pythonStatement guifg=#ac8b83 ctermfg=138
-hi pythonBuiltin guifg=#59b6b6 ctermfg=73
-hi pythonRepeat guifg=#ac8b83 ctermfg=138
-hi pythonOperator guifg=#ac8b83 ctermfg=138
-hi pythonDecorator guifg=#b871b8 ctermfg=133
-hi pythonDecoratorName guifg=#b871b8 ctermfg=133
-hi zshVariableDef guifg=#929be5 ctermfg=104
-hi zshFunction guifg=#929be5 ctermfg=104
-hi zshKSHFunction guifg=#929be5 ctermfg=104
-hi cPreCondit guifg=#ac8b83 ctermfg=138
-hi cIncluded guifg=#b871b8 ctermfg=133
-hi cStorageClass guifg=#ac8b83 ctermfg=138
-hi cppStructure guifg=#b871b8 ctermfg=133
-hi cppSTLnamespace guifg=#ac8b83 ctermfg=138
-hi csStorage guifg=#ac8b83 ctermfg=138
-hi csModifier guifg=#929be5 ctermfg=104
-hi csClass guifg=#929be5 ctermfg=104
-hi csClassType guifg=#b871b8 ctermfg=133
-hi csNewType guifg=#ac8b83 ctermfg=138
-hi rubyConstant guifg=#ac8b83 ctermfg=138
-hi rubySymbol guifg=#929be5 ctermfg=104
-hi rubyBlockParameter guifg=#929be5 ctermfg=104
-hi rubyClassName guifg=#b871b8 ctermfg=133
-hi rubyInstanceVariable guifg=#b871b8 ctermfg=133
-hi typescriptImport guifg=#929be5 ctermfg=104
-hi typescriptDocRef guifg=#545c8c ctermfg=60 gui=underline cterm=underline
-hi mkdHeading guifg=#545c8c ctermfg=60
-hi mkdLink guifg=#929be5 ctermfg=104
-hi mkdCode guifg=#929be5 ctermfg=104
-hi mkdCodeStart guifg=#929be5 ctermfg=104
-hi mkdCodeEnd guifg=#929be5 ctermfg=104
-hi mkdCodeDelimiter guifg=#929be5 ctermfg=104
-hi tomlTable guifg=#929be5 ctermfg=104
-hi rustModPath guifg=#929be5 ctermfg=104
-hi rustTypedef guifg=#929be5 ctermfg=104
-hi rustStructure guifg=#929be5 ctermfg=104
-hi rustMacro guifg=#929be5 ctermfg=104
-hi rustExternCrate guifg=#929be5 ctermfg=104
-hi graphqlStructure guifg=#b871b8 ctermfg=133
-hi graphqlDirective guifg=#b871b8 ctermfg=133
-hi graphqlName guifg=#929be5 ctermfg=104
-hi graphqlTemplateString guifg=#9ea3c0 ctermfg=146
-hi vimfilerOpenedFile guifg=#6f78be ctermfg=104
-hi vimfilerClosedFile guifg=#6f78be ctermfg=104
-hi vimfilerNonMark guifg=#73c1a9 ctermfg=79
-hi vimfilerLeaf guifg=#73c1a9 ctermfg=79
-hi DefxIconsMarkIcon guifg=#6f78be ctermfg=104 gui=NONE cterm=NONE
-hi DefxIconsDirectory guifg=#6f78be ctermfg=104 gui=NONE cterm=NONE
-hi DefxIconsParentDirectory guifg=#6f78be ctermfg=104 gui=NONE cterm=NONE
-hi DefxIconsSymlinkDirectory guifg=#73c1a9 ctermfg=79 gui=NONE cterm=NONE
-hi DefxIconsOpenedTreeIcon guifg=#6f78be ctermfg=104 gui=NONE cterm=NONE
-hi DefxIconsNestedTreeIcon guifg=#6f78be ctermfg=104 gui=NONE cterm=NONE
-hi DefxIconsClosedTreeIcon guifg=#6f78be ctermfg=104 gui=NONE cterm=NONE
-hi Defx_git_Untracked guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
-hi Defx_git_Ignored guifg=#545c8c ctermfg=60 gui=NONE cterm=NONE
-hi Defx_git_Unknown guifg=#545c8c ctermfg=60 gui=NONE cterm=NONE
-hi Defx_git_Renamed guifg=#1e3930 ctermfg=236
-hi Defx_git_Modified guifg=#1e3930 ctermfg=236
-hi Defx_git_Unmerged guifg=#b871b8 ctermfg=133
-hi Defx_git_Deleted guifg=#513351 ctermfg=53
-hi Defx_git_Staged guifg=#73c1a9 ctermfg=79
-hi NvimTreeSymlink guifg=#5b9a87 ctermfg=72 gui=NONE cterm=NONE
-hi NvimTreeSymlinkFolderName guifg=#5b9a87 ctermfg=72 gui=NONE cterm=NONE
-hi NvimTreeFolderName guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
-hi NvimTreeRootFolder guifg=#464c79 ctermfg=60 gui=bold cterm=bold
-hi NvimTreeFolderIcon guifg=#6f78be ctermfg=104 gui=NONE cterm=NONE
-hi NvimTreeFileIcon guifg=#6f78be ctermfg=104 gui=NONE cterm=NONE
-hi NvimTreeEmptyFolderName guifg=#545c8c ctermfg=60 gui=NONE cterm=NONE
-hi NvimTreeOpenedFolderName guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
-hi NvimTreeExecFile guifg=#8085a6 ctermfg=103 gui=NONE cterm=NONE
-hi NvimTreeOpenedHL guifg=#8085a6 ctermfg=103 gui=NONE cterm=NONE
-hi NvimTreeSpecialFile guifg=#8085a6 ctermfg=103 gui=bold cterm=bold
-hi NvimTreeImageFile guifg=#8085a6 ctermfg=103 gui=NONE cterm=NONE
-hi NvimTreeIndentMarker guifg=#464c79 ctermfg=60 gui=NONE cterm=NONE
-hi NvimTreeModifiedIcon guifg=#9ea3c0 ctermfg=146 gui=NONE cterm=NONE
-hi NvimTreeGitDirtyIcon guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
-hi NvimTreeGitStagedIcon guifg=#7cbe8c ctermfg=108 gui=NONE cterm=NONE
-hi NvimTreeGitMergeIcon guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
-hi NvimTreeGitRenamedIcon guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
-hi NvimTreeGitNewIcon guifg=#73c1a9 ctermfg=79 gui=NONE cterm=NONE
-hi NvimTreeGitDeletedIcon guifg=#1f4a3c ctermfg=23 gui=NONE cterm=NONE
-hi NvimTreeWindowPicker guifg=#222433 ctermfg=235 guibg=#929be5 ctermbg=104 gui=bold cterm=bold
-hi NvimTreeNormal guifg=#8085a6 ctermfg=103 gui=NONE cterm=NONE
-hi NvimTreeLiveFilterPrefix guifg=#5b9a87 ctermfg=72 gui=NONE cterm=NONE
-hi NvimTreeLiveFilterValue guifg=#73c1a9 ctermfg=79 gui=NONE cterm=NONE
-hi NvimTreeBookmarkIcon guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
-hi FylerBlue guifg=#589ec6 ctermfg=74 gui=NONE cterm=NONE
-hi FylerGreen guifg=#73c1a9 ctermfg=79 gui=NONE cterm=NONE
-hi FylerGrey guifg=#9ea3c0 ctermfg=146 gui=NONE cterm=NONE
-hi FylerRed guifg=#ff9494 ctermfg=210 gui=NONE cterm=NONE
-hi FylerYellow guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
-hi FylerNormal guifg=#8085a6 ctermfg=103 gui=NONE cterm=NONE
-hi FylerNormalNC guifg=#8085a6 ctermfg=103 gui=NONE cterm=NONE
-hi FylerBorder guifg=#545c8c ctermfg=60 gui=NONE cterm=NONE
-hi FylerFSDirectoryIcon guifg=#6f78be ctermfg=104 gui=bold cterm=bold
-hi FylerFSDirectoryName guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
-hi FylerFSFile guifg=#8085a6 ctermfg=103 gui=NONE cterm=NONE
-hi FylerFSLink guifg=#5b9a87 ctermfg=72 gui=NONE cterm=NONE
-hi FylerGitAdded guifg=#9ea3c0 ctermfg=146 gui=NONE cterm=NONE
-hi FylerGitConflict gui=NONE cterm=NONE
-hi FylerGitDeleted guifg=#1f4a3c ctermfg=23 gui=NONE cterm=NONE
-hi FylerGitIgnored gui=NONE cterm=NONE
-hi FylerGitModified gui=NONE cterm=NONE
-hi FylerGitRenamed guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
-hi FylerGitStaged guifg=#7cbe8c ctermfg=108 gui=NONE cterm=NONE
-hi FylerGitUnstaged gui=NONE cterm=NONE
-hi FylerGitUntracked gui=NONE cterm=NONE
-hi FylerIndentMarker guifg=#545c8c ctermfg=60 gui=NONE cterm=NONE
-hi FylerWinPick gui=NONE cterm=NONE
-hi EdaNormal guifg=#8085a6 ctermfg=103 gui=NONE cterm=NONE
-hi EdaNormalNC guifg=#8085a6 ctermfg=103 gui=NONE cterm=NONE
-hi EdaBorder guifg=#545c8c ctermfg=60 gui=NONE cterm=NONE
-hi EdaTitle guifg=#929be5 ctermfg=104 gui=bold cterm=bold
-hi EdaCursorLine guibg=#2a2c3f ctermbg=236 gui=NONE cterm=NONE
-hi EdaIndentMarker guifg=#464c79 ctermfg=60 gui=NONE cterm=NONE
-hi EdaRootName guifg=#464c79 ctermfg=60 gui=bold cterm=bold
-hi EdaDivider guifg=#545c8c ctermfg=60 gui=NONE cterm=NONE
-hi EdaFilterIndicator guifg=#5b9a87 ctermfg=72 gui=NONE cterm=NONE
-hi EdaDirectoryName guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
-hi EdaDirectoryIcon guifg=#6f78be ctermfg=104 gui=NONE cterm=NONE
-hi EdaOpenedDirectoryName guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
-hi EdaEmptyDirectoryName guifg=#545c8c ctermfg=60 gui=NONE cterm=NONE
-hi EdaFileName guifg=#8085a6 ctermfg=103 gui=NONE cterm=NONE
-hi EdaFileIcon guifg=#6f78be ctermfg=104 gui=NONE cterm=NONE
-hi EdaSymlink guifg=#5b9a87 ctermfg=72 gui=NONE cterm=NONE
-hi EdaBrokenSymlink guifg=#ff9494 ctermfg=210 gui=NONE cterm=NONE
-hi EdaSymlinkTarget guifg=#545c8c ctermfg=60 gui=NONE cterm=NONE
-hi EdaErrorNode guifg=#ff9494 ctermfg=210 gui=NONE cterm=NONE
-hi EdaLoadingNode guifg=#545c8c ctermfg=60 gui=italic cterm=italic
-hi EdaOpenedFile guifg=#8085a6 ctermfg=103 gui=NONE cterm=NONE
-hi EdaModifiedFile guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
-hi EdaGitUntracked guifg=#73c1a9 ctermfg=79 gui=NONE cterm=NONE
-hi EdaGitUntrackedIcon guifg=#73c1a9 ctermfg=79 gui=NONE cterm=NONE
-hi EdaGitAdded guifg=#73c1a9 ctermfg=79 gui=NONE cterm=NONE
-hi EdaGitAddedIcon guifg=#73c1a9 ctermfg=79 gui=NONE cterm=NONE
-hi EdaGitModified guifg=#beb996 ctermfg=144 gui=NONE cterm=NONE
-hi EdaGitModifiedIcon guifg=#beb996 ctermfg=144 gui=NONE cterm=NONE
-hi EdaGitDeleted guifg=#bf74bf ctermfg=176 gui=NONE cterm=NONE
-hi EdaGitDeletedIcon guifg=#bf74bf ctermfg=176 gui=NONE cterm=NONE
-hi EdaGitRenamed guifg=#beb996 ctermfg=144 gui=NONE cterm=NONE
-hi EdaGitRenamedIcon guifg=#beb996 ctermfg=144 gui=NONE cterm=NONE
-hi EdaGitStaged guifg=#7cbe8c ctermfg=108 gui=NONE cterm=NONE
-hi EdaGitStagedIcon guifg=#7cbe8c ctermfg=108 gui=NONE cterm=NONE
-hi EdaGitConflict guifg=#c09b92 ctermfg=138 gui=NONE cterm=NONE
-hi EdaGitConflictIcon guifg=#c09b92 ctermfg=138 gui=NONE cterm=NONE
-hi EdaGitIgnored guifg=#545c8c ctermfg=60 gui=NONE cterm=NONE
-hi EdaGitIgnoredIcon guifg=#545c8c ctermfg=60 gui=NONE cterm=NONE
-hi EdaMarked guifg=#a8a384 ctermfg=144 gui=bold cterm=bold
-hi EdaCut guifg=#545c8c ctermfg=60 gui=italic cterm=italic
-hi EdaOpDeleteSign guifg=#ff9494 ctermfg=210 gui=bold cterm=bold
-hi EdaOpDeletePath guifg=#ff9494 ctermfg=210 gui=NONE cterm=NONE
-hi EdaOpDeleteText guifg=#545c8c ctermfg=60 gui=NONE cterm=NONE
-hi EdaOpCreateSign guifg=#73c1a9 ctermfg=79 gui=bold cterm=bold
-hi EdaOpCreatePath guifg=#73c1a9 ctermfg=79 gui=NONE cterm=NONE
-hi EdaOpCreateText guifg=#545c8c ctermfg=60 gui=NONE cterm=NONE
-hi EdaOpMoveSign guifg=#a8a384 ctermfg=144 gui=bold cterm=bold
-hi EdaOpMovePath guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
-hi EdaOpMoveText guifg=#545c8c ctermfg=60 gui=NONE cterm=NONE
-hi FernBranchSymbol guifg=#6f78be ctermfg=104 gui=NONE cterm=NONE
-hi FernBranchText guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
-hi FernLeafSymbol guifg=#5b9a87 ctermfg=72 gui=NONE cterm=NONE
-hi FernLeafText guifg=#9ea3c0 ctermfg=146 gui=NONE cterm=NONE
-hi FernMarked guifg=#59b6b6 ctermfg=73 gui=NONE cterm=NONE
-hi GitSignsAdd guifg=#7cbe8c ctermfg=108
-hi GitSignsChange guifg=#a8a384 ctermfg=144
-hi GitSignsDelete guifg=#b871b8 ctermfg=133
-hi GitSignsChangeDelete guifg=#1f4a3c ctermfg=23
-hi GitGutterAdd guifg=#7cbe8c ctermfg=108
-hi GitGutterChange guifg=#a8a384 ctermfg=144
-hi GitGutterDelete guifg=#b871b8 ctermfg=133
-hi GitGutterChangeDelete guifg=#1f4a3c ctermfg=23
-hi fugitiveHeader guifg=#73c1a9 ctermfg=79 gui=bold cterm=bold
-hi DiffviewDim1 guifg=#545c8c ctermfg=60
-hi DiffviewPrimary guifg=#929be5 ctermfg=104
-hi DiffviewSecondary guifg=#b871b8 ctermfg=133
-hi DiffviewStatusAdded guifg=#589ec6 ctermfg=74
-hi DiffviewStatusUntracked guifg=#a8a384 ctermfg=144
-hi DiffviewStatusModified guifg=#73c1a9 ctermfg=79
-hi DiffviewStatusRenamed guifg=#73c1a9 ctermfg=79
-hi DiffviewStatusCopied guifg=#73c1a9 ctermfg=79
-hi DiffviewStatusTypeChanged guifg=#73c1a9 ctermfg=79
-hi DiffviewStatusUnmerged guifg=#b871b8 ctermfg=133
-hi DiffviewStatusUnknown guifg=#a8a384 ctermfg=144
-hi DiffviewStatusDeleted guifg=#8085a6 ctermfg=103
-hi DiffviewStatusBroken guifg=#b871b8 ctermfg=133
-hi DiffviewStatusIgnored guifg=#a8a384 ctermfg=144
-hi DiffviewFilePanelRootPath guifg=#6f78be ctermfg=104
-hi DiffviewFilePanelTitle guifg=#929be5 ctermfg=104 gui=bold cterm=bold
-hi DiffviewFilePanelCounter guifg=#8085a6 ctermfg=103 gui=bold cterm=bold
-hi DiffviewFilePanelFileName guifg=#9ea3c0 ctermfg=146
-hi DiffviewFilePanelPath guifg=#545c8c ctermfg=60 gui=bold cterm=bold
-hi DiffviewFilePanelSelected guifg=#a8a384 ctermfg=144
-hi DiffviewFilePanelInsertions guifg=#73c1a9 ctermfg=79
-hi DiffviewFilePanelDeletions guifg=#b871b8 ctermfg=133
-hi DiffviewFilePanelConflicts guifg=#ac8b83 ctermfg=138
-hi DiffviewHash guifg=#6f78be ctermfg=104
-hi ALEWarningSign guifg=#ac8b83 ctermfg=138 gui=bold cterm=bold
-hi ALEInfoSign guifg=#82dabf ctermfg=115 gui=NONE cterm=NONE
-hi NullLsInfoBorder guifg=#545c8c ctermfg=60 guibg=#222433 ctermbg=235 gui=NONE cterm=NONE
-hi CocErrorSign guifg=#ff9494 ctermfg=210 gui=bold cterm=bold
-hi CocWarningSign guifg=#ac8b83 ctermfg=138 gui=bold cterm=bold
-hi CocInfoSign guifg=#82dabf ctermfg=115 gui=bold cterm=bold
-hi CocHintSign guifg=#82dabf ctermfg=115 gui=bold cterm=bold
-hi LspError guifg=#ff9494 ctermfg=210
-hi LspErrorText guifg=#ff9494 ctermfg=210 gui=bold cterm=bold
-hi LspErrorHighlight gui=underline cterm=underline
-hi LspErrorVirtualText guifg=#ff9494 ctermfg=210 gui=bold cterm=bold
-hi LspWarning guifg=#ac8b83 ctermfg=138
-hi LspWarningText guifg=#ac8b83 ctermfg=138 gui=bold cterm=bold
-hi LspWarningHighlight gui=underline cterm=underline
-hi LspWarningVirtualText guifg=#ac8b83 ctermfg=138 gui=bold cterm=bold
-hi LspInformation guifg=#82dabf ctermfg=115
-hi LspInformationText guifg=#82dabf ctermfg=115 gui=bold cterm=bold
-hi LspInformationHighlight gui=underline cterm=underline
-hi LspInformationVirtualText guifg=#545c8c ctermfg=60 gui=bold cterm=bold
-hi LspHint guifg=#82dabf ctermfg=115
-hi LspHintText guifg=#82dabf ctermfg=115 gui=bold cterm=bold
-hi LspHintHighlight gui=underline cterm=underline
-hi LspHintVirtualText guifg=#545c8c ctermfg=60 gui=bold cterm=bold
-hi LspCodeActionText guifg=#6f78be ctermfg=104 gui=bold cterm=bold
-hi CmpItemAbbr guifg=#9ea3c0 ctermfg=146
-hi CmpItemAbbrMatch guifg=#929be5 ctermfg=104 gui=bold cterm=bold
-hi CmpItemAbbrMatchFuzzy guifg=#929be5 ctermfg=104 gui=bold cterm=bold
-hi CmpItemAbbrDeprecated guifg=#545c8c ctermfg=60 gui=strikethrough cterm=strikethrough
-hi CmpItemMenu guifg=#545c8c ctermfg=60 gui=italic cterm=italic
-hi CmpItemKind guifg=#8085a6 ctermfg=103
-hi CmpItemKindText guifg=#8085a6 ctermfg=103
-hi CmpItemKindVariable guifg=#73c1a9 ctermfg=79
-hi CmpItemKindConstant guifg=#73c1a9 ctermfg=79
-hi CmpItemKindEnum guifg=#73c1a9 ctermfg=79
-hi CmpItemKindInterface guifg=#73c1a9 ctermfg=79
-hi CmpItemKindClass guifg=#73c1a9 ctermfg=79
-hi CmpItemKindFunction guifg=#ac8b83 ctermfg=138
-hi CmpItemKindMethod guifg=#ac8b83 ctermfg=138
-hi CmpItemKindModule guifg=#ac8b83 ctermfg=138
-hi CmpItemKindConstructor guifg=#ac8b83 ctermfg=138
-hi CmpItemKindKeyword guifg=#a8a384 ctermfg=144
-hi CmpItemKindProperty guifg=#a8a384 ctermfg=144
-hi CmpItemKindField guifg=#a8a384 ctermfg=144
-hi CmpItemKindUnit guifg=#a8a384 ctermfg=144
-hi BlinkCmpMenu guifg=#9ea3c0 ctermfg=146
-hi BlinkCmpMenuSelection guibg=#363e7f ctermbg=61
-hi BlinkCmpLabelMatch guifg=#929be5 ctermfg=104 gui=bold cterm=bold
-hi BlinkCmpLabelDeprecated guifg=#545c8c ctermfg=60 gui=strikethrough cterm=strikethrough
-hi BlinkCmpKind guifg=#8085a6 ctermfg=103
-hi BlinkCmpKindText guifg=#8085a6 ctermfg=103
-hi BlinkCmpKindVariable guifg=#73c1a9 ctermfg=79
-hi BlinkCmpKindConstant guifg=#73c1a9 ctermfg=79
-hi BlinkCmpKindEnum guifg=#73c1a9 ctermfg=79
-hi BlinkCmpKindInterface guifg=#73c1a9 ctermfg=79
-hi BlinkCmpKindClass guifg=#73c1a9 ctermfg=79
-hi BlinkCmpKindFunction guifg=#ac8b83 ctermfg=138
-hi BlinkCmpKindMethod guifg=#ac8b83 ctermfg=138
-hi BlinkCmpKindModule guifg=#ac8b83 ctermfg=138
-hi BlinkCmpKindConstructor guifg=#ac8b83 ctermfg=138
-hi BlinkCmpKindKeyword guifg=#a8a384 ctermfg=144
-hi BlinkCmpKindProperty guifg=#a8a384 ctermfg=144
-hi BlinkCmpKindField guifg=#a8a384 ctermfg=144
-hi BlinkCmpKindUnit guifg=#a8a384 ctermfg=144
-hi BlinkCmpSource guifg=#545c8c ctermfg=60 gui=italic cterm=italic
-hi BlinkCmpDocSeparator guifg=#545c8c ctermfg=60
-hi BlinkCmpMenuBorder guifg=#545c8c ctermfg=60 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
-hi BlinkCmpDocBorder guifg=#545c8c ctermfg=60 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
-hi BlinkCmpSignatureHelpBorder guifg=#545c8c ctermfg=60 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
-hi FloatTitle guifg=#929be5 ctermfg=104 guibg=#222433 ctermbg=235 gui=NONE cterm=NONE
-hi TelescopeNormal guifg=#8085a6 ctermfg=103
-hi TelescopeTitle guifg=#929be5 ctermfg=104
-hi TelescopeMatching guifg=#bdc3e6 ctermfg=146 gui=bold cterm=bold
-hi TelescopeBorder guifg=#545c8c ctermfg=60
-hi TelescopePromptPrefix guifg=#73c1a9 ctermfg=79
-hi TelescopePromptCounter guifg=#545c8c ctermfg=60
-hi TelescopeMultiIcon guifg=#a8a384 ctermfg=144
-hi TelescopeMultiSelection guifg=#a8a384 ctermfg=144
-hi SnacksNormal guifg=#8085a6 ctermfg=103
-hi SnacksPickerPrompt guifg=#73c1a9 ctermfg=79
-hi SnacksPickerMatch guifg=#929be5 ctermfg=104
-hi SnacksPickerDir guifg=#545c8c ctermfg=60
-hi CopilotSuggestion guifg=#545c8c ctermfg=60
-hi CleverFChar guifg=#a6afff ctermfg=147 guibg=#6471e5 ctermbg=63 gui=underline cterm=underline
-hi MiniJump guifg=#a6afff ctermfg=147 guibg=#6471e5 ctermbg=63 gui=underline cterm=underline
-hi ConflictMarkerBegin guibg=#5b9a87 ctermbg=72 gui=bold cterm=bold
-hi ConflictMarkerOurs guibg=#26463b ctermbg=23 gui=NONE cterm=NONE
-hi ConflictMarkerTheirs guibg=#1c394b ctermbg=237 gui=NONE cterm=NONE
-hi ConflictMarkerEnd guibg=#417593 ctermbg=31 gui=bold cterm=bold
-hi ConflictMarkerSeparator guifg=#363859 ctermfg=60 gui=bold cterm=bold
-hi EasyMotionTarget guifg=#a8a384 ctermfg=144 gui=bold cterm=bold
-hi EasyMotionShade guifg=#545c8c ctermfg=60 guibg=#222433 ctermbg=235
-hi EasyMotionIncCursor guifg=#9ea3c0 ctermfg=146 guibg=#222433 ctermbg=235
-hi HopNextKey guifg=#73c1a9 ctermfg=79 gui=bold cterm=bold
-hi HopNextKey1 guifg=#73c1a9 ctermfg=79 gui=bold cterm=bold
-hi HopNextKey2 guifg=#5b9a87 ctermfg=72 gui=bold cterm=bold
-hi HopUnmatched guifg=#545c8c ctermfg=60
-hi FlashPrompt guifg=#929be5 ctermfg=104 gui=bold cterm=bold
-hi FlashPromptIcon guifg=#73c1a9 ctermfg=79 gui=bold cterm=bold
-hi FlashLabel guifg=#73c1a9 ctermfg=79 gui=bold cterm=bold
-hi FidgetTitle guifg=#73c1a9 ctermfg=79 gui=bold cterm=bold
-hi FidgetTask guifg=#545c8c ctermfg=60
-hi HlSearchLens guifg=#545c8c ctermfg=60 gui=italic cterm=italic
-hi HlSearchLensNear guifg=#545c8c ctermfg=60 gui=italic cterm=italic
-hi NotifyBackground guibg=#222433 ctermbg=235
-hi NotifyERRORBorder guifg=#cc8a8a ctermfg=174
-hi NotifyWARNBorder guifg=#796b68 ctermfg=242
-hi NotifyINFOBorder guifg=#628e80 ctermfg=66
-hi NotifyDEBUGBorder guifg=#82838d ctermfg=102
-hi NotifyTRACEBorder guifg=#628e80 ctermfg=66
-hi NotifyERRORIcon guifg=#ff9494 ctermfg=210
-hi NotifyWARNIcon guifg=#ac8b83 ctermfg=138
-hi NotifyINFOIcon guifg=#82dabf ctermfg=115
-hi NotifyDEBUGIcon guifg=#9ea3c0 ctermfg=146
-hi NotifyTRACEIcon guifg=#82dabf ctermfg=115
-hi NotifyERRORTitle guifg=#ff9494 ctermfg=210
-hi NotifyWARNTitle guifg=#ac8b83 ctermfg=138
-hi NotifyINFOTitle guifg=#82dabf ctermfg=115
-hi NotifyDEBUGTitle guifg=#9ea3c0 ctermfg=146
-hi NotifyTRACETitle guifg=#82dabf ctermfg=115
-hi NotifyERRORBody guifg=#9ea3c0 ctermfg=146
-hi NotifyWARNBody guifg=#9ea3c0 ctermfg=146
-hi NotifyINFOBody guifg=#9ea3c0 ctermfg=146
-hi NotifyDEBUGBody guifg=#9ea3c0 ctermfg=146
-hi NotifyTRACEBody guifg=#9ea3c0 ctermfg=146
-hi AvanteTitle guifg=#222433 ctermfg=235 guibg=#929be5 ctermbg=104
-hi AvanteReversedTitle guifg=#929be5 ctermfg=104 guibg=#222433 ctermbg=235
-hi AvanteSubtitle guifg=#222433 ctermfg=235 guibg=#73c1a9 ctermbg=79
-hi AvanteReversedSubtitle guifg=#73c1a9 ctermfg=79 guibg=#222433 ctermbg=235
-hi AvanteThirdTitle guifg=#9ea3c0 ctermfg=146 guibg=#32364c ctermbg=237
-hi AvanteReversedThirdTitle guifg=#32364c ctermfg=237 guibg=#222433 ctermbg=235
-hi AvantePopupHint guifg=#545c8c ctermfg=60
-hi AvanteInlineHint guifg=#545c8c ctermfg=60
-hi AvanteSidebarWinSeparator guifg=#363859 ctermfg=60 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
-hi AvanteSidebarWinHorizontalSeparator guifg=#222433 ctermfg=235 guibg=#222433 ctermbg=235 gui=NONE cterm=NONE
-if has("nvim")
-  let g:terminal_color_0 = '#111219'
-  let g:terminal_color_1 = '#e58585'
-  let g:terminal_color_2 = '#7cbe8c'
-  let g:terminal_color_3 = '#8e8a6f'
-  let g:terminal_color_4 = '#4c89ac'
-  let g:terminal_color_5 = '#6c75cb'
-  let g:terminal_color_6 = '#73c1a9'
-  let g:terminal_color_7 = '#9ea3c0'
-  let g:terminal_color_8 = '#545c8c'
-  let g:terminal_color_9 = '#b871b8'
-  let g:terminal_color_10 = '#7cbe8c'
-  let g:terminal_color_11 = '#a8a384'
-  let g:terminal_color_12 = '#589ec6'
-  let g:terminal_color_13 = '#929be5'
-  let g:terminal_color_14 = '#59b6b6'
-  let g:terminal_color_15 = '#9ea3c0'
-  let g:terminal_color_background = g:terminal_color_0
-  let g:terminal_color_foreground = g:terminal_color_7
-endif
-if has("nvim-0.8.0")
-  hi @string guifg=#7cbe8c ctermfg=108
-  hi @string.regex guifg=#7cbe8c ctermfg=108
-  hi @string.escape guifg=#b871b8 ctermfg=133
-  hi @string.special.url guifg=#545c8c ctermfg=60
-  hi @text.title guifg=#a8a384 ctermfg=144 gui=bold cterm=bold
-  hi @text.reference guifg=#929be5 ctermfg=104
-  hi @text.uri guifg=#545c8c ctermfg=60
-  hi @text.strong gui=bold cterm=bold
-  hi @text.literal guifg=#73c1a9 ctermfg=79
-  hi @parameter guifg=#929be5 ctermfg=104
-  hi @property guifg=#929be5 ctermfg=104
-  hi @keyword guifg=#b871b8 ctermfg=133
-  hi @operator guifg=#929be5 ctermfg=104
-  hi @module guifg=#9ea3c0 ctermfg=146
-  hi @type guifg=#ac8b83 ctermfg=138
-  hi @type.builtin guifg=#ac8b83 ctermfg=138
-  hi @function.tsx guifg=#9ea3c0 ctermfg=146
-  hi @punctuation.special.typescript guifg=#8085a6 ctermfg=103
-  hi @include guifg=#929be5 ctermfg=104
-  hi @variable guifg=#9ea3c0 ctermfg=146
-  hi @variable.builtin guifg=#ac8b83 ctermfg=138
-  hi @constant.builtin guifg=#73c1a9 ctermfg=79
-  hi @constructor guifg=#9ea3c0 ctermfg=146
-  hi @tag guifg=#9ea3c0 ctermfg=146
-  hi @tag.delimiter guifg=#929be5 ctermfg=104
-  hi @tag.attribute guifg=#929be5 ctermfg=104
-  hi @tag.builtin.tsx guifg=#9ea3c0 ctermfg=146
-  hi @markup.heading guifg=#a8a384 ctermfg=144 gui=bold cterm=bold
-  hi @markup.strong gui=bold cterm=bold
-  hi @markup.list guifg=#545c8c ctermfg=60
-  hi @markup.raw guifg=#73c1a9 ctermfg=79
-  hi @markup.link guifg=#929be5 ctermfg=104
-  hi @markup.link.url guifg=#8085a6 ctermfg=103
-  hi @markup.quote guifg=#545c8c ctermfg=60
-  hi @lsp.type.class guifg=#9ea3c0 ctermfg=146
-  hi @lsp.type.interface guifg=#ac8b83 ctermfg=138
-  hi @lsp.type.parameter guifg=#929be5 ctermfg=104
-  hi @lsp.type.property guifg=#929be5 ctermfg=104
-  hi @lsp.type.struct guifg=#9ea3c0 ctermfg=146
-  hi @lsp.type.type guifg=#ac8b83 ctermfg=138
-  hi @lsp.type.typeParameter guifg=#9ea3c0 ctermfg=146
-  hi @lsp.type.variable guifg=#9ea3c0 ctermfg=146
-  hi @lsp.type.member guifg=#929be5 ctermfg=104
-  hi @lsp.type.namespace guifg=#9ea3c0 ctermfg=146
+if &background ==# 'light'
+  hi Normal guifg=#464b65 ctermfg=60 guibg=#d3d4e8 ctermbg=189
+  hi Delimiter guifg=#606584 ctermfg=60
+  hi NonText guifg=#b2b4d8 ctermfg=146 guibg=NONE ctermbg=NONE
+  hi VertSplit guifg=#babbd8 ctermfg=146 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi LineNr guifg=#babbd8 ctermfg=146 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi EndOfBuffer guifg=#b2b4d8 ctermfg=146 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi Comment guifg=#868bbe ctermfg=103 gui=NONE cterm=NONE
+  hi Cursor guifg=#d3d4e8 ctermfg=189 guibg=#464b65 ctermbg=60
+  hi! link CursorIM Cursor
+  hi SignColumn guifg=#868bbe ctermfg=103 guibg=NONE ctermbg=NONE
+  hi ColorColumn guibg=#c4c6df ctermbg=189 gui=NONE cterm=NONE
+  hi CursorColumn guibg=#c4c6df ctermbg=189 gui=NONE cterm=NONE
+  hi CursorLine guibg=#c4c6df ctermbg=189 gui=NONE cterm=NONE
+  hi CursorLineNr guifg=#8187c4 ctermfg=104 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi Conceal guifg=#74564f ctermfg=95 guibg=#d3d4e8 ctermbg=189 gui=NONE cterm=NONE
+  hi Folded guifg=#777caa ctermfg=103 guibg=#babbd8 ctermbg=146 gui=NONE cterm=NONE
+  hi FoldColumn guifg=#babbd8 ctermfg=146 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi MatchParen guibg=#bfc1dc ctermbg=146
+  hi Directory guifg=#3c4c8d ctermfg=61
+  hi Underlined gui=underline cterm=underline
+  hi String guifg=#044e25 ctermfg=22
+  hi Statement guifg=#3c4c8d ctermfg=61 gui=NONE cterm=NONE
+  hi Label guifg=#3c4c8d ctermfg=61 gui=NONE cterm=NONE
+  hi Function guifg=#3c4c8d ctermfg=61 gui=NONE cterm=NONE
+  hi Constant guifg=#004b39 ctermfg=23
+  hi Boolean guifg=#004b39 ctermfg=23
+  hi Number guifg=#004b39 ctermfg=23
+  hi Float guifg=#004b39 ctermfg=23
+  hi Title guifg=#534f34 ctermfg=239 gui=bold cterm=bold
+  hi Keyword guifg=#74564f ctermfg=95
+  hi Identifier guifg=#74564f ctermfg=95
+  hi Exception guifg=#534f34 ctermfg=239
+  hi Type guifg=#534f34 ctermfg=239 gui=NONE cterm=NONE
+  hi TypeDef guifg=#534f34 ctermfg=239 gui=NONE cterm=NONE
+  hi PreProc guifg=#3c4c8d ctermfg=61
+  hi Special guifg=#8f4b90 ctermfg=96
+  hi SpecialKey guifg=#8f4b90 ctermfg=96
+  hi SpecialChar guifg=#8f4b90 ctermfg=96
+  hi SpecialComment guifg=#8f4b90 ctermfg=96
+  hi Error guifg=#7a1e28 ctermfg=52 guibg=#d3d4e8 ctermbg=189 gui=bold cterm=bold
+  hi ErrorMsg guifg=#7a1e28 ctermfg=52 guibg=NONE ctermbg=NONE gui=bold cterm=bold
+  hi WarningMsg guifg=#74564f ctermfg=95 gui=bold cterm=bold
+  hi MoreMsg guifg=#004b39 ctermfg=23
+  hi ModeMsg guifg=#004b39 ctermfg=23
+  hi Debug guifg=#464b65 ctermfg=60
+  hi Todo guifg=#534f34 ctermfg=239 guibg=NONE ctermbg=NONE gui=bold cterm=bold
+  hi Pmenu guifg=#464b65 ctermfg=60 guibg=#babbd8 ctermbg=146
+  hi PmenuSel guifg=#464b65 ctermfg=60 guibg=#a8a9f0 ctermbg=147 gui=NONE cterm=NONE
+  hi PmenuMatch guifg=#3c4c8d ctermfg=61 gui=bold cterm=bold
+  hi PmenuSbar guibg=#c6c7e0 ctermbg=189
+  hi PmenuThumb guibg=#959ad0 ctermbg=104
+  hi Visual guibg=#a8a9f0 ctermbg=147 gui=NONE cterm=NONE
+  hi Search guifg=#213a7a ctermfg=25 guibg=#5667d8 ctermbg=62
+  hi! link CurSearch Search
+  hi IncSearch guifg=#153771 ctermfg=25 guibg=#7a7ef9 ctermbg=105 gui=NONE cterm=NONE
+  hi Question guifg=#004b39 ctermfg=23 gui=bold cterm=bold
+  hi WildMenu guifg=#d3d4e8 ctermfg=189 guibg=#3c4c8d ctermbg=61
+  hi SpellBad guifg=#7a1e28 ctermfg=52 gui=underline cterm=underline
+  hi SpellCap gui=underline cterm=underline
+  hi SpellLocal guifg=#7a1e28 ctermfg=52 gui=underline cterm=underline
+  hi SpellRare guifg=#534f34 ctermfg=239 gui=underline cterm=underline
+  hi Added guifg=NONE ctermfg=NONE guibg=#b2c5ea ctermbg=153 gui=NONE cterm=NONE
+  hi Removed guifg=#9c82ab ctermfg=139 guibg=#ccacdc ctermbg=182 gui=NONE cterm=NONE
+  hi Changed guifg=NONE ctermfg=NONE guibg=#90b4b0 ctermbg=109 gui=NONE cterm=NONE
+  hi DiffAdd guifg=NONE ctermfg=NONE guibg=#b2c5ea ctermbg=153
+  hi DiffChange guifg=NONE ctermfg=NONE guibg=#90b4b0 ctermbg=109
+  hi DiffDelete guifg=#9c82ab ctermfg=139 guibg=#ccacdc ctermbg=182
+  hi DiffText guifg=NONE ctermfg=NONE guibg=#5a9475 ctermbg=65
+  hi QuickFixLine guifg=#464b65 ctermfg=60 guibg=#a8a9f0 ctermbg=147
+  hi StatusLine guifg=#676d96 ctermfg=60 guibg=#c6c7e0 ctermbg=189 gui=bold cterm=bold
+  hi! link StatusLineTerm StatusLine
+  hi StatusLineNC guifg=#999cbe ctermfg=146 guibg=#c9cbdf ctermbg=189 gui=NONE cterm=NONE
+  hi! link StatusLineTermNC StatusLineNC
+  hi TabLine guifg=#676d96 ctermfg=60 guibg=#c6c7e0 ctermbg=189 gui=NONE cterm=NONE
+  hi TabLineFill guifg=#676d96 ctermfg=60 guibg=#c6c7e0 ctermbg=189 gui=NONE cterm=NONE
+  hi TabLineSel guifg=#d3d4e8 ctermfg=189 guibg=#3c4c8d ctermbg=61 gui=bold cterm=bold
+  hi qfFileName guifg=#004b39 ctermfg=23
+  hi qfLineNr guifg=#868bbe ctermfg=103
+  hi htmlTag guifg=#606584 ctermfg=60
+  hi htmlEndTag guifg=#606584 ctermfg=60
+  hi htmlSpecialTagName guifg=#74564f ctermfg=95
+  hi htmlArg guifg=#606584 ctermfg=60
+  hi jsonQuote guifg=#606584 ctermfg=60
+  hi yamlBlockMappingKey guifg=#3c4c8d ctermfg=61
+  hi yamlAnchor guifg=#8f4b90 ctermfg=96
+  hi pythonStatement guifg=#74564f ctermfg=95
+  hi pythonBuiltin guifg=#005556 ctermfg=23
+  hi pythonRepeat guifg=#74564f ctermfg=95
+  hi pythonOperator guifg=#74564f ctermfg=95
+  hi pythonDecorator guifg=#8f4b90 ctermfg=96
+  hi pythonDecoratorName guifg=#8f4b90 ctermfg=96
+  hi zshVariableDef guifg=#3c4c8d ctermfg=61
+  hi zshFunction guifg=#3c4c8d ctermfg=61
+  hi zshKSHFunction guifg=#3c4c8d ctermfg=61
+  hi cPreCondit guifg=#74564f ctermfg=95
+  hi cIncluded guifg=#8f4b90 ctermfg=96
+  hi cStorageClass guifg=#74564f ctermfg=95
+  hi cppStructure guifg=#8f4b90 ctermfg=96
+  hi cppSTLnamespace guifg=#74564f ctermfg=95
+  hi csStorage guifg=#74564f ctermfg=95
+  hi csModifier guifg=#3c4c8d ctermfg=61
+  hi csClass guifg=#3c4c8d ctermfg=61
+  hi csClassType guifg=#8f4b90 ctermfg=96
+  hi csNewType guifg=#74564f ctermfg=95
+  hi rubyConstant guifg=#74564f ctermfg=95
+  hi rubySymbol guifg=#3c4c8d ctermfg=61
+  hi rubyBlockParameter guifg=#3c4c8d ctermfg=61
+  hi rubyClassName guifg=#8f4b90 ctermfg=96
+  hi rubyInstanceVariable guifg=#8f4b90 ctermfg=96
+  hi typescriptImport guifg=#3c4c8d ctermfg=61
+  hi typescriptDocRef guifg=#868bbe ctermfg=103 gui=underline cterm=underline
+  hi mkdHeading guifg=#868bbe ctermfg=103
+  hi mkdLink guifg=#3c4c8d ctermfg=61
+  hi mkdCode guifg=#3c4c8d ctermfg=61
+  hi mkdCodeStart guifg=#3c4c8d ctermfg=61
+  hi mkdCodeEnd guifg=#3c4c8d ctermfg=61
+  hi mkdCodeDelimiter guifg=#3c4c8d ctermfg=61
+  hi tomlTable guifg=#3c4c8d ctermfg=61
+  hi rustModPath guifg=#3c4c8d ctermfg=61
+  hi rustTypedef guifg=#3c4c8d ctermfg=61
+  hi rustStructure guifg=#3c4c8d ctermfg=61
+  hi rustMacro guifg=#3c4c8d ctermfg=61
+  hi rustExternCrate guifg=#3c4c8d ctermfg=61
+  hi graphqlStructure guifg=#8f4b90 ctermfg=96
+  hi graphqlDirective guifg=#8f4b90 ctermfg=96
+  hi graphqlName guifg=#3c4c8d ctermfg=61
+  hi graphqlTemplateString guifg=#464b65 ctermfg=60
+  hi vimfilerOpenedFile guifg=#616bb0 ctermfg=61
+  hi vimfilerClosedFile guifg=#616bb0 ctermfg=61
+  hi vimfilerNonMark guifg=#004b39 ctermfg=23
+  hi vimfilerLeaf guifg=#004b39 ctermfg=23
+  hi DefxIconsMarkIcon guifg=#616bb0 ctermfg=61 gui=NONE cterm=NONE
+  hi DefxIconsDirectory guifg=#616bb0 ctermfg=61 gui=NONE cterm=NONE
+  hi DefxIconsParentDirectory guifg=#616bb0 ctermfg=61 gui=NONE cterm=NONE
+  hi DefxIconsSymlinkDirectory guifg=#004b39 ctermfg=23 gui=NONE cterm=NONE
+  hi DefxIconsOpenedTreeIcon guifg=#616bb0 ctermfg=61 gui=NONE cterm=NONE
+  hi DefxIconsNestedTreeIcon guifg=#616bb0 ctermfg=61 gui=NONE cterm=NONE
+  hi DefxIconsClosedTreeIcon guifg=#616bb0 ctermfg=61 gui=NONE cterm=NONE
+  hi Defx_git_Untracked guifg=#3c4c8d ctermfg=61 gui=NONE cterm=NONE
+  hi Defx_git_Ignored guifg=#868bbe ctermfg=103 gui=NONE cterm=NONE
+  hi Defx_git_Unknown guifg=#868bbe ctermfg=103 gui=NONE cterm=NONE
+  hi Defx_git_Renamed guifg=#90b4b0 ctermfg=109
+  hi Defx_git_Modified guifg=#90b4b0 ctermfg=109
+  hi Defx_git_Unmerged guifg=#8f4b90 ctermfg=96
+  hi Defx_git_Deleted guifg=#ccacdc ctermfg=182
+  hi Defx_git_Staged guifg=#004b39 ctermfg=23
+  hi NvimTreeSymlink guifg=#2b6a59 ctermfg=23 gui=NONE cterm=NONE
+  hi NvimTreeSymlinkFolderName guifg=#2b6a59 ctermfg=23 gui=NONE cterm=NONE
+  hi NvimTreeFolderName guifg=#3c4c8d ctermfg=61 gui=NONE cterm=NONE
+  hi NvimTreeRootFolder guifg=#9a9ccf ctermfg=146 gui=bold cterm=bold
+  hi NvimTreeFolderIcon guifg=#616bb0 ctermfg=61 gui=NONE cterm=NONE
+  hi NvimTreeFileIcon guifg=#616bb0 ctermfg=61 gui=NONE cterm=NONE
+  hi NvimTreeEmptyFolderName guifg=#868bbe ctermfg=103 gui=NONE cterm=NONE
+  hi NvimTreeOpenedFolderName guifg=#3c4c8d ctermfg=61 gui=NONE cterm=NONE
+  hi NvimTreeExecFile guifg=#606584 ctermfg=60 gui=NONE cterm=NONE
+  hi NvimTreeOpenedHL guifg=#606584 ctermfg=60 gui=NONE cterm=NONE
+  hi NvimTreeSpecialFile guifg=#606584 ctermfg=60 gui=bold cterm=bold
+  hi NvimTreeImageFile guifg=#606584 ctermfg=60 gui=NONE cterm=NONE
+  hi NvimTreeIndentMarker guifg=#9a9ccf ctermfg=146 gui=NONE cterm=NONE
+  hi NvimTreeModifiedIcon guifg=#464b65 ctermfg=60 gui=NONE cterm=NONE
+  hi NvimTreeGitDirtyIcon guifg=#534f34 ctermfg=239 gui=NONE cterm=NONE
+  hi NvimTreeGitStagedIcon guifg=#044e25 ctermfg=22 gui=NONE cterm=NONE
+  hi NvimTreeGitMergeIcon guifg=#534f34 ctermfg=239 gui=NONE cterm=NONE
+  hi NvimTreeGitRenamedIcon guifg=#534f34 ctermfg=239 gui=NONE cterm=NONE
+  hi NvimTreeGitNewIcon guifg=#004b39 ctermfg=23 gui=NONE cterm=NONE
+  hi NvimTreeGitDeletedIcon guifg=#5a9475 ctermfg=65 gui=NONE cterm=NONE
+  hi NvimTreeWindowPicker guifg=#d3d4e8 ctermfg=189 guibg=#3c4c8d ctermbg=61 gui=bold cterm=bold
+  hi NvimTreeNormal guifg=#606584 ctermfg=60 gui=NONE cterm=NONE
+  hi NvimTreeLiveFilterPrefix guifg=#2b6a59 ctermfg=23 gui=NONE cterm=NONE
+  hi NvimTreeLiveFilterValue guifg=#004b39 ctermfg=23 gui=NONE cterm=NONE
+  hi NvimTreeBookmarkIcon guifg=#534f34 ctermfg=239 gui=NONE cterm=NONE
+  hi FylerBlue guifg=#006085 ctermfg=24 gui=NONE cterm=NONE
+  hi FylerGreen guifg=#004b39 ctermfg=23 gui=NONE cterm=NONE
+  hi FylerGrey guifg=#464b65 ctermfg=60 gui=NONE cterm=NONE
+  hi FylerRed guifg=#7a1e28 ctermfg=52 gui=NONE cterm=NONE
+  hi FylerYellow guifg=#534f34 ctermfg=239 gui=NONE cterm=NONE
+  hi FylerNormal guifg=#606584 ctermfg=60 gui=NONE cterm=NONE
+  hi FylerNormalNC guifg=#606584 ctermfg=60 gui=NONE cterm=NONE
+  hi FylerBorder guifg=#868bbe ctermfg=103 gui=NONE cterm=NONE
+  hi FylerFSDirectoryIcon guifg=#616bb0 ctermfg=61 gui=bold cterm=bold
+  hi FylerFSDirectoryName guifg=#3c4c8d ctermfg=61 gui=NONE cterm=NONE
+  hi FylerFSFile guifg=#606584 ctermfg=60 gui=NONE cterm=NONE
+  hi FylerFSLink guifg=#2b6a59 ctermfg=23 gui=NONE cterm=NONE
+  hi FylerGitAdded guifg=#464b65 ctermfg=60 gui=NONE cterm=NONE
+  hi FylerGitConflict gui=NONE cterm=NONE
+  hi FylerGitDeleted guifg=#5a9475 ctermfg=65 gui=NONE cterm=NONE
+  hi FylerGitIgnored gui=NONE cterm=NONE
+  hi FylerGitModified gui=NONE cterm=NONE
+  hi FylerGitRenamed guifg=#534f34 ctermfg=239 gui=NONE cterm=NONE
+  hi FylerGitStaged guifg=#044e25 ctermfg=22 gui=NONE cterm=NONE
+  hi FylerGitUnstaged gui=NONE cterm=NONE
+  hi FylerGitUntracked gui=NONE cterm=NONE
+  hi FylerIndentMarker guifg=#868bbe ctermfg=103 gui=NONE cterm=NONE
+  hi FylerWinPick gui=NONE cterm=NONE
+  hi EdaNormal guifg=#606584 ctermfg=60 gui=NONE cterm=NONE
+  hi EdaNormalNC guifg=#606584 ctermfg=60 gui=NONE cterm=NONE
+  hi EdaBorder guifg=#868bbe ctermfg=103 gui=NONE cterm=NONE
+  hi EdaTitle guifg=#3c4c8d ctermfg=61 gui=bold cterm=bold
+  hi EdaCursorLine guibg=#c4c6df ctermbg=189 gui=NONE cterm=NONE
+  hi EdaIndentMarker guifg=#9a9ccf ctermfg=146 gui=NONE cterm=NONE
+  hi EdaRootName guifg=#9a9ccf ctermfg=146 gui=bold cterm=bold
+  hi EdaDivider guifg=#868bbe ctermfg=103 gui=NONE cterm=NONE
+  hi EdaFilterIndicator guifg=#2b6a59 ctermfg=23 gui=NONE cterm=NONE
+  hi EdaDirectoryName guifg=#3c4c8d ctermfg=61 gui=NONE cterm=NONE
+  hi EdaDirectoryIcon guifg=#616bb0 ctermfg=61 gui=NONE cterm=NONE
+  hi EdaOpenedDirectoryName guifg=#3c4c8d ctermfg=61 gui=NONE cterm=NONE
+  hi EdaEmptyDirectoryName guifg=#868bbe ctermfg=103 gui=NONE cterm=NONE
+  hi EdaFileName guifg=#606584 ctermfg=60 gui=NONE cterm=NONE
+  hi EdaFileIcon guifg=#616bb0 ctermfg=61 gui=NONE cterm=NONE
+  hi EdaSymlink guifg=#2b6a59 ctermfg=23 gui=NONE cterm=NONE
+  hi EdaBrokenSymlink guifg=#7a1e28 ctermfg=52 gui=NONE cterm=NONE
+  hi EdaSymlinkTarget guifg=#868bbe ctermfg=103 gui=NONE cterm=NONE
+  hi EdaErrorNode guifg=#7a1e28 ctermfg=52 gui=NONE cterm=NONE
+  hi EdaLoadingNode guifg=#868bbe ctermfg=103 gui=italic cterm=italic
+  hi EdaOpenedFile guifg=#606584 ctermfg=60 gui=NONE cterm=NONE
+  hi EdaModifiedFile guifg=#534f34 ctermfg=239 gui=NONE cterm=NONE
+  hi EdaGitUntracked guifg=#004b39 ctermfg=23 gui=NONE cterm=NONE
+  hi! link EdaGitUntrackedIcon EdaGitUntracked
+  hi EdaGitAdded guifg=#004b39 ctermfg=23 gui=NONE cterm=NONE
+  hi! link EdaGitAddedIcon EdaGitAdded
+  hi EdaGitModified guifg=#403d1f ctermfg=237 gui=NONE cterm=NONE
+  hi! link EdaGitModifiedIcon EdaGitModified
+  hi EdaGitDeleted guifg=#8a448b ctermfg=96 gui=NONE cterm=NONE
+  hi! link EdaGitDeletedIcon EdaGitDeleted
+  hi EdaGitRenamed guifg=#403d1f ctermfg=237 gui=NONE cterm=NONE
+  hi! link EdaGitRenamedIcon EdaGitRenamed
+  hi EdaGitStaged guifg=#044e25 ctermfg=22 gui=NONE cterm=NONE
+  hi! link EdaGitStagedIcon EdaGitStaged
+  hi EdaGitConflict guifg=#65463f ctermfg=95 gui=NONE cterm=NONE
+  hi! link EdaGitConflictIcon EdaGitConflict
+  hi EdaGitIgnored guifg=#868bbe ctermfg=103 gui=NONE cterm=NONE
+  hi! link EdaGitIgnoredIcon EdaGitIgnored
+  hi EdaMarked guifg=#534f34 ctermfg=239 gui=bold cterm=bold
+  hi EdaCut guifg=#868bbe ctermfg=103 gui=italic cterm=italic
+  hi EdaOpDeleteSign guifg=#7a1e28 ctermfg=52 gui=bold cterm=bold
+  hi EdaOpDeletePath guifg=#7a1e28 ctermfg=52 gui=NONE cterm=NONE
+  hi EdaOpDeleteText guifg=#868bbe ctermfg=103 gui=NONE cterm=NONE
+  hi EdaOpCreateSign guifg=#004b39 ctermfg=23 gui=bold cterm=bold
+  hi EdaOpCreatePath guifg=#004b39 ctermfg=23 gui=NONE cterm=NONE
+  hi EdaOpCreateText guifg=#868bbe ctermfg=103 gui=NONE cterm=NONE
+  hi EdaOpMoveSign guifg=#534f34 ctermfg=239 gui=bold cterm=bold
+  hi EdaOpMovePath guifg=#534f34 ctermfg=239 gui=NONE cterm=NONE
+  hi EdaOpMoveText guifg=#868bbe ctermfg=103 gui=NONE cterm=NONE
+  hi FernBranchSymbol guifg=#616bb0 ctermfg=61 gui=NONE cterm=NONE
+  hi FernBranchText guifg=#3c4c8d ctermfg=61 gui=NONE cterm=NONE
+  hi FernLeafSymbol guifg=#2b6a59 ctermfg=23 gui=NONE cterm=NONE
+  hi FernLeafText guifg=#464b65 ctermfg=60 gui=NONE cterm=NONE
+  hi FernMarked guifg=#005556 ctermfg=23 gui=NONE cterm=NONE
+  hi GitSignsAdd guifg=#044e25 ctermfg=22
+  hi GitSignsChange guifg=#534f34 ctermfg=239
+  hi GitSignsDelete guifg=#8f4b90 ctermfg=96
+  hi GitSignsChangeDelete guifg=#5a9475 ctermfg=65
+  hi GitGutterAdd guifg=#044e25 ctermfg=22
+  hi GitGutterChange guifg=#534f34 ctermfg=239
+  hi GitGutterDelete guifg=#8f4b90 ctermfg=96
+  hi GitGutterChangeDelete guifg=#5a9475 ctermfg=65
+  hi fugitiveHeader guifg=#004b39 ctermfg=23 gui=bold cterm=bold
+  hi DiffviewDim1 guifg=#868bbe ctermfg=103
+  hi DiffviewPrimary guifg=#3c4c8d ctermfg=61
+  hi DiffviewSecondary guifg=#8f4b90 ctermfg=96
+  hi DiffviewStatusAdded guifg=#006085 ctermfg=24
+  hi DiffviewStatusUntracked guifg=#534f34 ctermfg=239
+  hi DiffviewStatusModified guifg=#004b39 ctermfg=23
+  hi DiffviewStatusRenamed guifg=#004b39 ctermfg=23
+  hi DiffviewStatusCopied guifg=#004b39 ctermfg=23
+  hi DiffviewStatusTypeChanged guifg=#004b39 ctermfg=23
+  hi DiffviewStatusUnmerged guifg=#8f4b90 ctermfg=96
+  hi DiffviewStatusUnknown guifg=#534f34 ctermfg=239
+  hi DiffviewStatusDeleted guifg=#606584 ctermfg=60
+  hi DiffviewStatusBroken guifg=#8f4b90 ctermfg=96
+  hi DiffviewStatusIgnored guifg=#534f34 ctermfg=239
+  hi DiffviewFilePanelRootPath guifg=#616bb0 ctermfg=61
+  hi DiffviewFilePanelTitle guifg=#3c4c8d ctermfg=61 gui=bold cterm=bold
+  hi DiffviewFilePanelCounter guifg=#606584 ctermfg=60 gui=bold cterm=bold
+  hi DiffviewFilePanelFileName guifg=#464b65 ctermfg=60
+  hi DiffviewFilePanelPath guifg=#868bbe ctermfg=103 gui=bold cterm=bold
+  hi DiffviewFilePanelSelected guifg=#534f34 ctermfg=239
+  hi DiffviewFilePanelInsertions guifg=#004b39 ctermfg=23
+  hi DiffviewFilePanelDeletions guifg=#8f4b90 ctermfg=96
+  hi DiffviewFilePanelConflicts guifg=#74564f ctermfg=95
+  hi DiffviewHash guifg=#616bb0 ctermfg=61
+  hi ALEWarningSign guifg=#74564f ctermfg=95 gui=bold cterm=bold
+  hi ALEInfoSign guifg=#003826 ctermfg=23 gui=NONE cterm=NONE
+  hi NullLsInfoBorder guifg=#868bbe ctermfg=103 guibg=#d3d4e8 ctermbg=189 gui=NONE cterm=NONE
+  hi CocErrorSign guifg=#7a1e28 ctermfg=52 gui=bold cterm=bold
+  hi CocWarningSign guifg=#74564f ctermfg=95 gui=bold cterm=bold
+  hi CocInfoSign guifg=#003826 ctermfg=23 gui=bold cterm=bold
+  hi CocHintSign guifg=#003826 ctermfg=23 gui=bold cterm=bold
+  hi LspError guifg=#7a1e28 ctermfg=52
+  hi LspErrorText guifg=#7a1e28 ctermfg=52 gui=bold cterm=bold
+  hi LspErrorHighlight guisp=#7a1e28 gui=undercurl cterm=undercurl
+  hi LspErrorVirtualText guifg=#7a1e28 ctermfg=52 gui=bold cterm=bold
+  hi LspWarning guifg=#74564f ctermfg=95
+  hi LspWarningText guifg=#74564f ctermfg=95 gui=bold cterm=bold
+  hi LspWarningHighlight guisp=#74564f gui=undercurl cterm=undercurl
+  hi LspWarningVirtualText guifg=#74564f ctermfg=95 gui=bold cterm=bold
+  hi LspInformation guifg=#003826 ctermfg=23
+  hi LspInformationText guifg=#003826 ctermfg=23 gui=bold cterm=bold
+  hi LspInformationHighlight guisp=#003826 gui=undercurl cterm=undercurl
+  hi LspInformationVirtualText guifg=#868bbe ctermfg=103 gui=bold cterm=bold
+  hi LspHint guifg=#003826 ctermfg=23
+  hi LspHintText guifg=#003826 ctermfg=23 gui=bold cterm=bold
+  hi LspHintHighlight guisp=#003826 gui=undercurl cterm=undercurl
+  hi LspHintVirtualText guifg=#868bbe ctermfg=103 gui=bold cterm=bold
+  hi LspCodeActionText guifg=#616bb0 ctermfg=61 gui=bold cterm=bold
+  hi CmpItemAbbr guifg=#464b65 ctermfg=60
+  hi CmpItemAbbrMatch guifg=#3c4c8d ctermfg=61 gui=bold cterm=bold
+  hi! link CmpItemAbbrMatchFuzzy CmpItemAbbrMatch
+  hi CmpItemAbbrDeprecated guifg=#868bbe ctermfg=103 gui=strikethrough cterm=strikethrough
+  hi CmpItemMenu guifg=#868bbe ctermfg=103 gui=italic cterm=italic
+  hi CmpItemKind guifg=#606584 ctermfg=60
+  hi CmpItemKindText guifg=#606584 ctermfg=60
+  hi CmpItemKindVariable guifg=#004b39 ctermfg=23
+  hi CmpItemKindConstant guifg=#004b39 ctermfg=23
+  hi CmpItemKindEnum guifg=#004b39 ctermfg=23
+  hi CmpItemKindInterface guifg=#004b39 ctermfg=23
+  hi CmpItemKindClass guifg=#004b39 ctermfg=23
+  hi CmpItemKindFunction guifg=#74564f ctermfg=95
+  hi CmpItemKindMethod guifg=#74564f ctermfg=95
+  hi CmpItemKindModule guifg=#74564f ctermfg=95
+  hi CmpItemKindConstructor guifg=#74564f ctermfg=95
+  hi CmpItemKindKeyword guifg=#534f34 ctermfg=239
+  hi CmpItemKindProperty guifg=#534f34 ctermfg=239
+  hi CmpItemKindField guifg=#534f34 ctermfg=239
+  hi CmpItemKindUnit guifg=#534f34 ctermfg=239
+  hi BlinkCmpMenu guifg=#464b65 ctermfg=60
+  hi BlinkCmpMenuSelection guibg=#a8a9f0 ctermbg=147
+  hi BlinkCmpLabelMatch guifg=#3c4c8d ctermfg=61 gui=bold cterm=bold
+  hi BlinkCmpLabelDeprecated guifg=#868bbe ctermfg=103 gui=strikethrough cterm=strikethrough
+  hi BlinkCmpKind guifg=#606584 ctermfg=60
+  hi BlinkCmpKindText guifg=#606584 ctermfg=60
+  hi BlinkCmpKindVariable guifg=#004b39 ctermfg=23
+  hi BlinkCmpKindConstant guifg=#004b39 ctermfg=23
+  hi BlinkCmpKindEnum guifg=#004b39 ctermfg=23
+  hi BlinkCmpKindInterface guifg=#004b39 ctermfg=23
+  hi BlinkCmpKindClass guifg=#004b39 ctermfg=23
+  hi BlinkCmpKindFunction guifg=#74564f ctermfg=95
+  hi BlinkCmpKindMethod guifg=#74564f ctermfg=95
+  hi BlinkCmpKindModule guifg=#74564f ctermfg=95
+  hi BlinkCmpKindConstructor guifg=#74564f ctermfg=95
+  hi BlinkCmpKindKeyword guifg=#534f34 ctermfg=239
+  hi BlinkCmpKindProperty guifg=#534f34 ctermfg=239
+  hi BlinkCmpKindField guifg=#534f34 ctermfg=239
+  hi BlinkCmpKindUnit guifg=#534f34 ctermfg=239
+  hi BlinkCmpSource guifg=#868bbe ctermfg=103 gui=italic cterm=italic
+  hi BlinkCmpDocSeparator guifg=#868bbe ctermfg=103
+  hi BlinkCmpMenuBorder guifg=#868bbe ctermfg=103 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi BlinkCmpDocBorder guifg=#868bbe ctermfg=103 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi BlinkCmpSignatureHelpBorder guifg=#868bbe ctermfg=103 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi TelescopeNormal guifg=#606584 ctermfg=60
+  hi TelescopeTitle guifg=#3c4c8d ctermfg=61
+  hi TelescopeMatching guifg=#29314c ctermfg=236 gui=bold cterm=bold
+  hi TelescopeBorder guifg=#868bbe ctermfg=103
+  hi TelescopePromptPrefix guifg=#004b39 ctermfg=23
+  hi TelescopePromptCounter guifg=#868bbe ctermfg=103
+  hi TelescopeMultiIcon guifg=#534f34 ctermfg=239
+  hi TelescopeMultiSelection guifg=#534f34 ctermfg=239
+  hi SnacksNormal guifg=#606584 ctermfg=60
+  hi SnacksPickerPrompt guifg=#004b39 ctermfg=23
+  hi SnacksPickerMatch guifg=#3c4c8d ctermfg=61
+  hi SnacksPickerDir guifg=#868bbe ctermfg=103
+  hi CopilotSuggestion guifg=#868bbe ctermfg=103
+  hi CleverFChar guifg=#213a7a ctermfg=25 guibg=#5667d8 ctermbg=62 gui=underline cterm=underline
+  hi MiniJump guifg=#213a7a ctermfg=25 guibg=#5667d8 ctermbg=62 gui=underline cterm=underline
+  hi ConflictMarkerBegin guibg=#2b6a59 ctermbg=23 gui=bold cterm=bold
+  hi ConflictMarkerOurs guibg=#96baad ctermbg=109 gui=NONE cterm=NONE
+  hi ConflictMarkerTheirs guibg=#a4c1d6 ctermbg=110 gui=NONE cterm=NONE
+  hi ConflictMarkerEnd guibg=#5285a3 ctermbg=67 gui=bold cterm=bold
+  hi ConflictMarkerSeparator guifg=#b2b4d8 ctermfg=146 gui=bold cterm=bold
+  hi EasyMotionTarget guifg=#534f34 ctermfg=239 gui=bold cterm=bold
+  hi EasyMotionShade guifg=#868bbe ctermfg=103 guibg=#d3d4e8 ctermbg=189
+  hi EasyMotionIncCursor guifg=#464b65 ctermfg=60 guibg=#d3d4e8 ctermbg=189
+  hi HopNextKey guifg=#004b39 ctermfg=23 gui=bold cterm=bold
+  hi! link HopNextKey1 HopNextKey
+  hi HopNextKey2 guifg=#2b6a59 ctermfg=23 gui=bold cterm=bold
+  hi HopUnmatched guifg=#868bbe ctermfg=103
+  hi FlashPrompt guifg=#3c4c8d ctermfg=61 gui=bold cterm=bold
+  hi FlashPromptIcon guifg=#004b39 ctermfg=23 gui=bold cterm=bold
+  hi FlashLabel guifg=#004b39 ctermfg=23 gui=bold cterm=bold
+  hi FidgetTitle guifg=#004b39 ctermfg=23 gui=bold cterm=bold
+  hi FidgetTask guifg=#868bbe ctermfg=103
+  hi HlSearchLens guifg=#868bbe ctermfg=103 gui=italic cterm=italic
+  hi! link HlSearchLensNear HlSearchLens
+  hi NotifyBackground guibg=#d3d4e8 ctermbg=189
+  hi NotifyERRORBorder guifg=#7d4445 ctermfg=95
+  hi NotifyWARNBorder guifg=#8a7d78 ctermfg=8
+  hi NotifyINFOBorder guifg=#477165 ctermfg=6
+  hi NotifyDEBUGBorder guifg=#686a72 ctermfg=242
+  hi NotifyTRACEBorder guifg=#477165 ctermfg=6
+  hi NotifyERRORIcon guifg=#7a1e28 ctermfg=52
+  hi NotifyWARNIcon guifg=#74564f ctermfg=95
+  hi NotifyINFOIcon guifg=#003826 ctermfg=23
+  hi NotifyDEBUGIcon guifg=#464b65 ctermfg=60
+  hi NotifyTRACEIcon guifg=#003826 ctermfg=23
+  hi NotifyERRORTitle guifg=#7a1e28 ctermfg=52
+  hi NotifyWARNTitle guifg=#74564f ctermfg=95
+  hi NotifyINFOTitle guifg=#003826 ctermfg=23
+  hi NotifyDEBUGTitle guifg=#464b65 ctermfg=60
+  hi NotifyTRACETitle guifg=#003826 ctermfg=23
+  hi NotifyERRORBody guifg=#464b65 ctermfg=60
+  hi NotifyWARNBody guifg=#464b65 ctermfg=60
+  hi NotifyINFOBody guifg=#464b65 ctermfg=60
+  hi NotifyDEBUGBody guifg=#464b65 ctermfg=60
+  hi NotifyTRACEBody guifg=#464b65 ctermfg=60
+  hi AvanteTitle guifg=#d3d4e8 ctermfg=189 guibg=#3c4c8d ctermbg=61
+  hi AvanteReversedTitle guifg=#3c4c8d ctermfg=61 guibg=#d3d4e8 ctermbg=189
+  hi AvanteSubtitle guifg=#d3d4e8 ctermfg=189 guibg=#004b39 ctermbg=23
+  hi AvanteReversedSubtitle guifg=#004b39 ctermfg=23 guibg=#d3d4e8 ctermbg=189
+  hi AvanteThirdTitle guifg=#464b65 ctermfg=60 guibg=#babbd8 ctermbg=146
+  hi AvanteReversedThirdTitle guifg=#babbd8 ctermfg=146 guibg=#d3d4e8 ctermbg=189
+  hi AvantePopupHint guifg=#868bbe ctermfg=103
+  hi AvanteInlineHint guifg=#868bbe ctermfg=103
+  hi AvanteSidebarWinSeparator guifg=#b2b4d8 ctermfg=146 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi AvanteSidebarWinHorizontalSeparator guifg=#d3d4e8 ctermfg=189 guibg=#d3d4e8 ctermbg=189 gui=NONE cterm=NONE
+  if has("nvim")
+    hi NormalFloat guifg=#606584 ctermfg=60 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+    hi FloatBorder guifg=#868bbe ctermfg=103 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+    hi WinSeparator guifg=#b2b4d8 ctermfg=146 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+    hi DiagnosticError guifg=#7a1e28 ctermfg=52
+    hi DiagnosticVirtualTextError guifg=#7a1e28 ctermfg=52 gui=bold cterm=bold
+    hi DiagnosticUnderlineError guifg=#7a1e28 ctermfg=52 guisp=#7a1e28 gui=undercurl cterm=undercurl
+    hi DiagnosticWarn guifg=#74564f ctermfg=95
+    hi DiagnosticVirtualTextWarn guifg=#74564f ctermfg=95 gui=bold cterm=bold
+    hi DiagnosticUnderlineWarn guifg=#74564f ctermfg=95 guisp=#74564f gui=undercurl cterm=undercurl
+    hi DiagnosticInfo guifg=#003826 ctermfg=23
+    hi DiagnosticVirtualTextInfo guifg=#868bbe ctermfg=103 gui=bold cterm=bold
+    hi DiagnosticUnderlineInfo guisp=#003826 gui=undercurl cterm=undercurl
+    hi DiagnosticHint guifg=#003826 ctermfg=23
+    hi DiagnosticOk guifg=#003826 ctermfg=23
+    hi DiagnosticVirtualTextHint guifg=#868bbe ctermfg=103 gui=bold cterm=bold
+    hi DiagnosticUnderlineHint guisp=#003826 gui=undercurl cterm=undercurl
+    hi LspSignatureActiveParameter gui=italic cterm=italic
+    hi LspReferenceText guibg=#bfc1dc ctermbg=146
+    hi LspReferenceRead guibg=#bfc1dc ctermbg=146
+    hi LspReferenceWrite guibg=#bfc1dc ctermbg=146
+    hi FloatTitle guifg=#3c4c8d ctermfg=61 guibg=#d3d4e8 ctermbg=189 gui=NONE cterm=NONE
+    let g:terminal_color_0 = '#edeef9'
+    let g:terminal_color_1 = '#893338'
+    let g:terminal_color_2 = '#044e25'
+    let g:terminal_color_3 = '#6a654d'
+    let g:terminal_color_4 = '#317193'
+    let g:terminal_color_5 = '#5c69bb'
+    let g:terminal_color_6 = '#004b39'
+    let g:terminal_color_7 = '#464b65'
+    let g:terminal_color_8 = '#868bbe'
+    let g:terminal_color_9 = '#8f4b90'
+    let g:terminal_color_10 = '#044e25'
+    let g:terminal_color_11 = '#534f34'
+    let g:terminal_color_12 = '#006085'
+    let g:terminal_color_13 = '#3c4c8d'
+    let g:terminal_color_14 = '#005556'
+    let g:terminal_color_15 = '#464b65'
+    let g:terminal_color_background = g:terminal_color_0
+    let g:terminal_color_foreground = g:terminal_color_7
+  endif
+  if has("nvim-0.8.0")
+    hi @string guifg=#044e25 ctermfg=22
+    hi! link @string.regex @string
+    hi @string.escape guifg=#8f4b90 ctermfg=96
+    hi @string.special.url guifg=#868bbe ctermfg=103
+    hi @text.title guifg=#534f34 ctermfg=239 gui=bold cterm=bold
+    hi @text.reference guifg=#3c4c8d ctermfg=61
+    hi @text.uri guifg=#868bbe ctermfg=103
+    hi @text.strong gui=bold cterm=bold
+    hi @text.literal guifg=#004b39 ctermfg=23
+    hi @parameter guifg=#3c4c8d ctermfg=61
+    hi @property guifg=#3c4c8d ctermfg=61
+    hi @keyword guifg=#8f4b90 ctermfg=96
+    hi @operator guifg=#3c4c8d ctermfg=61
+    hi @module guifg=#464b65 ctermfg=60
+    hi @type guifg=#74564f ctermfg=95
+    hi! link @type.builtin @type
+    hi @function.tsx guifg=#464b65 ctermfg=60
+    hi @punctuation.special.typescript guifg=#606584 ctermfg=60
+    hi @include guifg=#3c4c8d ctermfg=61
+    hi @variable guifg=#464b65 ctermfg=60
+    hi @variable.builtin guifg=#74564f ctermfg=95
+    hi @constant.builtin guifg=#004b39 ctermfg=23
+    hi @constructor guifg=#464b65 ctermfg=60
+    hi @tag guifg=#464b65 ctermfg=60
+    hi @tag.delimiter guifg=#3c4c8d ctermfg=61
+    hi @tag.attribute guifg=#3c4c8d ctermfg=61
+    hi @tag.builtin.tsx guifg=#464b65 ctermfg=60
+    hi @markup.heading guifg=#534f34 ctermfg=239 gui=bold cterm=bold
+    hi @markup.strong gui=bold cterm=bold
+    hi @markup.list guifg=#868bbe ctermfg=103
+    hi @markup.raw guifg=#004b39 ctermfg=23
+    hi @markup.link guifg=#3c4c8d ctermfg=61
+    hi @markup.link.url guifg=#606584 ctermfg=60
+    hi @markup.quote guifg=#868bbe ctermfg=103
+    hi @lsp.type.class guifg=#464b65 ctermfg=60
+    hi @lsp.type.interface guifg=#74564f ctermfg=95
+    hi @lsp.type.parameter guifg=#3c4c8d ctermfg=61
+    hi @lsp.type.property guifg=#3c4c8d ctermfg=61
+    hi @lsp.type.struct guifg=#464b65 ctermfg=60
+    hi @lsp.type.type guifg=#74564f ctermfg=95
+    hi @lsp.type.typeParameter guifg=#464b65 ctermfg=60
+    hi @lsp.type.variable guifg=#464b65 ctermfg=60
+    hi @lsp.type.member guifg=#3c4c8d ctermfg=61
+    hi @lsp.type.namespace guifg=#464b65 ctermfg=60
+  endif
+  let g:defx_icons_gui_colors = {
+    \ 'brown': 'c94b4c',
+    \ 'aqua': '226667',
+    \ 'blue': '366a86',
+    \ 'darkBlue': '638193',
+    \ 'purple': '616bb0',
+    \ 'lightPurple': '474f79',
+    \ 'red': '893338',
+    \ 'beige': '868685',
+    \ 'yellow': '6a654d',
+    \ 'orange': '614139',
+    \ 'darkOrange': 'ab877e',
+    \ 'pink': '9f619f',
+    \ 'salmon': 'aa57aa',
+    \ 'green': '396d48',
+    \ 'lightGreen': '196734',
+    \ 'white': '5b5f77',
+    \ }
+  let g:defx_icons_term_colors = {
+    \ 'brown': 167,
+    \ 'aqua': 23,
+    \ 'blue': 24,
+    \ 'darkBlue': 67,
+    \ 'purple': 61,
+    \ 'lightPurple': 60,
+    \ 'red': 1,
+    \ 'beige': 102,
+    \ 'yellow': 241,
+    \ 'orange': 95,
+    \ 'darkOrange': 138,
+    \ 'pink': 133,
+    \ 'salmon': 133,
+    \ 'green': 29,
+    \ 'lightGreen': 22,
+    \ 'white': 60,
+    \ }
+else
+  hi Normal guifg=#9ea3c0 ctermfg=146 guibg=#222433 ctermbg=235
+  hi Delimiter guifg=#8186a6 ctermfg=103
+  hi NonText guifg=#373b59 ctermfg=60 guibg=NONE ctermbg=NONE
+  hi VertSplit guifg=#33364d ctermfg=237 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi LineNr guifg=#33364d ctermfg=237 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi EndOfBuffer guifg=#373b59 ctermfg=60 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi Comment guifg=#565d8c ctermfg=60 gui=NONE cterm=NONE
+  hi Cursor guifg=#222433 ctermfg=235 guibg=#9ea3c0 ctermbg=146
+  hi! link CursorIM Cursor
+  hi SignColumn guifg=#565d8c ctermfg=60 guibg=NONE ctermbg=NONE
+  hi ColorColumn guibg=#2b2e41 ctermbg=236 gui=NONE cterm=NONE
+  hi CursorColumn guibg=#2b2e41 ctermbg=236 gui=NONE cterm=NONE
+  hi CursorLine guibg=#2b2e41 ctermbg=236 gui=NONE cterm=NONE
+  hi CursorLineNr guifg=#575f99 ctermfg=61 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi Conceal guifg=#ac8b83 ctermfg=138 guibg=#222433 ctermbg=235 gui=NONE cterm=NONE
+  hi Folded guifg=#666c99 ctermfg=60 guibg=#33364d ctermbg=237 gui=NONE cterm=NONE
+  hi FoldColumn guifg=#33364d ctermfg=237 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi MatchParen guibg=#2f3248 ctermbg=236
+  hi Directory guifg=#929be5 ctermfg=104
+  hi Underlined gui=underline cterm=underline
+  hi String guifg=#7cbe8c ctermfg=108
+  hi Statement guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
+  hi Label guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
+  hi Function guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
+  hi Constant guifg=#73c1a9 ctermfg=79
+  hi Boolean guifg=#73c1a9 ctermfg=79
+  hi Number guifg=#73c1a9 ctermfg=79
+  hi Float guifg=#73c1a9 ctermfg=79
+  hi Title guifg=#a8a384 ctermfg=144 gui=bold cterm=bold
+  hi Keyword guifg=#ac8b83 ctermfg=138
+  hi Identifier guifg=#ac8b83 ctermfg=138
+  hi Exception guifg=#a8a384 ctermfg=144
+  hi Type guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
+  hi TypeDef guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
+  hi PreProc guifg=#929be5 ctermfg=104
+  hi Special guifg=#b871b8 ctermfg=133
+  hi SpecialKey guifg=#b871b8 ctermfg=133
+  hi SpecialChar guifg=#b871b8 ctermfg=133
+  hi SpecialComment guifg=#b871b8 ctermfg=133
+  hi Error guifg=#ff9494 ctermfg=210 guibg=#222433 ctermbg=235 gui=bold cterm=bold
+  hi ErrorMsg guifg=#ff9494 ctermfg=210 guibg=NONE ctermbg=NONE gui=bold cterm=bold
+  hi WarningMsg guifg=#ac8b83 ctermfg=138 gui=bold cterm=bold
+  hi MoreMsg guifg=#73c1a9 ctermfg=79
+  hi ModeMsg guifg=#73c1a9 ctermfg=79
+  hi Debug guifg=#9ea3c0 ctermfg=146
+  hi Todo guifg=#a8a384 ctermfg=144 guibg=NONE ctermbg=NONE gui=bold cterm=bold
+  hi Pmenu guifg=#9ea3c0 ctermfg=146 guibg=#33364d ctermbg=237
+  hi PmenuSel guifg=#9ea3c0 ctermfg=146 guibg=#363e7a ctermbg=60 gui=NONE cterm=NONE
+  hi PmenuMatch guifg=#929be5 ctermfg=104 gui=bold cterm=bold
+  hi PmenuSbar guibg=#2b2d40 ctermbg=236
+  hi PmenuThumb guibg=#484f80 ctermbg=60
+  hi Visual guibg=#363e7a ctermbg=60 gui=NONE cterm=NONE
+  hi Search guifg=#a7b1ff ctermfg=147 guibg=#6472e5 ctermbg=63
+  hi! link CurSearch Search
+  hi IncSearch guifg=#a7b8ff ctermfg=147 guibg=#4957cc ctermbg=62 gui=NONE cterm=NONE
+  hi Question guifg=#73c1a9 ctermfg=79 gui=bold cterm=bold
+  hi WildMenu guifg=#222433 ctermfg=235 guibg=#929be5 ctermbg=104
+  hi SpellBad guifg=#ff9494 ctermfg=210 gui=underline cterm=underline
+  hi SpellCap gui=underline cterm=underline
+  hi SpellLocal guifg=#ff9494 ctermfg=210 gui=underline cterm=underline
+  hi SpellRare guifg=#a8a384 ctermfg=144 gui=underline cterm=underline
+  hi Added guifg=NONE ctermfg=NONE guibg=#1d3350 ctermbg=236 gui=NONE cterm=NONE
+  hi Removed guifg=#735a81 ctermfg=96 guibg=#4c325b ctermbg=53 gui=NONE cterm=NONE
+  hi Changed guifg=NONE ctermfg=NONE guibg=#2a4b48 ctermbg=23 gui=NONE cterm=NONE
+  hi DiffAdd guifg=NONE ctermfg=NONE guibg=#1d3350 ctermbg=236
+  hi DiffChange guifg=NONE ctermfg=NONE guibg=#2a4b48 ctermbg=23
+  hi DiffDelete guifg=#735a81 ctermfg=96 guibg=#4c325b ctermbg=53
+  hi DiffText guifg=NONE ctermfg=NONE guibg=#387154 ctermbg=29
+  hi QuickFixLine guifg=#9ea3c0 ctermfg=146 guibg=#363e7a ctermbg=60
+  hi StatusLine guifg=#777ca6 ctermfg=103 guibg=#2b2d40 ctermbg=236 gui=bold cterm=bold
+  hi! link StatusLineTerm StatusLine
+  hi StatusLineNC guifg=#4c506e ctermfg=60 guibg=#292b3b ctermbg=236 gui=NONE cterm=NONE
+  hi! link StatusLineTermNC StatusLineNC
+  hi TabLine guifg=#777ca6 ctermfg=103 guibg=#2b2d40 ctermbg=236 gui=NONE cterm=NONE
+  hi TabLineFill guifg=#777ca6 ctermfg=103 guibg=#2b2d40 ctermbg=236 gui=NONE cterm=NONE
+  hi TabLineSel guifg=#222433 ctermfg=235 guibg=#929be5 ctermbg=104 gui=bold cterm=bold
+  hi qfFileName guifg=#73c1a9 ctermfg=79
+  hi qfLineNr guifg=#565d8c ctermfg=60
+  hi htmlTag guifg=#8186a6 ctermfg=103
+  hi htmlEndTag guifg=#8186a6 ctermfg=103
+  hi htmlSpecialTagName guifg=#ac8b83 ctermfg=138
+  hi htmlArg guifg=#8186a6 ctermfg=103
+  hi jsonQuote guifg=#8186a6 ctermfg=103
+  hi yamlBlockMappingKey guifg=#929be5 ctermfg=104
+  hi yamlAnchor guifg=#b871b8 ctermfg=133
+  hi pythonStatement guifg=#ac8b83 ctermfg=138
+  hi pythonBuiltin guifg=#59b6b6 ctermfg=73
+  hi pythonRepeat guifg=#ac8b83 ctermfg=138
+  hi pythonOperator guifg=#ac8b83 ctermfg=138
+  hi pythonDecorator guifg=#b871b8 ctermfg=133
+  hi pythonDecoratorName guifg=#b871b8 ctermfg=133
+  hi zshVariableDef guifg=#929be5 ctermfg=104
+  hi zshFunction guifg=#929be5 ctermfg=104
+  hi zshKSHFunction guifg=#929be5 ctermfg=104
+  hi cPreCondit guifg=#ac8b83 ctermfg=138
+  hi cIncluded guifg=#b871b8 ctermfg=133
+  hi cStorageClass guifg=#ac8b83 ctermfg=138
+  hi cppStructure guifg=#b871b8 ctermfg=133
+  hi cppSTLnamespace guifg=#ac8b83 ctermfg=138
+  hi csStorage guifg=#ac8b83 ctermfg=138
+  hi csModifier guifg=#929be5 ctermfg=104
+  hi csClass guifg=#929be5 ctermfg=104
+  hi csClassType guifg=#b871b8 ctermfg=133
+  hi csNewType guifg=#ac8b83 ctermfg=138
+  hi rubyConstant guifg=#ac8b83 ctermfg=138
+  hi rubySymbol guifg=#929be5 ctermfg=104
+  hi rubyBlockParameter guifg=#929be5 ctermfg=104
+  hi rubyClassName guifg=#b871b8 ctermfg=133
+  hi rubyInstanceVariable guifg=#b871b8 ctermfg=133
+  hi typescriptImport guifg=#929be5 ctermfg=104
+  hi typescriptDocRef guifg=#565d8c ctermfg=60 gui=underline cterm=underline
+  hi mkdHeading guifg=#565d8c ctermfg=60
+  hi mkdLink guifg=#929be5 ctermfg=104
+  hi mkdCode guifg=#929be5 ctermfg=104
+  hi mkdCodeStart guifg=#929be5 ctermfg=104
+  hi mkdCodeEnd guifg=#929be5 ctermfg=104
+  hi mkdCodeDelimiter guifg=#929be5 ctermfg=104
+  hi tomlTable guifg=#929be5 ctermfg=104
+  hi rustModPath guifg=#929be5 ctermfg=104
+  hi rustTypedef guifg=#929be5 ctermfg=104
+  hi rustStructure guifg=#929be5 ctermfg=104
+  hi rustMacro guifg=#929be5 ctermfg=104
+  hi rustExternCrate guifg=#929be5 ctermfg=104
+  hi graphqlStructure guifg=#b871b8 ctermfg=133
+  hi graphqlDirective guifg=#b871b8 ctermfg=133
+  hi graphqlName guifg=#929be5 ctermfg=104
+  hi graphqlTemplateString guifg=#9ea3c0 ctermfg=146
+  hi vimfilerOpenedFile guifg=#7079bf ctermfg=104
+  hi vimfilerClosedFile guifg=#7079bf ctermfg=104
+  hi vimfilerNonMark guifg=#73c1a9 ctermfg=79
+  hi vimfilerLeaf guifg=#73c1a9 ctermfg=79
+  hi DefxIconsMarkIcon guifg=#7079bf ctermfg=104 gui=NONE cterm=NONE
+  hi DefxIconsDirectory guifg=#7079bf ctermfg=104 gui=NONE cterm=NONE
+  hi DefxIconsParentDirectory guifg=#7079bf ctermfg=104 gui=NONE cterm=NONE
+  hi DefxIconsSymlinkDirectory guifg=#73c1a9 ctermfg=79 gui=NONE cterm=NONE
+  hi DefxIconsOpenedTreeIcon guifg=#7079bf ctermfg=104 gui=NONE cterm=NONE
+  hi DefxIconsNestedTreeIcon guifg=#7079bf ctermfg=104 gui=NONE cterm=NONE
+  hi DefxIconsClosedTreeIcon guifg=#7079bf ctermfg=104 gui=NONE cterm=NONE
+  hi Defx_git_Untracked guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
+  hi Defx_git_Ignored guifg=#565d8c ctermfg=60 gui=NONE cterm=NONE
+  hi Defx_git_Unknown guifg=#565d8c ctermfg=60 gui=NONE cterm=NONE
+  hi Defx_git_Renamed guifg=#2a4b48 ctermfg=23
+  hi Defx_git_Modified guifg=#2a4b48 ctermfg=23
+  hi Defx_git_Unmerged guifg=#b871b8 ctermfg=133
+  hi Defx_git_Deleted guifg=#4c325b ctermfg=53
+  hi Defx_git_Staged guifg=#73c1a9 ctermfg=79
+  hi NvimTreeSymlink guifg=#5c9b88 ctermfg=72 gui=NONE cterm=NONE
+  hi NvimTreeSymlinkFolderName guifg=#5c9b88 ctermfg=72 gui=NONE cterm=NONE
+  hi NvimTreeFolderName guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
+  hi NvimTreeRootFolder guifg=#484d7a ctermfg=60 gui=bold cterm=bold
+  hi NvimTreeFolderIcon guifg=#7079bf ctermfg=104 gui=NONE cterm=NONE
+  hi NvimTreeFileIcon guifg=#7079bf ctermfg=104 gui=NONE cterm=NONE
+  hi NvimTreeEmptyFolderName guifg=#565d8c ctermfg=60 gui=NONE cterm=NONE
+  hi NvimTreeOpenedFolderName guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
+  hi NvimTreeExecFile guifg=#8186a6 ctermfg=103 gui=NONE cterm=NONE
+  hi NvimTreeOpenedHL guifg=#8186a6 ctermfg=103 gui=NONE cterm=NONE
+  hi NvimTreeSpecialFile guifg=#8186a6 ctermfg=103 gui=bold cterm=bold
+  hi NvimTreeImageFile guifg=#8186a6 ctermfg=103 gui=NONE cterm=NONE
+  hi NvimTreeIndentMarker guifg=#484d7a ctermfg=60 gui=NONE cterm=NONE
+  hi NvimTreeModifiedIcon guifg=#9ea3c0 ctermfg=146 gui=NONE cterm=NONE
+  hi NvimTreeGitDirtyIcon guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
+  hi NvimTreeGitStagedIcon guifg=#7cbe8c ctermfg=108 gui=NONE cterm=NONE
+  hi NvimTreeGitMergeIcon guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
+  hi NvimTreeGitRenamedIcon guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
+  hi NvimTreeGitNewIcon guifg=#73c1a9 ctermfg=79 gui=NONE cterm=NONE
+  hi NvimTreeGitDeletedIcon guifg=#387154 ctermfg=29 gui=NONE cterm=NONE
+  hi NvimTreeWindowPicker guifg=#222433 ctermfg=235 guibg=#929be5 ctermbg=104 gui=bold cterm=bold
+  hi NvimTreeNormal guifg=#8186a6 ctermfg=103 gui=NONE cterm=NONE
+  hi NvimTreeLiveFilterPrefix guifg=#5c9b88 ctermfg=72 gui=NONE cterm=NONE
+  hi NvimTreeLiveFilterValue guifg=#73c1a9 ctermfg=79 gui=NONE cterm=NONE
+  hi NvimTreeBookmarkIcon guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
+  hi FylerBlue guifg=#589ec6 ctermfg=74 gui=NONE cterm=NONE
+  hi FylerGreen guifg=#73c1a9 ctermfg=79 gui=NONE cterm=NONE
+  hi FylerGrey guifg=#9ea3c0 ctermfg=146 gui=NONE cterm=NONE
+  hi FylerRed guifg=#ff9494 ctermfg=210 gui=NONE cterm=NONE
+  hi FylerYellow guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
+  hi FylerNormal guifg=#8186a6 ctermfg=103 gui=NONE cterm=NONE
+  hi FylerNormalNC guifg=#8186a6 ctermfg=103 gui=NONE cterm=NONE
+  hi FylerBorder guifg=#565d8c ctermfg=60 gui=NONE cterm=NONE
+  hi FylerFSDirectoryIcon guifg=#7079bf ctermfg=104 gui=bold cterm=bold
+  hi FylerFSDirectoryName guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
+  hi FylerFSFile guifg=#8186a6 ctermfg=103 gui=NONE cterm=NONE
+  hi FylerFSLink guifg=#5c9b88 ctermfg=72 gui=NONE cterm=NONE
+  hi FylerGitAdded guifg=#9ea3c0 ctermfg=146 gui=NONE cterm=NONE
+  hi FylerGitConflict gui=NONE cterm=NONE
+  hi FylerGitDeleted guifg=#387154 ctermfg=29 gui=NONE cterm=NONE
+  hi FylerGitIgnored gui=NONE cterm=NONE
+  hi FylerGitModified gui=NONE cterm=NONE
+  hi FylerGitRenamed guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
+  hi FylerGitStaged guifg=#7cbe8c ctermfg=108 gui=NONE cterm=NONE
+  hi FylerGitUnstaged gui=NONE cterm=NONE
+  hi FylerGitUntracked gui=NONE cterm=NONE
+  hi FylerIndentMarker guifg=#565d8c ctermfg=60 gui=NONE cterm=NONE
+  hi FylerWinPick gui=NONE cterm=NONE
+  hi EdaNormal guifg=#8186a6 ctermfg=103 gui=NONE cterm=NONE
+  hi EdaNormalNC guifg=#8186a6 ctermfg=103 gui=NONE cterm=NONE
+  hi EdaBorder guifg=#565d8c ctermfg=60 gui=NONE cterm=NONE
+  hi EdaTitle guifg=#929be5 ctermfg=104 gui=bold cterm=bold
+  hi EdaCursorLine guibg=#2b2e41 ctermbg=236 gui=NONE cterm=NONE
+  hi EdaIndentMarker guifg=#484d7a ctermfg=60 gui=NONE cterm=NONE
+  hi EdaRootName guifg=#484d7a ctermfg=60 gui=bold cterm=bold
+  hi EdaDivider guifg=#565d8c ctermfg=60 gui=NONE cterm=NONE
+  hi EdaFilterIndicator guifg=#5c9b88 ctermfg=72 gui=NONE cterm=NONE
+  hi EdaDirectoryName guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
+  hi EdaDirectoryIcon guifg=#7079bf ctermfg=104 gui=NONE cterm=NONE
+  hi EdaOpenedDirectoryName guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
+  hi EdaEmptyDirectoryName guifg=#565d8c ctermfg=60 gui=NONE cterm=NONE
+  hi EdaFileName guifg=#8186a6 ctermfg=103 gui=NONE cterm=NONE
+  hi EdaFileIcon guifg=#7079bf ctermfg=104 gui=NONE cterm=NONE
+  hi EdaSymlink guifg=#5c9b88 ctermfg=72 gui=NONE cterm=NONE
+  hi EdaBrokenSymlink guifg=#ff9494 ctermfg=210 gui=NONE cterm=NONE
+  hi EdaSymlinkTarget guifg=#565d8c ctermfg=60 gui=NONE cterm=NONE
+  hi EdaErrorNode guifg=#ff9494 ctermfg=210 gui=NONE cterm=NONE
+  hi EdaLoadingNode guifg=#565d8c ctermfg=60 gui=italic cterm=italic
+  hi EdaOpenedFile guifg=#8186a6 ctermfg=103 gui=NONE cterm=NONE
+  hi EdaModifiedFile guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
+  hi EdaGitUntracked guifg=#73c1a9 ctermfg=79 gui=NONE cterm=NONE
+  hi! link EdaGitUntrackedIcon EdaGitUntracked
+  hi EdaGitAdded guifg=#73c1a9 ctermfg=79 gui=NONE cterm=NONE
+  hi! link EdaGitAddedIcon EdaGitAdded
+  hi EdaGitModified guifg=#bfb996 ctermfg=144 gui=NONE cterm=NONE
+  hi! link EdaGitModifiedIcon EdaGitModified
+  hi EdaGitDeleted guifg=#c076c0 ctermfg=176 gui=NONE cterm=NONE
+  hi! link EdaGitDeletedIcon EdaGitDeleted
+  hi EdaGitRenamed guifg=#bfb996 ctermfg=144 gui=NONE cterm=NONE
+  hi! link EdaGitRenamedIcon EdaGitRenamed
+  hi EdaGitStaged guifg=#7cbe8c ctermfg=108 gui=NONE cterm=NONE
+  hi! link EdaGitStagedIcon EdaGitStaged
+  hi EdaGitConflict guifg=#c09b93 ctermfg=181 gui=NONE cterm=NONE
+  hi! link EdaGitConflictIcon EdaGitConflict
+  hi EdaGitIgnored guifg=#565d8c ctermfg=60 gui=NONE cterm=NONE
+  hi! link EdaGitIgnoredIcon EdaGitIgnored
+  hi EdaMarked guifg=#a8a384 ctermfg=144 gui=bold cterm=bold
+  hi EdaCut guifg=#565d8c ctermfg=60 gui=italic cterm=italic
+  hi EdaOpDeleteSign guifg=#ff9494 ctermfg=210 gui=bold cterm=bold
+  hi EdaOpDeletePath guifg=#ff9494 ctermfg=210 gui=NONE cterm=NONE
+  hi EdaOpDeleteText guifg=#565d8c ctermfg=60 gui=NONE cterm=NONE
+  hi EdaOpCreateSign guifg=#73c1a9 ctermfg=79 gui=bold cterm=bold
+  hi EdaOpCreatePath guifg=#73c1a9 ctermfg=79 gui=NONE cterm=NONE
+  hi EdaOpCreateText guifg=#565d8c ctermfg=60 gui=NONE cterm=NONE
+  hi EdaOpMoveSign guifg=#a8a384 ctermfg=144 gui=bold cterm=bold
+  hi EdaOpMovePath guifg=#a8a384 ctermfg=144 gui=NONE cterm=NONE
+  hi EdaOpMoveText guifg=#565d8c ctermfg=60 gui=NONE cterm=NONE
+  hi FernBranchSymbol guifg=#7079bf ctermfg=104 gui=NONE cterm=NONE
+  hi FernBranchText guifg=#929be5 ctermfg=104 gui=NONE cterm=NONE
+  hi FernLeafSymbol guifg=#5c9b88 ctermfg=72 gui=NONE cterm=NONE
+  hi FernLeafText guifg=#9ea3c0 ctermfg=146 gui=NONE cterm=NONE
+  hi FernMarked guifg=#59b6b6 ctermfg=73 gui=NONE cterm=NONE
+  hi GitSignsAdd guifg=#7cbe8c ctermfg=108
+  hi GitSignsChange guifg=#a8a384 ctermfg=144
+  hi GitSignsDelete guifg=#b871b8 ctermfg=133
+  hi GitSignsChangeDelete guifg=#387154 ctermfg=29
+  hi GitGutterAdd guifg=#7cbe8c ctermfg=108
+  hi GitGutterChange guifg=#a8a384 ctermfg=144
+  hi GitGutterDelete guifg=#b871b8 ctermfg=133
+  hi GitGutterChangeDelete guifg=#387154 ctermfg=29
+  hi fugitiveHeader guifg=#73c1a9 ctermfg=79 gui=bold cterm=bold
+  hi DiffviewDim1 guifg=#565d8c ctermfg=60
+  hi DiffviewPrimary guifg=#929be5 ctermfg=104
+  hi DiffviewSecondary guifg=#b871b8 ctermfg=133
+  hi DiffviewStatusAdded guifg=#589ec6 ctermfg=74
+  hi DiffviewStatusUntracked guifg=#a8a384 ctermfg=144
+  hi DiffviewStatusModified guifg=#73c1a9 ctermfg=79
+  hi DiffviewStatusRenamed guifg=#73c1a9 ctermfg=79
+  hi DiffviewStatusCopied guifg=#73c1a9 ctermfg=79
+  hi DiffviewStatusTypeChanged guifg=#73c1a9 ctermfg=79
+  hi DiffviewStatusUnmerged guifg=#b871b8 ctermfg=133
+  hi DiffviewStatusUnknown guifg=#a8a384 ctermfg=144
+  hi DiffviewStatusDeleted guifg=#8186a6 ctermfg=103
+  hi DiffviewStatusBroken guifg=#b871b8 ctermfg=133
+  hi DiffviewStatusIgnored guifg=#a8a384 ctermfg=144
+  hi DiffviewFilePanelRootPath guifg=#7079bf ctermfg=104
+  hi DiffviewFilePanelTitle guifg=#929be5 ctermfg=104 gui=bold cterm=bold
+  hi DiffviewFilePanelCounter guifg=#8186a6 ctermfg=103 gui=bold cterm=bold
+  hi DiffviewFilePanelFileName guifg=#9ea3c0 ctermfg=146
+  hi DiffviewFilePanelPath guifg=#565d8c ctermfg=60 gui=bold cterm=bold
+  hi DiffviewFilePanelSelected guifg=#a8a384 ctermfg=144
+  hi DiffviewFilePanelInsertions guifg=#73c1a9 ctermfg=79
+  hi DiffviewFilePanelDeletions guifg=#b871b8 ctermfg=133
+  hi DiffviewFilePanelConflicts guifg=#ac8b83 ctermfg=138
+  hi DiffviewHash guifg=#7079bf ctermfg=104
+  hi ALEWarningSign guifg=#ac8b83 ctermfg=138 gui=bold cterm=bold
+  hi ALEInfoSign guifg=#82dabf ctermfg=115 gui=NONE cterm=NONE
+  hi NullLsInfoBorder guifg=#565d8c ctermfg=60 guibg=#222433 ctermbg=235 gui=NONE cterm=NONE
+  hi CocErrorSign guifg=#ff9494 ctermfg=210 gui=bold cterm=bold
+  hi CocWarningSign guifg=#ac8b83 ctermfg=138 gui=bold cterm=bold
+  hi CocInfoSign guifg=#82dabf ctermfg=115 gui=bold cterm=bold
+  hi CocHintSign guifg=#82dabf ctermfg=115 gui=bold cterm=bold
+  hi LspError guifg=#ff9494 ctermfg=210
+  hi LspErrorText guifg=#ff9494 ctermfg=210 gui=bold cterm=bold
+  hi LspErrorHighlight guisp=#ff9494 gui=undercurl cterm=undercurl
+  hi LspErrorVirtualText guifg=#ff9494 ctermfg=210 gui=bold cterm=bold
+  hi LspWarning guifg=#ac8b83 ctermfg=138
+  hi LspWarningText guifg=#ac8b83 ctermfg=138 gui=bold cterm=bold
+  hi LspWarningHighlight guisp=#ac8b83 gui=undercurl cterm=undercurl
+  hi LspWarningVirtualText guifg=#ac8b83 ctermfg=138 gui=bold cterm=bold
+  hi LspInformation guifg=#82dabf ctermfg=115
+  hi LspInformationText guifg=#82dabf ctermfg=115 gui=bold cterm=bold
+  hi LspInformationHighlight guisp=#82dabf gui=undercurl cterm=undercurl
+  hi LspInformationVirtualText guifg=#565d8c ctermfg=60 gui=bold cterm=bold
+  hi LspHint guifg=#82dabf ctermfg=115
+  hi LspHintText guifg=#82dabf ctermfg=115 gui=bold cterm=bold
+  hi LspHintHighlight guisp=#82dabf gui=undercurl cterm=undercurl
+  hi LspHintVirtualText guifg=#565d8c ctermfg=60 gui=bold cterm=bold
+  hi LspCodeActionText guifg=#7079bf ctermfg=104 gui=bold cterm=bold
+  hi CmpItemAbbr guifg=#9ea3c0 ctermfg=146
+  hi CmpItemAbbrMatch guifg=#929be5 ctermfg=104 gui=bold cterm=bold
+  hi! link CmpItemAbbrMatchFuzzy CmpItemAbbrMatch
+  hi CmpItemAbbrDeprecated guifg=#565d8c ctermfg=60 gui=strikethrough cterm=strikethrough
+  hi CmpItemMenu guifg=#565d8c ctermfg=60 gui=italic cterm=italic
+  hi CmpItemKind guifg=#8186a6 ctermfg=103
+  hi CmpItemKindText guifg=#8186a6 ctermfg=103
+  hi CmpItemKindVariable guifg=#73c1a9 ctermfg=79
+  hi CmpItemKindConstant guifg=#73c1a9 ctermfg=79
+  hi CmpItemKindEnum guifg=#73c1a9 ctermfg=79
+  hi CmpItemKindInterface guifg=#73c1a9 ctermfg=79
+  hi CmpItemKindClass guifg=#73c1a9 ctermfg=79
+  hi CmpItemKindFunction guifg=#ac8b83 ctermfg=138
+  hi CmpItemKindMethod guifg=#ac8b83 ctermfg=138
+  hi CmpItemKindModule guifg=#ac8b83 ctermfg=138
+  hi CmpItemKindConstructor guifg=#ac8b83 ctermfg=138
+  hi CmpItemKindKeyword guifg=#a8a384 ctermfg=144
+  hi CmpItemKindProperty guifg=#a8a384 ctermfg=144
+  hi CmpItemKindField guifg=#a8a384 ctermfg=144
+  hi CmpItemKindUnit guifg=#a8a384 ctermfg=144
+  hi BlinkCmpMenu guifg=#9ea3c0 ctermfg=146
+  hi BlinkCmpMenuSelection guibg=#363e7a ctermbg=60
+  hi BlinkCmpLabelMatch guifg=#929be5 ctermfg=104 gui=bold cterm=bold
+  hi BlinkCmpLabelDeprecated guifg=#565d8c ctermfg=60 gui=strikethrough cterm=strikethrough
+  hi BlinkCmpKind guifg=#8186a6 ctermfg=103
+  hi BlinkCmpKindText guifg=#8186a6 ctermfg=103
+  hi BlinkCmpKindVariable guifg=#73c1a9 ctermfg=79
+  hi BlinkCmpKindConstant guifg=#73c1a9 ctermfg=79
+  hi BlinkCmpKindEnum guifg=#73c1a9 ctermfg=79
+  hi BlinkCmpKindInterface guifg=#73c1a9 ctermfg=79
+  hi BlinkCmpKindClass guifg=#73c1a9 ctermfg=79
+  hi BlinkCmpKindFunction guifg=#ac8b83 ctermfg=138
+  hi BlinkCmpKindMethod guifg=#ac8b83 ctermfg=138
+  hi BlinkCmpKindModule guifg=#ac8b83 ctermfg=138
+  hi BlinkCmpKindConstructor guifg=#ac8b83 ctermfg=138
+  hi BlinkCmpKindKeyword guifg=#a8a384 ctermfg=144
+  hi BlinkCmpKindProperty guifg=#a8a384 ctermfg=144
+  hi BlinkCmpKindField guifg=#a8a384 ctermfg=144
+  hi BlinkCmpKindUnit guifg=#a8a384 ctermfg=144
+  hi BlinkCmpSource guifg=#565d8c ctermfg=60 gui=italic cterm=italic
+  hi BlinkCmpDocSeparator guifg=#565d8c ctermfg=60
+  hi BlinkCmpMenuBorder guifg=#565d8c ctermfg=60 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi BlinkCmpDocBorder guifg=#565d8c ctermfg=60 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi BlinkCmpSignatureHelpBorder guifg=#565d8c ctermfg=60 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi TelescopeNormal guifg=#8186a6 ctermfg=103
+  hi TelescopeTitle guifg=#929be5 ctermfg=104
+  hi TelescopeMatching guifg=#bdc3e6 ctermfg=189 gui=bold cterm=bold
+  hi TelescopeBorder guifg=#565d8c ctermfg=60
+  hi TelescopePromptPrefix guifg=#73c1a9 ctermfg=79
+  hi TelescopePromptCounter guifg=#565d8c ctermfg=60
+  hi TelescopeMultiIcon guifg=#a8a384 ctermfg=144
+  hi TelescopeMultiSelection guifg=#a8a384 ctermfg=144
+  hi SnacksNormal guifg=#8186a6 ctermfg=103
+  hi SnacksPickerPrompt guifg=#73c1a9 ctermfg=79
+  hi SnacksPickerMatch guifg=#929be5 ctermfg=104
+  hi SnacksPickerDir guifg=#565d8c ctermfg=60
+  hi CopilotSuggestion guifg=#565d8c ctermfg=60
+  hi CleverFChar guifg=#a7b1ff ctermfg=147 guibg=#6472e5 ctermbg=63 gui=underline cterm=underline
+  hi MiniJump guifg=#a7b1ff ctermfg=147 guibg=#6472e5 ctermbg=63 gui=underline cterm=underline
+  hi ConflictMarkerBegin guibg=#5c9b88 ctermbg=72 gui=bold cterm=bold
+  hi ConflictMarkerOurs guibg=#27473d ctermbg=23 gui=NONE cterm=NONE
+  hi ConflictMarkerTheirs guibg=#1e3b4c ctermbg=237 gui=NONE cterm=NONE
+  hi ConflictMarkerEnd guibg=#417593 ctermbg=31 gui=bold cterm=bold
+  hi ConflictMarkerSeparator guifg=#373b59 ctermfg=60 gui=bold cterm=bold
+  hi EasyMotionTarget guifg=#a8a384 ctermfg=144 gui=bold cterm=bold
+  hi EasyMotionShade guifg=#565d8c ctermfg=60 guibg=#222433 ctermbg=235
+  hi EasyMotionIncCursor guifg=#9ea3c0 ctermfg=146 guibg=#222433 ctermbg=235
+  hi HopNextKey guifg=#73c1a9 ctermfg=79 gui=bold cterm=bold
+  hi! link HopNextKey1 HopNextKey
+  hi HopNextKey2 guifg=#5c9b88 ctermfg=72 gui=bold cterm=bold
+  hi HopUnmatched guifg=#565d8c ctermfg=60
+  hi FlashPrompt guifg=#929be5 ctermfg=104 gui=bold cterm=bold
+  hi FlashPromptIcon guifg=#73c1a9 ctermfg=79 gui=bold cterm=bold
+  hi FlashLabel guifg=#73c1a9 ctermfg=79 gui=bold cterm=bold
+  hi FidgetTitle guifg=#73c1a9 ctermfg=79 gui=bold cterm=bold
+  hi FidgetTask guifg=#565d8c ctermfg=60
+  hi HlSearchLens guifg=#565d8c ctermfg=60 gui=italic cterm=italic
+  hi! link HlSearchLensNear HlSearchLens
+  hi NotifyBackground guibg=#222433 ctermbg=235
+  hi NotifyERRORBorder guifg=#cc8b8b ctermfg=174
+  hi NotifyWARNBorder guifg=#796c68 ctermfg=242
+  hi NotifyINFOBorder guifg=#638e81 ctermfg=66
+  hi NotifyDEBUGBorder guifg=#82848d ctermfg=102
+  hi NotifyTRACEBorder guifg=#638e81 ctermfg=66
+  hi NotifyERRORIcon guifg=#ff9494 ctermfg=210
+  hi NotifyWARNIcon guifg=#ac8b83 ctermfg=138
+  hi NotifyINFOIcon guifg=#82dabf ctermfg=115
+  hi NotifyDEBUGIcon guifg=#9ea3c0 ctermfg=146
+  hi NotifyTRACEIcon guifg=#82dabf ctermfg=115
+  hi NotifyERRORTitle guifg=#ff9494 ctermfg=210
+  hi NotifyWARNTitle guifg=#ac8b83 ctermfg=138
+  hi NotifyINFOTitle guifg=#82dabf ctermfg=115
+  hi NotifyDEBUGTitle guifg=#9ea3c0 ctermfg=146
+  hi NotifyTRACETitle guifg=#82dabf ctermfg=115
+  hi NotifyERRORBody guifg=#9ea3c0 ctermfg=146
+  hi NotifyWARNBody guifg=#9ea3c0 ctermfg=146
+  hi NotifyINFOBody guifg=#9ea3c0 ctermfg=146
+  hi NotifyDEBUGBody guifg=#9ea3c0 ctermfg=146
+  hi NotifyTRACEBody guifg=#9ea3c0 ctermfg=146
+  hi AvanteTitle guifg=#222433 ctermfg=235 guibg=#929be5 ctermbg=104
+  hi AvanteReversedTitle guifg=#929be5 ctermfg=104 guibg=#222433 ctermbg=235
+  hi AvanteSubtitle guifg=#222433 ctermfg=235 guibg=#73c1a9 ctermbg=79
+  hi AvanteReversedSubtitle guifg=#73c1a9 ctermfg=79 guibg=#222433 ctermbg=235
+  hi AvanteThirdTitle guifg=#9ea3c0 ctermfg=146 guibg=#33364d ctermbg=237
+  hi AvanteReversedThirdTitle guifg=#33364d ctermfg=237 guibg=#222433 ctermbg=235
+  hi AvantePopupHint guifg=#565d8c ctermfg=60
+  hi AvanteInlineHint guifg=#565d8c ctermfg=60
+  hi AvanteSidebarWinSeparator guifg=#373b59 ctermfg=60 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+  hi AvanteSidebarWinHorizontalSeparator guifg=#222433 ctermfg=235 guibg=#222433 ctermbg=235 gui=NONE cterm=NONE
+  if has("nvim")
+    hi NormalFloat guifg=#8186a6 ctermfg=103 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+    hi FloatBorder guifg=#565d8c ctermfg=60 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+    hi WinSeparator guifg=#373b59 ctermfg=60 guibg=NONE ctermbg=NONE gui=NONE cterm=NONE
+    hi DiagnosticError guifg=#ff9494 ctermfg=210
+    hi DiagnosticVirtualTextError guifg=#ff9494 ctermfg=210 gui=bold cterm=bold
+    hi DiagnosticUnderlineError guifg=#ff9494 ctermfg=210 guisp=#ff9494 gui=undercurl cterm=undercurl
+    hi DiagnosticWarn guifg=#ac8b83 ctermfg=138
+    hi DiagnosticVirtualTextWarn guifg=#ac8b83 ctermfg=138 gui=bold cterm=bold
+    hi DiagnosticUnderlineWarn guifg=#ac8b83 ctermfg=138 guisp=#ac8b83 gui=undercurl cterm=undercurl
+    hi DiagnosticInfo guifg=#82dabf ctermfg=115
+    hi DiagnosticVirtualTextInfo guifg=#565d8c ctermfg=60 gui=bold cterm=bold
+    hi DiagnosticUnderlineInfo guisp=#82dabf gui=undercurl cterm=undercurl
+    hi DiagnosticHint guifg=#82dabf ctermfg=115
+    hi DiagnosticOk guifg=#82dabf ctermfg=115
+    hi DiagnosticVirtualTextHint guifg=#565d8c ctermfg=60 gui=bold cterm=bold
+    hi DiagnosticUnderlineHint guisp=#82dabf gui=undercurl cterm=undercurl
+    hi LspSignatureActiveParameter gui=italic cterm=italic
+    hi LspReferenceText guibg=#2f3248 ctermbg=236
+    hi LspReferenceRead guibg=#2f3248 ctermbg=236
+    hi LspReferenceWrite guibg=#2f3248 ctermbg=236
+    hi FloatTitle guifg=#929be5 ctermfg=104 guibg=#222433 ctermbg=235 gui=NONE cterm=NONE
+    let g:terminal_color_0 = '#11121a'
+    let g:terminal_color_1 = '#e68585'
+    let g:terminal_color_2 = '#7cbe8c'
+    let g:terminal_color_3 = '#8f8a70'
+    let g:terminal_color_4 = '#4d8aad'
+    let g:terminal_color_5 = '#6d78cc'
+    let g:terminal_color_6 = '#73c1a9'
+    let g:terminal_color_7 = '#9ea3c0'
+    let g:terminal_color_8 = '#565d8c'
+    let g:terminal_color_9 = '#b871b8'
+    let g:terminal_color_10 = '#7cbe8c'
+    let g:terminal_color_11 = '#a8a384'
+    let g:terminal_color_12 = '#589ec6'
+    let g:terminal_color_13 = '#929be5'
+    let g:terminal_color_14 = '#59b6b6'
+    let g:terminal_color_15 = '#9ea3c0'
+    let g:terminal_color_background = g:terminal_color_0
+    let g:terminal_color_foreground = g:terminal_color_7
+  endif
+  if has("nvim-0.8.0")
+    hi @string guifg=#7cbe8c ctermfg=108
+    hi! link @string.regex @string
+    hi @string.escape guifg=#b871b8 ctermfg=133
+    hi @string.special.url guifg=#565d8c ctermfg=60
+    hi @text.title guifg=#a8a384 ctermfg=144 gui=bold cterm=bold
+    hi @text.reference guifg=#929be5 ctermfg=104
+    hi @text.uri guifg=#565d8c ctermfg=60
+    hi @text.strong gui=bold cterm=bold
+    hi @text.literal guifg=#73c1a9 ctermfg=79
+    hi @parameter guifg=#929be5 ctermfg=104
+    hi @property guifg=#929be5 ctermfg=104
+    hi @keyword guifg=#b871b8 ctermfg=133
+    hi @operator guifg=#929be5 ctermfg=104
+    hi @module guifg=#9ea3c0 ctermfg=146
+    hi @type guifg=#ac8b83 ctermfg=138
+    hi! link @type.builtin @type
+    hi @function.tsx guifg=#9ea3c0 ctermfg=146
+    hi @punctuation.special.typescript guifg=#8186a6 ctermfg=103
+    hi @include guifg=#929be5 ctermfg=104
+    hi @variable guifg=#9ea3c0 ctermfg=146
+    hi @variable.builtin guifg=#ac8b83 ctermfg=138
+    hi @constant.builtin guifg=#73c1a9 ctermfg=79
+    hi @constructor guifg=#9ea3c0 ctermfg=146
+    hi @tag guifg=#9ea3c0 ctermfg=146
+    hi @tag.delimiter guifg=#929be5 ctermfg=104
+    hi @tag.attribute guifg=#929be5 ctermfg=104
+    hi @tag.builtin.tsx guifg=#9ea3c0 ctermfg=146
+    hi @markup.heading guifg=#a8a384 ctermfg=144 gui=bold cterm=bold
+    hi @markup.strong gui=bold cterm=bold
+    hi @markup.list guifg=#565d8c ctermfg=60
+    hi @markup.raw guifg=#73c1a9 ctermfg=79
+    hi @markup.link guifg=#929be5 ctermfg=104
+    hi @markup.link.url guifg=#8186a6 ctermfg=103
+    hi @markup.quote guifg=#565d8c ctermfg=60
+    hi @lsp.type.class guifg=#9ea3c0 ctermfg=146
+    hi @lsp.type.interface guifg=#ac8b83 ctermfg=138
+    hi @lsp.type.parameter guifg=#929be5 ctermfg=104
+    hi @lsp.type.property guifg=#929be5 ctermfg=104
+    hi @lsp.type.struct guifg=#9ea3c0 ctermfg=146
+    hi @lsp.type.type guifg=#ac8b83 ctermfg=138
+    hi @lsp.type.typeParameter guifg=#9ea3c0 ctermfg=146
+    hi @lsp.type.variable guifg=#9ea3c0 ctermfg=146
+    hi @lsp.type.member guifg=#929be5 ctermfg=104
+    hi @lsp.type.namespace guifg=#9ea3c0 ctermfg=146
+  endif
+  let g:defx_icons_gui_colors = {
+    \ 'brown': 'cc4e4e',
+    \ 'aqua': '5c9d9d',
+    \ 'blue': '5e90ad',
+    \ 'darkBlue': '577586',
+    \ 'purple': '7079bf',
+    \ 'lightPurple': '969ccc',
+    \ 'red': 'e68585',
+    \ 'beige': '686867',
+    \ 'yellow': '8f8a70',
+    \ 'orange': 'c6a096',
+    \ 'darkOrange': '795850',
+    \ 'pink': '9f619f',
+    \ 'salmon': 'ab58ab',
+    \ 'green': '639870',
+    \ 'lightGreen': '5ba56d',
+    \ 'white': '898da7',
+    \ }
+  let g:defx_icons_term_colors = {
+    \ 'brown': 167,
+    \ 'aqua': 73,
+    \ 'blue': 67,
+    \ 'darkBlue': 67,
+    \ 'purple': 104,
+    \ 'lightPurple': 146,
+    \ 'red': 174,
+    \ 'beige': 242,
+    \ 'yellow': 101,
+    \ 'orange': 181,
+    \ 'darkOrange': 95,
+    \ 'pink': 133,
+    \ 'salmon': 133,
+    \ 'green': 65,
+    \ 'lightGreen': 71,
+    \ 'white': 103,
+    \ }
 endif
-let g:defx_icons_gui_colors = {
-  \ 'brown': 'cc4d4d',
-  \ 'aqua': '5b9c9c',
-  \ 'blue': '5d8fac',
-  \ 'darkBlue': '557486',
-  \ 'purple': '6f78be',
-  \ 'lightPurple': '959acb',
-  \ 'red': 'e58585',
-  \ 'beige': '686765',
-  \ 'yellow': '8e8a6f',
-  \ 'orange': 'c59f96',
-  \ 'darkOrange': '79564f',
-  \ 'pink': '9e619e',
-  \ 'salmon': 'ab57ab',
-  \ 'green': '63976f',
-  \ 'lightGreen': '5aa46c',
-  \ 'white': '898da6',
-  \ }
-let g:defx_icons_term_colors = {
-  \ 'brown': 167,
-  \ 'aqua': 73,
-  \ 'blue': 67,
-  \ 'darkBlue': 67,
-  \ 'purple': 104,
-  \ 'lightPurple': 103,
-  \ 'red': 174,
-  \ 'beige': 242,
-  \ 'yellow': 101,
-  \ 'orange': 181,
-  \ 'darkOrange': 95,
-  \ 'pink': 133,
-  \ 'salmon': 133,
-  \ 'green': 65,
-  \ 'lightGreen': 71,
-  \ 'white': 103,
-  \ }
 let g:fzf_colors = {
   \ 'fg':      ['fg', 'Normal'],
   \ 'bg':      ['bg', 'Normal'],
@@ -0,0 +1,89 @@
+//! Times `conv::to_cterm` over the bundled palettes against the way it used
+//! to work: converting all 256 cterm colors to Lab and sorting them on every
+//! call. Fails when the precomputed lookup is no longer clearly faster, or
+//! when the two disagree.
+//!
+//! Run with `cargo bench`.
+
+use dogrun::conv::{self, CtermColors, CtermOptions, Metric, Rgb};
+use dogrun::highlight::{get_light_palette, get_palette};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ROUNDS: u32 = 20;
+
+/// The minimum speedup over the baseline. Most of the time goes into the
+/// CIEDE2000 differences themselves, so precomputing the table only about
+/// halves it.
+const MIN_SPEEDUP: f64 = 1.5;
+
+fn baseline(color: Rgb) -> u8 {
+    let color = color.to_lab();
+    let mut v: Vec<(usize, f32)> = (0..256)
+        .map(|id| {
+            let target = Rgb::parse(conv::cterm2hex(id).unwrap()).unwrap().to_lab();
+            (id, empfindung::cie00::diff(color, target))
+        })
+        .collect();
+
+    v.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap());
+
+    v[0].0 as u8
+}
+
+fn time(colors: &[Rgb], f: impl Fn(Rgb) -> u8) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for color in colors.iter() {
+            black_box(f(black_box(*color)));
+        }
+    }
+    start.elapsed() / ROUNDS
+}
+
+fn main() {
+    let dark = get_palette().unwrap();
+    let light = get_light_palette().unwrap();
+    let colors: Vec<Rgb> = dark
+        .values()
+        .chain(light.values())
+        .map(|color| color.gui)
+        .collect();
+
+    for color in colors.iter() {
+        assert_eq!(conv::to_cterm(*color), baseline(*color), "{}", color);
+    }
+
+    let fast = time(&colors, conv::to_cterm);
+    let slow = time(&colors, baseline);
+    println!(
+        "to_cterm ({} colors): {:?}, baseline {:?}",
+        colors.len(),
+        fast,
+        slow
+    );
+
+    for colors_option in CtermColors::ALL {
+        for metric in Metric::ALL {
+            let options = CtermOptions {
+                colors: colors_option,
+                metric,
+            };
+            let elapsed = time(&colors, |color| conv::to_cterm_with(color, &options));
+            println!(
+                "to_cterm_with {}/{}: {:?}",
+                colors_option.name(),
+                metric.name(),
+                elapsed
+            );
+        }
+    }
+
+    let speedup = slow.as_secs_f64() / fast.as_secs_f64();
+    assert!(
+        speedup >= MIN_SPEEDUP,
+        "to_cterm is only {:.1}x faster than the baseline (expected {}x)",
+        speedup,
+        MIN_SPEEDUP
+    );
+}
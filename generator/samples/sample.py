@@ -0,0 +1,27 @@
+# A sweet dog to take with you.
+from dataclasses import dataclass, field
+import json
+
+
+@dataclass
+class Dog:
+    """A dog that knows some tricks."""
+
+    name: str
+    tricks: list[str] = field(default_factory=list)
+
+    def load(self, path: str) -> int:
+        with open(path, encoding="utf-8") as f:
+            self.tricks = json.load(f)
+        return len(self.tricks)
+
+    def greet(self, times: int = 1) -> str:
+        # TODO: learn more greetings
+        if times > 0 and self.tricks is not None:
+            return "bark" * times
+        return 'wag'
+
+
+if __name__ == "__main__":
+    dog = Dog("dogrun")
+    print(f"{dog.name} says {dog.greet(3)}", 0.5, True, None)
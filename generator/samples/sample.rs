@@ -0,0 +1,45 @@
+// A sweet dog to take with you.
+use std::collections::HashMap;
+use std::fmt;
+
+/// How a dog greets.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Greeting {
+    Bark(u8),
+    Wag,
+}
+
+pub struct Dog {
+    name: String,
+    tricks: HashMap<String, bool>,
+}
+
+impl Dog {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            tricks: HashMap::new(),
+        }
+    }
+
+    pub fn greet(&self, times: u8) -> Greeting {
+        // TODO: learn more greetings
+        if times > 0 && !self.tricks.is_empty() {
+            Greeting::Bark(times * 2)
+        } else {
+            Greeting::Wag
+        }
+    }
+}
+
+impl fmt::Display for Dog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} knows {} tricks\n", self.name, self.tricks.len())
+    }
+}
+
+fn main() {
+    let dog = Dog::new("dogrun");
+    let ok = matches!(dog.greet(3), Greeting::Bark(6));
+    println!("{}: {}", dog, ok == true);
+}
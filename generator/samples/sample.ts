@@ -0,0 +1,35 @@
+// A sweet dog to take with you.
+import { readFile } from "node:fs/promises";
+
+type Greeting = "bark" | "wag";
+
+interface Trick {
+  name: string;
+  learned: boolean;
+}
+
+/**
+ * A dog that knows some tricks.
+ */
+export class Dog {
+  private tricks: Trick[] = [];
+
+  constructor(readonly name: string) {}
+
+  async load(path: string): Promise<number> {
+    const json = await readFile(path, "utf8");
+    this.tricks = JSON.parse(json) as Trick[];
+    return this.tricks.length;
+  }
+
+  greet(times = 1): Greeting {
+    // TODO: learn more greetings
+    if (times > 0 && this.tricks.length !== 0) {
+      return "bark";
+    }
+    return "wag";
+  }
+}
+
+const dog = new Dog("dogrun");
+console.log(`${dog.name} says ${dog.greet(3)}`, null, undefined, true);
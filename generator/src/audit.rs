@@ -0,0 +1,184 @@
+use crate::contrast::{apca_contrast, wcag_contrast};
+use crate::conv::{self, Rgb};
+use crate::error;
+use crate::highlight::{Background, Color, Highlight, HighlightAttr, Palette};
+use crate::palette::lookup;
+use crate::style::Mode;
+
+/// Minimum contrast a highlight has to reach to pass the audit.
+#[derive(Debug, Clone, Copy)]
+pub struct Thresholds {
+    /// WCAG 2.x contrast ratio.
+    pub contrast: f64,
+    /// Absolute APCA Lc.
+    pub lc: f64,
+}
+
+impl Default for Thresholds {
+    /// WCAG AA for normal text, and the APCA level it roughly corresponds to.
+    fn default() -> Self {
+        Self {
+            contrast: 4.5,
+            lc: 60.0,
+        }
+    }
+}
+
+/// The contrast of one highlight group in one mode.
+#[derive(Debug)]
+pub struct Measurement {
+    pub name: String,
+    pub mode: Mode,
+    pub fg: Rgb,
+    pub bg: Rgb,
+    pub contrast: f64,
+    pub lc: f64,
+}
+
+impl Measurement {
+    pub fn passes(&self, thresholds: &Thresholds) -> bool {
+        self.contrast >= thresholds.contrast && self.lc.abs() >= thresholds.lc
+    }
+}
+
+/// Measures the fg/bg contrast of every highlight that sets a foreground.
+/// Groups without a background (or with a "NONE" one) are drawn over
+/// `Normal`, so they are measured against `mainbg`. Links are skipped since
+/// their target is measured on its own.
+pub fn audit(palette: &Palette, highlights: &[Highlight]) -> error::Result<Vec<Measurement>> {
+    let color = |name: &Option<String>, hl: &Highlight| -> error::Result<Option<&Color>> {
+        match name.as_deref().filter(|name| *name != "NONE") {
+            Some(name) => Ok(Some(lookup(palette, name, &hl.name)?)),
+            None => Ok(None),
+        }
+    };
+
+    let mut measurements = vec![];
+
+    for hl in highlights.iter().filter(|hl| hl.link.is_none()) {
+        let fg = match color(&hl.fg, hl)? {
+            Some(fg) => fg,
+            None => continue,
+        };
+        let bg = match color(&hl.bg, hl)? {
+            Some(bg) => bg,
+            None => lookup(palette, "mainbg", &hl.name)?,
+        };
+
+        let (fg, bg) = if hl.attr.contains(HighlightAttr::REVERSE) {
+            (bg, fg)
+        } else {
+            (fg, bg)
+        };
+
+        for mode in [Mode::Gui, Mode::Cterm] {
+            let (fg, bg) = match mode {
+                Mode::Gui => (fg.gui, bg.gui),
+                Mode::Cterm => (conv::cterm_rgb(fg.cterm), conv::cterm_rgb(bg.cterm)),
+            };
+
+            measurements.push(Measurement {
+                name: hl.name.clone(),
+                mode,
+                contrast: wcag_contrast(fg, bg),
+                lc: apca_contrast(fg, bg),
+                fg,
+                bg,
+            });
+        }
+    }
+
+    Ok(measurements)
+}
+
+/// Writes the failing measurements of a background as a table and returns
+/// how many there were.
+pub fn report<W: std::io::Write>(
+    mut out: W,
+    background: Background,
+    measurements: &[Measurement],
+    thresholds: &Thresholds,
+) -> std::io::Result<usize> {
+    let failures: Vec<_> = measurements
+        .iter()
+        .filter(|m| !m.passes(thresholds))
+        .collect();
+
+    let width = failures.iter().map(|m| m.name.len()).max().unwrap_or(0);
+
+    for m in failures.iter() {
+        writeln!(
+            out,
+            "{:<6}{:<6}{:<width$}  {} on {}  contrast {:>5.2}  Lc {:>6.1}",
+            background,
+            m.mode,
+            m.name,
+            m.fg,
+            m.bg,
+            m.contrast,
+            m.lc,
+            width = width,
+        )?;
+    }
+
+    Ok(failures.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::highlight::parse_highlights;
+    use crate::palette::parse_palette;
+
+    #[test]
+    fn test_audit() {
+        let palette = parse_palette(
+            r##"
+mainbg = "#000000"
+white = "#ffffff"
+gray = "#222222"
+"##,
+        )
+        .unwrap();
+        let highlights = parse_highlights(
+            r#"
+[[section]]
+highlights = [
+  { name = "Normal", fg = "white", bg = "mainbg" },
+  { name = "Comment", fg = "gray" },
+  { name = "Visual", fg = "gray", bg = "white", attr = "reverse" },
+  { name = "CursorLine", bg = "gray" },
+  { name = "Todo", link = "Comment" },
+]
+"#,
+            &palette,
+        )
+        .unwrap();
+
+        let measurements = audit(&palette, &highlights).unwrap();
+        let names: Vec<_> = measurements
+            .iter()
+            .map(|m| (m.name.as_str(), m.mode))
+            .collect();
+
+        assert_eq!(
+            names,
+            vec![
+                ("Normal", Mode::Gui),
+                ("Normal", Mode::Cterm),
+                ("Comment", Mode::Gui),
+                ("Comment", Mode::Cterm),
+                ("Visual", Mode::Gui),
+                ("Visual", Mode::Cterm),
+            ]
+        );
+
+        let thresholds = Thresholds::default();
+        assert!(measurements[0].passes(&thresholds));
+        assert_eq!(measurements[2].bg.to_string(), "#000000");
+        assert!(!measurements[2].passes(&thresholds));
+        assert_eq!(measurements[4].fg.to_string(), "#ffffff");
+        assert_eq!(measurements[4].bg.to_string(), "#222222");
+        assert_eq!(measurements[5].bg.to_string(), "#262626");
+    }
+}
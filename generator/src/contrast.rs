@@ -0,0 +1,93 @@
+use crate::conv::Rgb;
+
+/// WCAG 2.x relative luminance.
+pub fn relative_luminance(color: Rgb) -> f64 {
+    let [r, g, b] = color.to_linear();
+
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// WCAG 2.x contrast ratio, from 1.0 (no contrast) to 21.0 (black on white).
+/// The order of the two colors does not matter.
+pub fn wcag_contrast(a: Rgb, b: Rgb) -> f64 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    let (lighter, darker) = if a > b { (a, b) } else { (b, a) };
+
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// APCA screen luminance, soft-clamped near black (APCA 0.0.98G).
+fn apca_luminance(color: Rgb) -> f64 {
+    let [r, g, b] = color.channels().map(|c| c.powf(2.4));
+    let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+
+    if y < 0.022 {
+        y + (0.022 - y).powf(1.414)
+    } else {
+        y
+    }
+}
+
+/// APCA lightness contrast (Lc) of text `fg` on `bg` (APCA 0.0.98G).
+/// Dark text on a light background gives a positive value, light text on a
+/// dark background a negative one; the magnitude is what matters.
+pub fn apca_contrast(fg: Rgb, bg: Rgb) -> f64 {
+    let (text, back) = (apca_luminance(fg), apca_luminance(bg));
+
+    if (back - text).abs() < 0.0005 {
+        return 0.0;
+    }
+
+    let lc = if back > text {
+        let s = (back.powf(0.56) - text.powf(0.57)) * 1.14;
+        if s < 0.1 {
+            0.0
+        } else {
+            s - 0.027
+        }
+    } else {
+        let s = (back.powf(0.65) - text.powf(0.62)) * 1.14;
+        if s > -0.1 {
+            0.0
+        } else {
+            s + 0.027
+        }
+    };
+
+    lc * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 0.01,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    fn rgb(hex: &str) -> Rgb {
+        Rgb::parse(hex).unwrap()
+    }
+
+    #[test]
+    fn test_wcag_contrast() {
+        assert_near(wcag_contrast(rgb("#000000"), rgb("#ffffff")), 21.0);
+        assert_near(wcag_contrast(rgb("#ffffff"), rgb("#000000")), 21.0);
+        assert_near(wcag_contrast(rgb("#777777"), rgb("#777777")), 1.0);
+        assert_near(wcag_contrast(rgb("#777777"), rgb("#ffffff")), 4.48);
+    }
+
+    #[test]
+    fn test_apca_contrast() {
+        assert_near(apca_contrast(rgb("#888888"), rgb("#ffffff")), 63.06);
+        assert_near(apca_contrast(rgb("#ffffff"), rgb("#888888")), -68.54);
+        assert_near(apca_contrast(rgb("#000000"), rgb("#aaaaaa")), 58.15);
+        assert_near(apca_contrast(rgb("#aaaaaa"), rgb("#000000")), -56.24);
+        assert_near(apca_contrast(rgb("#777777"), rgb("#777777")), 0.0);
+    }
+}
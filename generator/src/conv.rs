@@ -1,8 +1,8 @@
 #![allow(clippy::vec_init_then_push)]
 
-use empfindung::cie00::diff;
+use empfindung::{cie00, cie76};
 use lab::Lab;
-use tint::Color;
+use std::fmt;
 
 lazy_static! {
     static ref CTERM_COLORS: Vec<(usize, &'static str)> = {
@@ -265,115 +265,699 @@ lazy_static! {
         v.push((255, "#eeeeee"));
         v
     };
+
+    /// The colors of `CTERM_COLORS`, by index.
+    static ref CTERM_RGB: Vec<Rgb> =
+        CTERM_COLORS.iter().map(|(_, hex)| Rgb::parse(hex).unwrap()).collect();
+
+    /// The coordinates of `CTERM_COLORS`, by index.
+    static ref CTERM_COORDS: Vec<Coords> = CTERM_RGB.iter().map(|rgb| Coords::new(*rgb)).collect();
 }
 
-pub fn hex2lab(hex: String) -> Lab {
-    let color = Color::from(hex);
+/// A color in the spaces the metrics measure in, converted once so that
+/// matching against the cterm table doesn't convert every entry per call.
+struct Coords {
+    lab: Lab,
+    oklab: Oklab,
+}
+
+impl Coords {
+    fn new(color: Rgb) -> Self {
+        Self {
+            lab: color.to_lab(),
+            oklab: color.to_oklab(),
+        }
+    }
+}
 
-    Lab::from_rgb(&[
-        (color.red * 255.0) as u8,
-        (color.green * 255.0) as u8,
-        (color.blue * 255.0) as u8,
-    ])
+/// An sRGB color with channels in `0.0..=1.0`. Conversions from other
+/// spaces may give channels outside that range, which are clamped when the
+/// color is formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rgb {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
 }
 
-pub fn hex2hsv(hex: String) -> Lab {
-    let color = Color::from(hex);
+/// Hue in degrees (`0.0..360.0`), saturation and value in `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsv {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64,
+}
 
-    Lab::from_rgb(&[
-        (color.red * 255.0) as u8,
-        (color.green * 255.0) as u8,
-        (color.blue * 255.0) as u8,
-    ])
+/// Hue in degrees (`0.0..360.0`), saturation and lightness in `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
 }
 
-pub fn hue(hex: String, amount: f64) -> String {
-    let (h, s, v) = Color::from(hex).to_hsv();
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
 
-    Color::new(h + amount, s, v, 1.0).from_hsv().to_hex()
+/// OKLab in polar form, with the hue in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklch {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
 }
 
-pub fn saturate(hex: String, factor: f64) -> String {
-    let (h, s, v) = Color::from(hex).to_hsv();
+/// A component an operation pushed out of its valid range, with the value
+/// it asked for and the one it was clamped to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Clip {
+    pub component: &'static str,
+    pub value: f64,
+    pub clamped: f64,
+}
 
-    Color::new(h, s + factor, v, 1.0).from_hsv().to_hex()
+impl Clip {
+    fn check(component: &'static str, value: f64, clamped: f64, clips: &mut Vec<Clip>) -> f64 {
+        if (value - clamped).abs() > 1e-6 {
+            clips.push(Clip {
+                component,
+                value,
+                clamped,
+            });
+        }
+        clamped
+    }
 }
 
-pub fn darken(hex: String, amount: f64) -> String {
-    let (h, s, v) = Color::from(hex).to_hsv();
+impl Rgb {
+    pub fn new(r: f64, g: f64, b: f64) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Parses a `#rrggbb` string.
+    pub fn parse(hex: &str) -> Option<Self> {
+        let digits = hex.strip_prefix('#').filter(|d| d.len() == 6)?;
+        let channel = |i: usize| {
+            u8::from_str_radix(digits.get(i..i + 2)?, 16)
+                .ok()
+                .map(|c| c as f64 / 255.0)
+        };
+
+        Some(Self::new(channel(0)?, channel(2)?, channel(4)?))
+    }
+
+    /// The channels clamped to `0.0..=1.0`.
+    pub fn clamp(self) -> Self {
+        let [r, g, b] = self.channels().map(|c| c.clamp(0.0, 1.0));
+        Self::new(r, g, b)
+    }
+
+    /// Whether the channels are in `0.0..=1.0`, give or take rounding.
+    pub fn in_gamut(self) -> bool {
+        self.channels()
+            .iter()
+            .all(|c| (-1e-4..=1.0 + 1e-4).contains(c))
+    }
+
+    pub fn channels(self) -> [f64; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    /// The channels as bytes, clamped and rounded.
+    pub fn to_u8(self) -> [u8; 3] {
+        self.clamp().channels().map(|c| (c * 255.0).round() as u8)
+    }
+
+    /// The color rounded to bytes, i.e. the one its `#rrggbb` stands for.
+    pub fn round(self) -> Self {
+        let [r, g, b] = self.to_u8().map(|c| c as f64 / 255.0);
+        Self::new(r, g, b)
+    }
+
+    /// The channels with the sRGB transfer function removed.
+    pub fn to_linear(self) -> [f64; 3] {
+        self.channels().map(|c| {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        })
+    }
+
+    pub fn from_linear(linear: [f64; 3]) -> Self {
+        let [r, g, b] = linear.map(|c| {
+            if c <= 0.0031308 {
+                12.92 * c
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        });
+        Self::new(r, g, b)
+    }
+
+    pub fn to_hsv(self) -> Hsv {
+        let (h, max, delta) = self.hue_max_delta();
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+
+        Hsv { h, s, v: max }
+    }
+
+    pub fn from_hsv(Hsv { h, s, v }: Hsv) -> Self {
+        let c = v * s;
+        Self::from_hue(h, c, v - c)
+    }
+
+    pub fn to_hsl(self) -> Hsl {
+        let (h, max, delta) = self.hue_max_delta();
+        let l = max - delta / 2.0;
+        let s = if delta == 0.0 {
+            0.0
+        } else {
+            delta / (1.0 - (2.0 * l - 1.0).abs())
+        };
+
+        Hsl { h, s, l }
+    }
+
+    pub fn from_hsl(Hsl { h, s, l }: Hsl) -> Self {
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        Self::from_hue(h, c, l - c / 2.0)
+    }
+
+    /// The hue in degrees, the largest channel and the chroma, shared by
+    /// HSV and HSL.
+    fn hue_max_delta(self) -> (f64, f64, f64) {
+        let Rgb { r, g, b } = self;
+        let max = r.max(g).max(b);
+        let delta = max - r.min(g).min(b);
+
+        let h = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        (h.rem_euclid(360.0), max, delta)
+    }
+
+    /// Builds a color from a hue, a chroma and the amount added to every
+    /// channel, shared by HSV and HSL.
+    fn from_hue(h: f64, c: f64, m: f64) -> Self {
+        let h = h.rem_euclid(360.0) / 60.0;
+        let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+
+        let (r, g, b) = match h as u8 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        Self::new(r + m, g + m, b + m)
+    }
+
+    /// CIELAB (D65).
+    pub fn to_lab(self) -> Lab {
+        Lab::from_rgb_normalized(&self.clamp().channels().map(|c| c as f32))
+    }
+
+    pub fn from_lab(lab: Lab) -> Self {
+        let [r, g, b] = lab.to_rgb_normalized().map(|c| c as f64);
+        Self::new(r, g, b)
+    }
+
+    pub fn to_oklab(self) -> Oklab {
+        let [r, g, b] = self.to_linear();
+
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+        Oklab {
+            l: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            a: 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            b: 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        }
+    }
+
+    pub fn from_oklab(Oklab { l, a, b }: Oklab) -> Self {
+        let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+        let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+        let s_ = (l - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+        Self::from_linear([
+            4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_,
+            -1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_,
+            -0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_,
+        ])
+    }
+
+    pub fn to_oklch(self) -> Oklch {
+        let Oklab { l, a, b } = self.to_oklab();
+
+        Oklch {
+            l,
+            c: a.hypot(b),
+            h: b.atan2(a).to_degrees().rem_euclid(360.0),
+        }
+    }
+
+    pub fn from_oklch(Oklch { l, c, h }: Oklch) -> Self {
+        let h = h.to_radians();
+
+        Self::from_oklab(Oklab {
+            l,
+            a: c * h.cos(),
+            b: c * h.sin(),
+        })
+    }
+
+    /// Rotates the HSV hue by `amount` degrees.
+    pub fn hue(self, amount: f64) -> Self {
+        self.shift_hsv(amount, 0.0, 0.0).0
+    }
+
+    /// Adds `amount` to the HSV saturation, clamped to `0.0..=1.0`.
+    pub fn saturate(self, amount: f64) -> Self {
+        self.shift_hsv(0.0, amount, 0.0).0
+    }
+
+    /// Subtracts `amount` from the HSV value, clamped to `0.0..=1.0`.
+    pub fn darken(self, amount: f64) -> Self {
+        self.shift_hsv(0.0, 0.0, -amount).0
+    }
+
+    pub fn lighten(self, amount: f64) -> Self {
+        self.darken(-amount)
+    }
+
+    /// Interpolates between two colors channel by channel in sRGB: `t = 0.0`
+    /// is `self` and `t = 1.0` is `other`.
+    pub fn mix(self, other: Self, t: f64) -> Self {
+        let (from, to) = (self.channels(), other.channels());
+        let [r, g, b] = [0, 1, 2].map(|i| from[i] + (to[i] - from[i]) * t);
+        Self::new(r, g, b).clamp()
+    }
+
+    /// The color drawn with `alpha` opacity over `bg`, composited in sRGB
+    /// like the translucent colors of terminals and browsers.
+    pub fn blend_over(self, bg: Self, alpha: f64) -> Self {
+        bg.mix(self, alpha)
+    }
+
+    /// Mirrors the CIELAB lightness (`L* -> 100 - L*`) while keeping the hue
+    /// and chroma, which turns a dark-background color into its
+    /// light-background counterpart with the same perceived contrast.
+    pub fn invert_lightness(self) -> Self {
+        let lab = self.to_lab();
+        Self::from_lab(Lab {
+            l: 100.0 - lab.l,
+            ..lab
+        })
+    }
 
-    Color::new(h, s, v - amount, 1.0).from_hsv().to_hex()
+    /// Shifts the HSV hue (in degrees), saturation and value. Saturation
+    /// and value that leave `0.0..=1.0` are clamped and returned as clips.
+    pub fn shift_hsv(self, h: f64, s: f64, v: f64) -> (Self, Vec<Clip>) {
+        let hsv = self.to_hsv();
+        let mut clips = vec![];
+
+        let s = Clip::check("s", hsv.s + s, (hsv.s + s).clamp(0.0, 1.0), &mut clips);
+        let v = Clip::check("v", hsv.v + v, (hsv.v + v).clamp(0.0, 1.0), &mut clips);
+
+        (Self::from_hsv(Hsv { h: hsv.h + h, s, v }), clips)
+    }
+
+    /// Shifts the OKLCH lightness, chroma and hue (in degrees). Lightness
+    /// is clamped to `0.0..=1.0`, and colors that end up outside the sRGB
+    /// gamut lose chroma until they fit, keeping their lightness and hue.
+    /// Both are returned as clips.
+    pub fn shift_oklch(self, l: f64, c: f64, h: f64) -> (Self, Vec<Clip>) {
+        let lch = self.to_oklch();
+        let mut clips = vec![];
+
+        let l = Clip::check("l", lch.l + l, (lch.l + l).clamp(0.0, 1.0), &mut clips);
+        let h = lch.h + h;
+        let in_gamut = |c: f64| Self::from_oklch(Oklch { l, c, h }).in_gamut();
+
+        let mut fitted = (lch.c + c).max(0.0);
+        if !in_gamut(fitted) {
+            let (mut low, mut high) = (0.0, fitted);
+            for _ in 0..24 {
+                let mid = (low + high) / 2.0;
+                if in_gamut(mid) {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            fitted = low;
+        }
+        let c = Clip::check("c", lch.c + c, fitted, &mut clips);
+
+        (Self::from_oklch(Oklch { l, c, h }).clamp(), clips)
+    }
+}
+
+/// Formats the color as `#rrggbb`.
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [r, g, b] = self.to_u8();
+        write!(f, "#{:02x}{:02x}{:02x}", r, g, b)
+    }
+}
+
+/// CIEDE2000 color difference of two colors.
+pub fn distance(a: Rgb, b: Rgb) -> f32 {
+    cie00::diff(a.to_lab(), b.to_lab())
+}
+
+/// Returns the hex value of a 256-color terminal palette index.
+pub fn cterm2hex(id: usize) -> Option<&'static str> {
+    CTERM_COLORS.get(id).map(|(_, hex)| *hex)
+}
+
+/// Returns the color of a 256-color terminal palette index.
+pub fn cterm_rgb(id: u8) -> Rgb {
+    CTERM_RGB[id as usize]
+}
+
+/// Which entries of the 256-color palette cterm colors are picked from.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CtermColors {
+    /// Every entry, including the 16 system colors.
+    #[default]
+    All,
+    /// The 6x6x6 color cube and the grayscale ramp (16-255). Terminals let
+    /// users change the system colors, but rarely these.
+    Cube,
 }
 
-pub fn lighten(hex: String, amount: f64) -> String {
-    darken(hex, -amount)
+impl CtermColors {
+    pub const ALL: [CtermColors; 2] = [CtermColors::All, CtermColors::Cube];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            CtermColors::All => "all",
+            CtermColors::Cube => "cube",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|colors| colors.name() == name)
+    }
+}
+
+/// The color difference cterm colors are matched by.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Metric {
+    Cie76,
+    Cie94,
+    #[default]
+    Ciede2000,
+    /// Euclidean distance in OKLab.
+    Oklab,
 }
 
-pub fn to_cterm(hex: String) -> usize {
-    let color = hex2lab(hex);
-    let mut v: Vec<(&usize, f32)> = vec![];
+impl Metric {
+    pub const ALL: [Metric; 4] = [
+        Metric::Cie76,
+        Metric::Cie94,
+        Metric::Ciede2000,
+        Metric::Oklab,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Metric::Cie76 => "cie76",
+            Metric::Cie94 => "cie94",
+            Metric::Ciede2000 => "ciede2000",
+            Metric::Oklab => "oklab",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|metric| metric.name() == name)
+    }
 
-    for (id, value) in CTERM_COLORS.iter() {
-        let target = hex2lab(value.to_string());
-        let diff = diff(color, target);
-        v.push((id, diff));
+    /// The difference of two colors. Only comparable with differences
+    /// measured by the same metric.
+    pub fn distance(self, a: Rgb, b: Rgb) -> f32 {
+        self.between(&Coords::new(a), &Coords::new(b))
     }
 
-    v.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap());
+    fn between(self, a: &Coords, b: &Coords) -> f32 {
+        match self {
+            Metric::Cie76 => cie76::diff(a.lab, b.lab),
+            Metric::Cie94 => cie94(a.lab, b.lab),
+            Metric::Ciede2000 => cie00::diff(a.lab, b.lab),
+            Metric::Oklab => {
+                let (a, b) = (a.oklab, b.oklab);
+                ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt() as f32
+            }
+        }
+    }
+}
+
+/// CIE94 color difference with the graphic arts weights. `empfindung`
+/// returns NaN when rounding makes the squared hue difference negative, so
+/// it is computed here with that clamped to zero.
+fn cie94(reference: Lab, color: Lab) -> f32 {
+    let c1 = reference.a.hypot(reference.b);
+    let c2 = color.a.hypot(color.b);
+    let dl = reference.l - color.l;
+    let dc = c1 - c2;
+    let dh2 =
+        ((reference.a - color.a).powi(2) + (reference.b - color.b).powi(2) - dc * dc).max(0.0);
+
+    (dl.powi(2) + (dc / (1.0 + 0.045 * c1)).powi(2) + dh2 / (1.0 + 0.015 * c1).powi(2)).sqrt()
+}
+
+/// How gui colors are approximated in 256-color terminals.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CtermOptions {
+    pub colors: CtermColors,
+    pub metric: Metric,
+}
+
+/// Returns the 256-color palette index nearest to a color, with the
+/// default options.
+pub fn to_cterm(color: Rgb) -> u8 {
+    to_cterm_with(color, &CtermOptions::default())
+}
+
+/// Returns the 256-color palette index nearest to a color. Of several
+/// equally near entries, the lowest index wins.
+pub fn to_cterm_with(color: Rgb, options: &CtermOptions) -> u8 {
+    let skip = match options.colors {
+        CtermColors::All => 0,
+        CtermColors::Cube => 16,
+    };
 
-    let (id, _) = v.first().unwrap();
+    let color = Coords::new(color);
+    let (id, _) = CTERM_COORDS
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .map(|(id, coords)| (id, options.metric.between(&color, coords)))
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+        .unwrap();
 
-    **id
+    id as u8
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn rgb(hex: &str) -> Rgb {
+        Rgb::parse(hex).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(rgb("#ff0000"), Rgb::new(1.0, 0.0, 0.0));
+        assert_eq!(rgb("#589EC6").to_string(), "#589ec6");
+        assert_eq!(Rgb::parse("#ff00"), None);
+        assert_eq!(Rgb::parse("ff0000"), None);
+        assert_eq!(Rgb::parse("#gg0000"), None);
+        assert_eq!(Rgb::new(1.0, 0.5, -0.2).to_string(), "#ff8000");
+        assert_eq!(Rgb::new(1.0, 0.5, -0.2).round(), rgb("#ff8000"));
+    }
+
+    #[test]
+    fn test_conversions() {
+        for hex in [
+            "#589ec6", "#ff9494", "#222433", "#000000", "#ffffff", "#777777",
+        ] {
+            let color = rgb(hex);
+            assert_eq!(Rgb::from_hsv(color.to_hsv()).to_string(), hex);
+            assert_eq!(Rgb::from_hsl(color.to_hsl()).to_string(), hex);
+            assert_eq!(Rgb::from_lab(color.to_lab()).to_string(), hex);
+            assert_eq!(Rgb::from_oklab(color.to_oklab()).to_string(), hex);
+            assert_eq!(Rgb::from_oklch(color.to_oklch()).to_string(), hex);
+            assert_eq!(Rgb::from_linear(color.to_linear()).to_string(), hex);
+        }
+
+        let hsv = rgb("#00ffff").to_hsv();
+        assert_eq!((hsv.h, hsv.s, hsv.v), (180.0, 1.0, 1.0));
+        let hsl = rgb("#ff0000").to_hsl();
+        assert_eq!((hsl.h, hsl.s, hsl.l), (0.0, 1.0, 0.5));
+
+        let white = rgb("#ffffff").to_oklab();
+        assert!((white.l - 1.0).abs() < 1e-4 && white.a.abs() < 1e-4 && white.b.abs() < 1e-4);
+    }
+
     #[test]
     fn test_hue() {
-        assert_eq!(hue(String::from("#ff0000"), 0.0), String::from("#ff0000"));
-        assert_eq!(hue(String::from("#ff0000"), 180.0), String::from("#00ffff"));
+        assert_eq!(rgb("#ff0000").hue(0.0).to_string(), "#ff0000");
+        assert_eq!(rgb("#ff0000").hue(180.0).to_string(), "#00ffff");
+        assert_eq!(rgb("#ff0000").hue(-120.0).to_string(), "#0000ff");
     }
 
     #[test]
     fn test_saturate() {
+        assert_eq!(rgb("#ff0000").saturate(0.0).to_string(), "#ff0000");
+        assert_eq!(rgb("#ff0000").saturate(-0.2).to_string(), "#ff3333");
+        assert_eq!(rgb("#ff0000").saturate(0.5).to_string(), "#ff0000");
+    }
+
+    #[test]
+    fn test_shift_hsv() {
+        let (color, clips) = rgb("#347ba1").shift_hsv(0.0, 0.1, -0.2);
         assert_eq!(
-            saturate(String::from("#ff0000"), 0.0),
-            String::from("#ff0000")
+            color.to_string(),
+            rgb("#347ba1").darken(0.2).saturate(0.1).to_string()
         );
+        assert!(clips.is_empty());
 
+        let (color, clips) = rgb("#8080ff").shift_hsv(0.0, -0.6, 0.15);
+        assert_eq!(color.to_string(), "#ffffff");
         assert_eq!(
-            saturate(String::from("#ff0000"), -0.1),
-            String::from("#ff1919")
+            clips
+                .iter()
+                .map(|clip| (clip.component, clip.clamped))
+                .collect::<Vec<_>>(),
+            vec![("s", 0.0), ("v", 1.0)]
         );
+        assert!((clips[0].value - (1.0 - 128.0 / 255.0 - 0.6)).abs() < 1e-9);
+        assert!((clips[1].value - 1.15).abs() < 1e-9);
     }
 
     #[test]
     fn test_shade() {
+        assert_eq!(rgb("#ff0000").darken(0.0).to_string(), "#ff0000");
+        assert_eq!(rgb("#ff0000").lighten(0.0).to_string(), "#ff0000");
+        assert_eq!(rgb("#347ba1").darken(0.2).to_string(), "#24546e");
         assert_eq!(
-            darken(String::from("#ff0000"), 0.0),
-            String::from("#ff0000")
+            rgb("#347ba1").darken(0.2).lighten(0.2).to_string(),
+            "#347ba1"
         );
+        assert_eq!(rgb("#ffffff").lighten(0.5).to_string(), "#ffffff");
+        assert_eq!(rgb("#8080ff").lighten(0.2).to_string(), "#8080ff");
+        assert_eq!(rgb("#000000").darken(0.5).to_string(), "#000000");
+    }
+
+    #[test]
+    fn test_mix() {
+        let (red, blue) = (rgb("#ff0000"), rgb("#0000ff"));
 
+        assert_eq!(red.mix(blue, 0.0).to_string(), "#ff0000");
+        assert_eq!(red.mix(blue, 1.0).to_string(), "#0000ff");
+        assert_eq!(red.mix(blue, 0.25).to_string(), "#bf0040");
+        assert_eq!(red.mix(blue, 2.0).to_string(), "#0000ff");
         assert_eq!(
-            lighten(String::from("#ff0000"), 0.0),
-            String::from("#ff0000")
+            rgb("#ffffff").blend_over(rgb("#222433"), 0.1).to_string(),
+            rgb("#222433").mix(rgb("#ffffff"), 0.1).to_string()
         );
-
         assert_eq!(
-            darken(String::from("#347ba1"), 0.2),
-            String::from("#23546d")
+            rgb("#ffffff").blend_over(rgb("#000000"), 0.5).to_string(),
+            "#808080"
         );
+    }
+
+    #[test]
+    fn test_invert_lightness() {
+        assert_eq!(rgb("#000000").invert_lightness().to_string(), "#ffffff");
+        assert_eq!(rgb("#ffffff").invert_lightness().to_string(), "#000000");
+        assert_eq!(rgb("#777777").invert_lightness().to_string(), "#777777");
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(distance(rgb("#ff0000"), rgb("#ff0000")), 0.0);
+        assert!(distance(rgb("#000000"), rgb("#ffffff")) > 99.0);
+    }
+
+    #[test]
+    fn test_to_cterm() {
+        assert_eq!(to_cterm(rgb("#00c795")), 43);
 
+        let cube = CtermOptions {
+            colors: CtermColors::Cube,
+            ..CtermOptions::default()
+        };
+        assert_eq!(to_cterm(rgb("#ff0000")), 9);
+        assert_eq!(to_cterm_with(rgb("#ff0000"), &cube), 196);
+        assert_eq!(to_cterm(rgb("#000000")), 0);
+        assert_eq!(to_cterm_with(rgb("#000000"), &cube), 16);
+
+        for metric in Metric::ALL {
+            let options = CtermOptions {
+                colors: CtermColors::Cube,
+                metric,
+            };
+            assert_eq!(to_cterm_with(rgb("#00d7af"), &options), 43);
+        }
+    }
+
+    #[test]
+    fn test_shift_oklch() {
+        for hex in ["#929be5", "#73c1a9", "#ac8b83", "#000000", "#ffffff"] {
+            let (color, clips) = rgb(hex).shift_oklch(0.0, 0.0, 0.0);
+            assert_eq!(color.to_string(), hex);
+            assert!(clips.is_empty(), "{}: {:?}", hex, clips);
+        }
+
+        // the same lightness delta shifts every hue by the same amount
+        for hex in ["#929be5", "#73c1a9", "#ac8b83"] {
+            let l = rgb(hex).shift_oklch(-0.1, 0.0, 0.0).0.to_oklch().l;
+            assert!((l - (rgb(hex).to_oklch().l - 0.1)).abs() < 0.01, "{}", hex);
+        }
+
+        let red = rgb("#ff0000");
+        assert_eq!(red.shift_oklch(0.0, 0.0, 360.0).0.to_string(), "#ff0000");
+
+        let (white, clips) = red.shift_oklch(1.0, 0.0, 0.0);
+        assert_eq!(white.to_string(), "#ffffff");
         assert_eq!(
-            lighten(String::from("#23546d"), 0.2),
-            String::from("#337ba0") // FIXME Results are slightly different
+            clips.iter().map(|clip| clip.component).collect::<Vec<_>>(),
+            vec!["l", "c"]
         );
+        assert!(clips[1].clamped < 1e-3);
+
+        let (color, clips) = red.shift_oklch(0.0, 0.2, 0.0);
+        assert!(color.in_gamut());
+        assert_eq!(clips[0].component, "c");
+        assert!(clips[0].clamped < clips[0].value);
     }
 
     #[test]
-    fn test_to_cterm() {
-        assert_eq!(to_cterm(String::from("#00c795")), 43);
+    fn test_cterm2hex() {
+        assert_eq!(cterm2hex(43), Some("#00d7af"));
+        assert_eq!(cterm2hex(255), Some("#eeeeee"));
+        assert_eq!(cterm2hex(256), None);
+        assert_eq!(cterm_rgb(43).to_string(), "#00d7af");
     }
 }
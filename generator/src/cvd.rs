@@ -0,0 +1,355 @@
+use crate::conv::{self, Rgb};
+use crate::error;
+use crate::highlight::{Background, Highlight, Palette};
+use crate::palette::lookup;
+use crate::style::Styles;
+use std::fmt;
+
+/// A type of color vision deficiency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Deficiency {
+    Protanopia,
+    Deuteranopia,
+    Tritanopia,
+}
+
+impl Deficiency {
+    pub const ALL: [Deficiency; 3] = [
+        Deficiency::Protanopia,
+        Deficiency::Deuteranopia,
+        Deficiency::Tritanopia,
+    ];
+
+    /// Machado, Oliveira and Fernandes (2009) simulation matrices for
+    /// severity 1.0, applied to linear RGB.
+    fn matrix(self) -> [[f64; 3]; 3] {
+        match self {
+            Deficiency::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            Deficiency::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            Deficiency::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        }
+    }
+}
+
+impl fmt::Display for Deficiency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Deficiency::Protanopia => "protanopia",
+            Deficiency::Deuteranopia => "deuteranopia",
+            Deficiency::Tritanopia => "tritanopia",
+        })
+    }
+}
+
+/// Which color of a highlight group a pair compares.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Channel {
+    Fg,
+    Bg,
+}
+
+impl Channel {
+    pub fn name(self) -> &'static str {
+        match self {
+            Channel::Fg => "fg",
+            Channel::Bg => "bg",
+        }
+    }
+}
+
+/// Highlight groups that carry opposite meanings and have to stay
+/// distinguishable from each other.
+pub const PAIRS: [(Channel, &str, &str); 8] = [
+    (Channel::Bg, "DiffAdd", "DiffDelete"),
+    (Channel::Bg, "DiffAdd", "DiffChange"),
+    (Channel::Bg, "DiffChange", "DiffDelete"),
+    (Channel::Fg, "GitSignsAdd", "GitSignsDelete"),
+    (Channel::Fg, "GitSignsAdd", "GitSignsChange"),
+    (Channel::Fg, "GitSignsChange", "GitSignsDelete"),
+    (Channel::Fg, "DiagnosticError", "DiagnosticWarn"),
+    (Channel::Fg, "DiagnosticInfo", "DiagnosticHint"),
+];
+
+/// Simulates how a color is perceived with the given deficiency.
+pub fn simulate(color: Rgb, deficiency: Deficiency) -> Rgb {
+    let [r, g, b] = color.to_linear();
+
+    Rgb::from_linear(
+        deficiency
+            .matrix()
+            .map(|[mr, mg, mb]| (mr * r + mg * g + mb * b).clamp(0.0, 1.0)),
+    )
+}
+
+/// The CIEDE2000 distance of a pair, as seen with and without a deficiency.
+#[derive(Debug)]
+pub struct Collision {
+    pub a: String,
+    pub b: String,
+    pub deficiency: Deficiency,
+    pub normal: f32,
+    pub simulated: f32,
+}
+
+/// A pair of `PAIRS` that is already closer than the threshold with normal
+/// vision, e.g. because both groups use the same color.
+#[derive(Debug)]
+pub struct Indistinct {
+    pub a: String,
+    pub b: String,
+    pub distance: f32,
+}
+
+/// A pair of `PAIRS` that couldn't be compared.
+#[derive(Debug)]
+pub struct Unresolved {
+    pub a: String,
+    pub b: String,
+    /// Why, e.g. "GitSignsAdd isn't defined".
+    pub reason: String,
+}
+
+/// The outcome of checking `PAIRS` against a palette.
+#[derive(Debug, Default)]
+pub struct Findings {
+    pub collisions: Vec<Collision>,
+    pub indistinct: Vec<Indistinct>,
+    pub unresolved: Vec<Unresolved>,
+}
+
+/// Checks the pairs of `PAIRS`, following links, and returns those whose
+/// distance drops below `threshold` with one of the deficiencies. Pairs that
+/// are already closer than `threshold` with normal vision are returned as
+/// indistinct instead, once rather than for every deficiency. Pairs whose
+/// groups are missing or don't set the color are returned as unresolved.
+pub fn collisions(
+    palette: &Palette,
+    highlights: &[Highlight],
+    threshold: f32,
+) -> error::Result<Findings> {
+    let styles = Styles::new(palette, highlights);
+    let color = |name: &str, channel: Channel| -> error::Result<Result<Rgb, String>> {
+        let Some(hl) = styles.target(name) else {
+            return Ok(Err(format!("{} isn't defined", name)));
+        };
+        let color = match channel {
+            Channel::Fg => &hl.fg,
+            Channel::Bg => &hl.bg,
+        };
+
+        match color.as_deref().filter(|name| *name != "NONE") {
+            Some(color) => Ok(Ok(lookup(palette, color, &hl.name)?.gui)),
+            None if hl.name == name => {
+                Ok(Err(format!("{} doesn't set a {}", name, channel.name())))
+            }
+            None => Ok(Err(format!(
+                "{} links to {}, which doesn't set a {}",
+                name,
+                hl.name,
+                channel.name()
+            ))),
+        }
+    };
+
+    let mut findings = Findings::default();
+
+    for (channel, a, b) in PAIRS {
+        let (ca, cb) = match (color(a, channel)?, color(b, channel)?) {
+            (Ok(ca), Ok(cb)) => (ca, cb),
+            (Err(reason), _) | (_, Err(reason)) => {
+                findings.unresolved.push(Unresolved {
+                    a: a.to_string(),
+                    b: b.to_string(),
+                    reason,
+                });
+                continue;
+            }
+        };
+
+        let normal = conv::distance(ca, cb);
+        if normal < threshold {
+            findings.indistinct.push(Indistinct {
+                a: a.to_string(),
+                b: b.to_string(),
+                distance: normal,
+            });
+            continue;
+        }
+
+        for deficiency in Deficiency::ALL {
+            let simulated = conv::distance(simulate(ca, deficiency), simulate(cb, deficiency));
+
+            if simulated < threshold {
+                findings.collisions.push(Collision {
+                    a: a.to_string(),
+                    b: b.to_string(),
+                    deficiency,
+                    normal,
+                    simulated,
+                });
+            }
+        }
+    }
+
+    Ok(findings)
+}
+
+/// Writes the collisions, indistinct and unresolved pairs of a background as
+/// a table.
+pub fn report<W: std::io::Write>(
+    mut out: W,
+    background: Background,
+    findings: &Findings,
+) -> std::io::Result<()> {
+    let pair = |a: &str, b: &str| format!("{} / {}", a, b);
+    let width = findings
+        .collisions
+        .iter()
+        .map(|c| pair(&c.a, &c.b).len())
+        .chain(findings.indistinct.iter().map(|i| pair(&i.a, &i.b).len()))
+        .chain(findings.unresolved.iter().map(|u| pair(&u.a, &u.b).len()))
+        .max()
+        .unwrap_or(0);
+
+    for c in findings.collisions.iter() {
+        writeln!(
+            out,
+            "{:<6}{:<14}{:<width$}  {:>5.1} -> {:>5.1}",
+            background,
+            c.deficiency,
+            pair(&c.a, &c.b),
+            c.normal,
+            c.simulated,
+            width = width,
+        )?;
+    }
+
+    for i in findings.indistinct.iter() {
+        writeln!(
+            out,
+            "{:<6}{:<14}{:<width$}  {:>5.1}",
+            background,
+            "normal vision",
+            pair(&i.a, &i.b),
+            i.distance,
+            width = width,
+        )?;
+    }
+
+    for u in findings.unresolved.iter() {
+        writeln!(
+            out,
+            "{:<6}{:<14}{:<width$}  {}",
+            background,
+            "unresolved",
+            pair(&u.a, &u.b),
+            u.reason,
+            width = width,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::highlight::parse_highlights;
+    use crate::palette::parse_palette;
+
+    #[test]
+    fn test_simulate() {
+        let rgb = |hex| Rgb::parse(hex).unwrap();
+
+        // grays are seen the same way with every deficiency
+        for deficiency in Deficiency::ALL {
+            assert_eq!(simulate(rgb("#777777"), deficiency).to_string(), "#777777");
+        }
+
+        // red and green get much closer with protanopia and deuteranopia
+        let normal = conv::distance(rgb("#cc3333"), rgb("#339933"));
+        for deficiency in [Deficiency::Protanopia, Deficiency::Deuteranopia] {
+            let red = simulate(rgb("#cc3333"), deficiency);
+            let green = simulate(rgb("#339933"), deficiency);
+            assert!(conv::distance(red, green) < normal / 2.0);
+        }
+    }
+
+    #[test]
+    fn test_collisions() {
+        let palette = parse_palette(
+            r##"
+red = "#cc3333"
+green = "#339933"
+blue = "#3333cc"
+"##,
+        )
+        .unwrap();
+        let highlights = parse_highlights(
+            r#"
+[[section]]
+highlights = [
+  { name = "GitSignsAdd", fg = "green" },
+  { name = "GitSignsChange", fg = "blue" },
+  { name = "GitSignsDelete", fg = "red" },
+  { name = "DiagnosticInfo", fg = "blue" },
+  { name = "DiagnosticHint", link = "DiagnosticInfo" },
+  { name = "DiagnosticError", fg = "red" },
+  { name = "DiagnosticWarn", link = "DiffAdd" },
+  { name = "DiffAdd", bg = "green" },
+]
+"#,
+            &palette,
+        )
+        .unwrap();
+
+        let findings = collisions(&palette, &highlights, 10.0).unwrap();
+        let found: Vec<_> = findings
+            .collisions
+            .iter()
+            .map(|c| (c.a.as_str(), c.b.as_str(), c.deficiency))
+            .collect();
+
+        assert!(found.contains(&("GitSignsAdd", "GitSignsDelete", Deficiency::Deuteranopia)));
+        // followed through the link, Hint shares the color of Info, which is
+        // reported once as indistinct rather than for every deficiency
+        assert!(!found.iter().any(|(a, _, _)| *a == "DiagnosticInfo"));
+        let indistinct: Vec<_> = findings
+            .indistinct
+            .iter()
+            .map(|i| (i.a.as_str(), i.b.as_str(), i.distance))
+            .collect();
+        assert_eq!(indistinct, vec![("DiagnosticInfo", "DiagnosticHint", 0.0)]);
+
+        let unresolved: Vec<_> = findings
+            .unresolved
+            .iter()
+            .map(|u| (u.a.as_str(), u.b.as_str(), u.reason.as_str()))
+            .collect();
+        assert_eq!(
+            unresolved,
+            vec![
+                ("DiffAdd", "DiffDelete", "DiffDelete isn't defined"),
+                ("DiffAdd", "DiffChange", "DiffChange isn't defined"),
+                ("DiffChange", "DiffDelete", "DiffChange isn't defined"),
+                (
+                    "DiagnosticError",
+                    "DiagnosticWarn",
+                    "DiagnosticWarn links to DiffAdd, which doesn't set a fg"
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use crate::parse::ParseError;
+use crate::validate::Problem;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Everything that can go wrong while generating the colorscheme.
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// A data file couldn't be parsed.
+    Parse {
+        path: PathBuf,
+        error: ParseError,
+    },
+    /// A highlight group or writer referenced a color missing from the
+    /// palette, e.g. `referenced_by` is "highlight Normal".
+    UnknownColor {
+        name: String,
+        referenced_by: String,
+    },
+    /// The highlights failed validation.
+    Invalid(Vec<Problem>),
+    /// A section marker is missing from a file being updated in place.
+    MissingMarker {
+        marker: String,
+        path: PathBuf,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{}", err),
+            Error::Parse { path, error } => {
+                write!(f, "{}:{}: {}", path.display(), error.line, error.message)
+            }
+            Error::UnknownColor {
+                name,
+                referenced_by,
+            } => write!(
+                f,
+                "unknown color \"{}\" referenced by {}",
+                name, referenced_by
+            ),
+            Error::Invalid(problems) => {
+                write!(f, "{} problem(s) in the highlights", problems.len())?;
+                for problem in problems.iter() {
+                    write!(f, "\n  {}", problem)?;
+                }
+                Ok(())
+            }
+            Error::MissingMarker { marker, path } => {
+                write!(f, "marker '{}' not found in {}", marker, path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Parse { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
@@ -0,0 +1,59 @@
+use crate::palette::Clipped;
+
+/// Writes every clamped component of the clipped entries as a table and
+/// returns how many entries there were.
+pub fn report<W: std::io::Write>(mut out: W, clipped: &[Clipped]) -> std::io::Result<usize> {
+    let width = clipped.iter().map(|c| c.name.len()).max().unwrap_or(0);
+
+    for entry in clipped.iter() {
+        for clip in entry.clips.iter() {
+            writeln!(
+                out,
+                "{:<width$}  {:<6}{} {:>6.3} -> {:>5.3}  clipped by {:.3}",
+                entry.name,
+                entry.space.name(),
+                clip.component,
+                clip.value,
+                clip.clamped,
+                (clip.value - clip.clamped).abs(),
+                width = width,
+            )?;
+        }
+    }
+
+    Ok(clipped.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conv::CtermOptions;
+    use crate::palette::parse_palette_with;
+
+    #[test]
+    fn test_report() {
+        let clipped = parse_palette_with(
+            r##"
+blue = "#3333cc"
+lightblue = { extends = "blue", s = -0.9, v = 0.3 }
+darkblue = { extends = "blue", v = -0.2 }
+brightblue = { extends = "blue", space = "oklch", l = 0.8 }
+"##,
+            &CtermOptions::default(),
+        )
+        .unwrap()
+        .clipped;
+
+        let mut out = vec![];
+        assert_eq!(report(&mut out, &clipped).unwrap(), 2);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\
+lightblue   hsv   s -0.150 -> 0.000  clipped by 0.150
+lightblue   hsv   v  1.100 -> 1.000  clipped by 0.100
+brightblue  oklch l  1.242 -> 1.000  clipped by 0.242
+brightblue  oklch c  0.226 -> 0.000  clipped by 0.226
+"
+        );
+    }
+}
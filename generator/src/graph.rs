@@ -0,0 +1,336 @@
+use crate::contrast::wcag_contrast;
+use crate::conv::Rgb;
+use crate::error;
+use crate::highlight::Color;
+use crate::palette::{lookup, Derivations, ResolvedPalette};
+use std::collections::HashSet;
+use std::io;
+
+const REFERENCED_BY: &str = "the graph";
+
+/// The language the derivation graph is written in.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Format {
+    /// Graphviz, e.g. `dot -Tsvg`.
+    #[default]
+    Dot,
+    /// Mermaid flowchart, which GitHub renders in Markdown.
+    Mermaid,
+}
+
+impl Format {
+    pub const ALL: [Format; 2] = [Format::Dot, Format::Mermaid];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Format::Dot => "dot",
+            Format::Mermaid => "mermaid",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|format| format.name() == name)
+    }
+}
+
+/// An edge from a parent to a derived entry, labeled with the deltas, or
+/// from the entry a derived one is blended with.
+struct Edge<'a> {
+    from: &'a str,
+    to: &'a str,
+    deltas: String,
+    blend: bool,
+}
+
+/// Writes the derivation graph of the palette: every entry is a node filled
+/// with its color, with an edge from its parent and a dashed one from the
+/// entry it is blended with. With `from`, only that entry and the entries
+/// derived from it, i.e. those a change to it affects, are written.
+pub fn write<W: io::Write>(
+    mut out: W,
+    format: Format,
+    palette: &ResolvedPalette,
+    from: Option<&str>,
+) -> error::Result<()> {
+    let ResolvedPalette {
+        colors: palette,
+        derivations,
+        ..
+    } = palette;
+
+    let included = match from {
+        Some(from) => {
+            lookup(palette, from, REFERENCED_BY)?;
+            descendants(derivations, from)
+        }
+        None => derivations.iter().map(|(name, _)| name.as_str()).collect(),
+    };
+
+    let mut nodes = vec![];
+    let mut edges = vec![];
+    for (name, derivation) in derivations.iter() {
+        if !included.contains(name.as_str()) {
+            continue;
+        }
+        nodes.push((name.as_str(), lookup(palette, name, REFERENCED_BY)?));
+
+        let Some(derivation) = derivation else {
+            continue;
+        };
+        if included.contains(derivation.parent.as_str()) {
+            edges.push(Edge {
+                from: &derivation.parent,
+                to: name,
+                deltas: derivation.deltas(),
+                blend: false,
+            });
+        }
+        if let Some(color) = derivation.blend.as_ref().map(|blend| blend.color()) {
+            if included.contains(color) {
+                edges.push(Edge {
+                    from: color,
+                    to: name,
+                    deltas: String::new(),
+                    blend: true,
+                });
+            }
+        }
+    }
+
+    match format {
+        Format::Dot => write_dot(&mut out, &nodes, &edges)?,
+        Format::Mermaid => write_mermaid(&mut out, &nodes, &edges)?,
+    }
+
+    Ok(())
+}
+
+/// Returns `from` and every entry derived from it, directly or through
+/// other entries, as a parent or as the color it is blended with.
+fn descendants<'a>(derivations: &'a Derivations, from: &'a str) -> HashSet<&'a str> {
+    let mut found = HashSet::from([from]);
+
+    loop {
+        let before = found.len();
+        for (name, derivation) in derivations.iter() {
+            let Some(derivation) = derivation else {
+                continue;
+            };
+            let blend = derivation.blend.as_ref().map(|blend| blend.color());
+            if found.contains(derivation.parent.as_str())
+                || blend.is_some_and(|color| found.contains(color))
+            {
+                found.insert(name);
+            }
+        }
+        if found.len() == before {
+            return found;
+        }
+    }
+}
+
+fn write_dot<W: io::Write>(
+    out: &mut W,
+    nodes: &[(&str, &Color)],
+    edges: &[Edge<'_>],
+) -> io::Result<()> {
+    writeln!(out, "digraph palette {{")?;
+    writeln!(out, "  rankdir=LR;")?;
+    writeln!(
+        out,
+        "  node [shape=box, style=filled, fontname=\"monospace\"];"
+    )?;
+
+    for (name, color) in nodes.iter() {
+        writeln!(
+            out,
+            "  {} [label={}, fillcolor=\"{}\", fontcolor=\"{}\"];",
+            quote(name),
+            quote(&format!("{}\\n{}", name, color.gui)),
+            color.gui,
+            text_color(color),
+        )?;
+    }
+    for edge in edges.iter() {
+        let attrs = if edge.blend {
+            " [style=dashed]".to_string()
+        } else if edge.deltas.is_empty() {
+            String::new()
+        } else {
+            format!(" [label={}]", quote(&edge.deltas))
+        };
+        writeln!(
+            out,
+            "  {} -> {}{};",
+            quote(edge.from),
+            quote(edge.to),
+            attrs
+        )?;
+    }
+
+    writeln!(out, "}}")
+}
+
+/// Nodes get ids by position (`n0`, `n1`, ...) and the name only appears in
+/// the label, since names like `dark-red` or `end` aren't valid Mermaid ids.
+fn write_mermaid<W: io::Write>(
+    out: &mut W,
+    nodes: &[(&str, &Color)],
+    edges: &[Edge<'_>],
+) -> io::Result<()> {
+    let id = |name: &str| {
+        let index = nodes.iter().position(|(n, _)| *n == name).unwrap();
+        format!("n{}", index)
+    };
+
+    writeln!(out, "flowchart LR")?;
+
+    for (name, color) in nodes.iter() {
+        writeln!(out, "  {}[\"{}<br/>{}\"]", id(name), name, color.gui)?;
+    }
+    for edge in edges.iter() {
+        let arrow = if edge.blend {
+            "-.->".to_string()
+        } else if edge.deltas.is_empty() {
+            "-->".to_string()
+        } else {
+            format!("-->|\"{}\"|", edge.deltas)
+        };
+        writeln!(out, "  {} {} {}", id(edge.from), arrow, id(edge.to))?;
+    }
+    for (name, color) in nodes.iter() {
+        writeln!(
+            out,
+            "  style {} fill:{},color:{}",
+            id(name),
+            color.gui,
+            text_color(color)
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Black or white, whichever is more legible over the color.
+fn text_color(color: &Color) -> &'static str {
+    let rgb = color.gui;
+    if wcag_contrast(rgb, Rgb::new(0.0, 0.0, 0.0)) >= wcag_contrast(rgb, Rgb::new(1.0, 1.0, 1.0)) {
+        "#000000"
+    } else {
+        "#ffffff"
+    }
+}
+
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conv::CtermOptions;
+    use crate::palette::{parse_palette_with, ResolvedPalette};
+
+    const PALETTE: &str = r##"
+mainbg = "#000000"
+red = "#ff0000"
+darkred = { extends = "red", v = -0.2 }
+errorfg = { extends = "darkred" }
+errorbg = { extends = "red", over = "mainbg", alpha = 0.2 }
+"##;
+
+    fn resolve() -> ResolvedPalette {
+        parse_palette_with(PALETTE, &CtermOptions::default()).unwrap()
+    }
+
+    fn graph(format: Format, from: Option<&str>) -> String {
+        let mut buf = vec![];
+        write(&mut buf, format, &resolve(), from).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_write_dot() {
+        assert_eq!(
+            graph(Format::Dot, None),
+            r##"digraph palette {
+  rankdir=LR;
+  node [shape=box, style=filled, fontname="monospace"];
+  "mainbg" [label="mainbg\n#000000", fillcolor="#000000", fontcolor="#ffffff"];
+  "red" [label="red\n#ff0000", fillcolor="#ff0000", fontcolor="#000000"];
+  "darkred" [label="darkred\n#cc0000", fillcolor="#cc0000", fontcolor="#ffffff"];
+  "errorfg" [label="errorfg\n#cc0000", fillcolor="#cc0000", fontcolor="#ffffff"];
+  "errorbg" [label="errorbg\n#330000", fillcolor="#330000", fontcolor="#ffffff"];
+  "red" -> "darkred" [label="v -0.2"];
+  "darkred" -> "errorfg";
+  "red" -> "errorbg" [label="over mainbg alpha 0.2"];
+  "mainbg" -> "errorbg" [style=dashed];
+}
+"##
+        );
+    }
+
+    #[test]
+    fn test_write_mermaid() {
+        assert_eq!(
+            graph(Format::Mermaid, Some("darkred")),
+            r##"flowchart LR
+  n0["darkred<br/>#cc0000"]
+  n1["errorfg<br/>#cc0000"]
+  n0 --> n1
+  style n0 fill:#cc0000,color:#ffffff
+  style n1 fill:#cc0000,color:#ffffff
+"##
+        );
+    }
+
+    #[test]
+    fn test_write_mermaid_ids() {
+        // neither a reserved word nor a name with a dash is used as an id
+        let palette = parse_palette_with(
+            r##"
+end = "#000000"
+dark-end = { extends = "end", v = 0.2 }
+"##,
+            &CtermOptions::default(),
+        )
+        .unwrap();
+
+        let mut buf = vec![];
+        write(&mut buf, Format::Mermaid, &palette, None).unwrap();
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            r##"flowchart LR
+  n0["end<br/>#000000"]
+  n1["dark-end<br/>#333333"]
+  n0 -->|"v +0.2"| n1
+  style n0 fill:#000000,color:#ffffff
+  style n1 fill:#333333,color:#ffffff
+"##
+        );
+    }
+
+    #[test]
+    fn test_from() {
+        // entries blended with `mainbg` are affected by it as well
+        let graph = graph(Format::Mermaid, Some("mainbg"));
+        assert!(
+            graph.contains("  n0[\"mainbg<br/>#000000\"]\n"),
+            "{}",
+            graph
+        );
+        assert!(
+            graph.contains("  n1[\"errorbg<br/>#330000\"]\n"),
+            "{}",
+            graph
+        );
+        assert!(graph.contains("  n0 -.-> n1\n"), "{}", graph);
+        assert!(!graph.contains("darkred"), "{}", graph);
+
+        let err = write(vec![], Format::Dot, &resolve(), Some("blue")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown color \"blue\" referenced by the graph"
+        );
+    }
+}
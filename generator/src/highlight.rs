@@ -1,904 +1,685 @@
 use super::*;
+use crate::conv::{CtermOptions, Rgb};
+use crate::error::{self, Error};
+use crate::parse::ParseError;
 use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::de::{DeTable, DeValue};
+use toml::Spanned;
 
-#[derive(Debug, PartialEq)]
+/// The highlight definitions shipped with the generator.
+pub const DEFAULT_HIGHLIGHTS: &str = include_str!("../highlights.toml");
+
+/// A palette color: the gui color and its 256-color palette index.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Color {
-    pub gui: String,
-    pub cterm: String,
+    pub gui: Rgb,
+    pub cterm: u8,
 }
 
-pub type ColorName = Option<&'static str>;
+pub type ColorName = Option<String>;
 
-pub type Palette = HashMap<&'static str, Color>;
+pub type Palette = HashMap<String, Color>;
 
-#[derive(Debug)]
-pub enum HighlightAttr {
-    Nothing,
-    None,
-    Bold,
-    Italic,
-    Underline,
-    Strikethrough,
-    Reverse,
+/// A set of highlight attributes (`gui=bold,italic`).
+///
+/// The empty set leaves the attributes of a group untouched, while `NONE`
+/// explicitly resets them (`gui=NONE`) and cannot be combined with others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HighlightAttr(u16);
+
+impl HighlightAttr {
+    pub const NOTHING: Self = Self(0);
+    pub const NONE: Self = Self(1);
+    pub const BOLD: Self = Self(1 << 1);
+    pub const ITALIC: Self = Self(1 << 2);
+    pub const UNDERLINE: Self = Self(1 << 3);
+    pub const UNDERCURL: Self = Self(1 << 4);
+    pub const UNDERDOUBLE: Self = Self(1 << 5);
+    pub const UNDERDOTTED: Self = Self(1 << 6);
+    pub const UNDERDASHED: Self = Self(1 << 7);
+    pub const STRIKETHROUGH: Self = Self(1 << 8);
+    pub const REVERSE: Self = Self(1 << 9);
+    pub const STANDOUT: Self = Self(1 << 10);
+    pub const NOCOMBINE: Self = Self(1 << 11);
+    pub const ALTFONT: Self = Self(1 << 12);
+
+    /// Attribute names as used by `:highlight` and `nvim_set_hl()`.
+    const NAMES: [(Self, &'static str); 13] = [
+        (Self::NONE, "NONE"),
+        (Self::BOLD, "bold"),
+        (Self::ITALIC, "italic"),
+        (Self::UNDERLINE, "underline"),
+        (Self::UNDERCURL, "undercurl"),
+        (Self::UNDERDOUBLE, "underdouble"),
+        (Self::UNDERDOTTED, "underdotted"),
+        (Self::UNDERDASHED, "underdashed"),
+        (Self::STRIKETHROUGH, "strikethrough"),
+        (Self::REVERSE, "reverse"),
+        (Self::STANDOUT, "standout"),
+        (Self::NOCOMBINE, "nocombine"),
+        (Self::ALTFONT, "altfont"),
+    ];
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Looks up a single attribute by name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::NAMES
+            .iter()
+            .find(|(_, n)| *n == name)
+            .map(|(attr, _)| *attr)
+    }
+
+    /// Returns the names of the attributes in the set, in `:highlight` order.
+    pub fn names(self) -> Vec<&'static str> {
+        Self::NAMES
+            .iter()
+            .filter(|(attr, _)| self.contains(*attr))
+            .map(|(_, name)| *name)
+            .collect()
+    }
+}
+
+impl std::ops::BitOr for HighlightAttr {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum HighlightScope {
     All,
     Nvim,
     Nvim080OrLater,
 }
 
+impl HighlightScope {
+    /// Returns whether groups of this scope are defined wherever groups of
+    /// `other` are, e.g. an `All` group can be linked to from anywhere.
+    pub fn covers(self, other: HighlightScope) -> bool {
+        match self {
+            HighlightScope::All => true,
+            HighlightScope::Nvim => other != HighlightScope::All,
+            HighlightScope::Nvim080OrLater => other == HighlightScope::Nvim080OrLater,
+        }
+    }
+}
+
+impl fmt::Display for HighlightScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            HighlightScope::All => "all",
+            HighlightScope::Nvim => "nvim",
+            HighlightScope::Nvim080OrLater => "nvim-0.8",
+        })
+    }
+}
+
+/// The value of Vim's `'background'` option a palette is made for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Background {
+    Dark,
+    Light,
+}
+
+impl fmt::Display for Background {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Background::Dark => "dark",
+            Background::Light => "light",
+        })
+    }
+}
+
 #[derive(Debug)]
 pub struct Highlight {
-    pub name: &'static str,
+    pub name: String,
     pub fg: ColorName,
     pub bg: ColorName,
     pub sp: ColorName,
     pub attr: HighlightAttr,
     pub scope: HighlightScope,
+    pub link: Option<String>,
 }
 
-macro_rules! highlight {
-    ($name: literal, $fg: expr, $bg: expr, $sp: expr, $attr: ident, $scope: ident) => {
-        Highlight {
-            name: $name,
-            fg: $fg,
-            bg: $bg,
-            sp: $sp,
-            attr: HighlightAttr::$attr,
-            scope: HighlightScope::$scope,
+impl Highlight {
+    /// Creates a group that links to `target` (`hi! link name target`).
+    pub fn link(name: &str, target: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            fg: None,
+            bg: None,
+            sp: None,
+            attr: HighlightAttr::NOTHING,
+            scope: HighlightScope::All,
+            link: Some(target.to_string()),
         }
-    };
+    }
 }
 
-macro_rules! hi {
-    ($name: literal, -, -, -, -, -) => {
-        highlight!($name, None, None, None, Nothing, All)
-    };
-    ($name: literal, $fg: ident, -, -, -, -) => {
-        highlight!($name, Some(stringify!($fg)), None, None, Nothing, All)
-    };
-    ($name: literal, -, $bg: ident, -, -, -) => {
-        highlight!($name, None, Some(stringify!($bg)), None, Nothing, All)
-    };
-    ($name: literal, -, -, $sp: ident, -, -) => {
-        highlight!($name, None, None, Some(stringify!($sp)), Nothing, All)
-    };
-    ($name: literal, -, -, -, $attr: ident, -) => {
-        highlight!($name, None, None, None, $attr, All)
-    };
-    ($name: literal, -, -, -, -, $scope: expr) => {
-        highlight!($name, None, None, None, $attr, $scope)
-    };
-    ($name: literal, $fg: ident, $bg: ident, -, -, -) => {
-        highlight!(
-            $name,
-            Some(stringify!($fg)),
-            Some(stringify!($bg)),
-            None,
-            Nothing,
-            All
-        )
-    };
-    ($name: literal, $fg: ident, $bg: ident, -, -, $scope: ident) => {
-        highlight!(
-            $name,
-            Some(stringify!($fg)),
-            Some(stringify!($bg)),
-            None,
-            Nothing,
-            $scope
-        )
-    };
-    ($name: literal, $fg: ident, -, -, -, $scope: ident) => {
-        highlight!($name, Some(stringify!($fg)), None, None, Nothing, $scope)
-    };
-    ($name: literal, $fg: ident, -, -, $attr: ident, -) => {
-        highlight!($name, Some(stringify!($fg)), None, None, $attr, All)
-    };
-    ($name: literal, $fg: ident, -, -, $attr: ident, $scope: ident) => {
-        highlight!($name, Some(stringify!($fg)), None, None, $attr, $scope)
-    };
-    ($name: literal, -, $bg: ident, -, $attr: ident, -) => {
-        highlight!($name, None, Some(stringify!($bg)), None, $attr, All)
-    };
-    ($name: literal, -, $bg: ident, -, -, $scope: ident) => {
-        highlight!($name, None, Some(stringify!($bg)), None, Nothing, $scope)
-    };
-    ($name: literal, -, $bg: ident, -, $attr: ident, $scope: ident) => {
-        highlight!($name, None, Some(stringify!($bg)), None, $attr, $scope)
-    };
-    ($name: literal, $fg: ident, $bg: ident, -, -, -) => {
-        highlight!(
-            $name,
-            Some(stringify!($fg)),
-            Some(stringify!($bg)),
-            None,
-            Nothing,
-            -
-        )
-    };
-    ($name: literal, $fg: ident, $bg: ident, -, -, $scope: ident) => {
-        highlight!(
-            $name,
-            Some(stringify!($fg)),
-            Some(stringify!($bg)),
-            None,
-            Nothing,
-            $scope
-        )
-    };
-    ($name: literal, $fg: ident, $bg: ident, -, $attr: ident, -) => {
-        highlight!(
-            $name,
-            Some(stringify!($fg)),
-            Some(stringify!($bg)),
-            None,
-            $attr,
-            All
-        )
-    };
-    ($name: literal, $fg: ident, $bg: ident, -, $attr: ident, $scope: ident) => {
-        highlight!(
-            $name,
-            Some(stringify!($fg)),
-            Some(stringify!($bg)),
-            None,
-            $attr,
-            $scope
+/// Returns the palette defined in `palette.toml`.
+pub fn get_palette() -> error::Result<Palette> {
+    Ok(palette::default_palette(&CtermOptions::default())?.colors)
+}
+
+/// Returns the light variant of the palette defined in `palette.toml`.
+pub fn get_light_palette() -> error::Result<Palette> {
+    Ok(palette::light_palette(&palette::default_palette(
+        &CtermOptions::default(),
+    )?))
+}
+
+/// Returns the highlights defined in `highlights.toml`, which must pass
+/// validation.
+pub fn get_highlights() -> error::Result<Vec<Highlight>> {
+    let palette = get_palette()?;
+    let highlights = default_highlights(&palette)?;
+
+    let problems = validate::validate(&palette, &highlights);
+    if !problems.is_empty() {
+        return Err(Error::Invalid(problems));
+    }
+
+    Ok(highlights)
+}
+
+/// Parses `highlights.toml` against `palette`, without validating it.
+pub fn default_highlights(palette: &Palette) -> error::Result<Vec<Highlight>> {
+    parse_highlights(DEFAULT_HIGHLIGHTS, palette).map_err(|error| Error::Parse {
+        path: PathBuf::from("highlights.toml"),
+        error,
+    })
+}
+
+/// Parses a highlights file, checking every color against `palette`.
+/// See `highlights.toml` for the format.
+pub fn parse_highlights(source: &str, palette: &Palette) -> Result<Vec<Highlight>, ParseError> {
+    let table = DeTable::parse(source).map_err(|err| ParseError::from_toml(source, &err))?;
+    let mut highlights = vec![];
+
+    for (key, value) in table.get_ref().iter() {
+        if key.get_ref() != "section" {
+            return Err(ParseError::at(
+                source,
+                key.span().start,
+                format!("unknown key `{}`", key.get_ref()),
+            ));
+        }
+
+        for section in array(source, value, "section")? {
+            parse_section(source, palette, section, &mut highlights)?;
+        }
+    }
+
+    check_links(source, &highlights)?;
+
+    Ok(highlights.into_iter().map(|(hl, _)| hl).collect())
+}
+
+/// Reads and parses a highlights file.
+pub fn load_highlights(path: &Path, palette: &Palette) -> error::Result<Vec<Highlight>> {
+    let source = fs::read_to_string(path)?;
+
+    parse_highlights(&source, palette).map_err(|error| Error::Parse {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+fn parse_section(
+    source: &str,
+    palette: &Palette,
+    section: &Spanned<DeValue<'_>>,
+    highlights: &mut Vec<(Highlight, usize)>,
+) -> Result<(), ParseError> {
+    let mut scope = HighlightScope::All;
+    let mut groups = None;
+
+    for (key, value) in table(source, section, "section")? {
+        match key.get_ref().as_ref() {
+            "name" | "url" => {
+                string(source, value, key.get_ref())?;
+            }
+            "scope" => scope = parse_scope(source, value)?,
+            "highlights" => groups = Some(array(source, value, "highlights")?),
+            other => {
+                return Err(ParseError::at(
+                    source,
+                    key.span().start,
+                    format!("unknown section key `{}`", other),
+                ))
+            }
+        }
+    }
+
+    let groups = groups.ok_or_else(|| {
+        ParseError::at(
+            source,
+            section.span().start,
+            "section is missing `highlights`",
         )
+    })?;
+
+    for group in groups {
+        let hl = parse_highlight(source, palette, group, scope)?;
+        highlights.push((hl, group.span().start));
+    }
+
+    Ok(())
+}
+
+fn parse_highlight(
+    source: &str,
+    palette: &Palette,
+    group: &Spanned<DeValue<'_>>,
+    scope: HighlightScope,
+) -> Result<Highlight, ParseError> {
+    let mut hl = Highlight {
+        name: String::new(),
+        fg: None,
+        bg: None,
+        sp: None,
+        attr: HighlightAttr::NOTHING,
+        scope,
+        link: None,
     };
-    ($name: literal, -, -, -, $attr: ident, $scope: ident) => {
-        highlight!($name, None, None, None, $attr, $scope)
-    };
+
+    for (key, value) in table(source, group, "highlight")? {
+        let key = key.get_ref().as_ref();
+
+        match key {
+            "name" => hl.name = string(source, value, key)?.to_string(),
+            "fg" | "bg" | "sp" => {
+                let name = string(source, value, key)?;
+                let allows_none = key != "sp";
+
+                if !(palette.contains_key(name) || allows_none && name == "NONE") {
+                    return Err(ParseError::at(
+                        source,
+                        value.span().start,
+                        format!("`{}` refers to unknown color \"{}\"", key, name),
+                    ));
+                }
+
+                let color = Some(name.to_string());
+                match key {
+                    "fg" => hl.fg = color,
+                    "bg" => hl.bg = color,
+                    _ => hl.sp = color,
+                }
+            }
+            "attr" => hl.attr = parse_attr(source, value)?,
+            "scope" => hl.scope = parse_scope(source, value)?,
+            "link" => hl.link = Some(string(source, value, key)?.to_string()),
+            other => {
+                return Err(ParseError::at(
+                    source,
+                    value.span().start,
+                    format!("unknown highlight key `{}`", other),
+                ))
+            }
+        }
+    }
+
+    if hl.name.is_empty() {
+        return Err(ParseError::at(
+            source,
+            group.span().start,
+            "highlight is missing `name`",
+        ));
+    }
+
+    let has_colors = hl.fg.is_some() || hl.bg.is_some() || hl.sp.is_some();
+    if hl.link.is_some() && (has_colors || !hl.attr.is_empty()) {
+        return Err(ParseError::at(
+            source,
+            group.span().start,
+            format!(
+                "\"{}\": `link` cannot be combined with colors or `attr`",
+                hl.name
+            ),
+        ));
+    }
+
+    Ok(hl)
 }
 
-pub fn get_palette() -> Palette {
-    let mut p = HashMap::new();
-
-    macro_rules! def {
-        ($name: ident, $hex: expr) => {
-            assert_eq!(
-                p.insert(
-                    stringify!($name),
-                    Color {
-                        gui: String::from($hex),
-                        cterm: conv::to_cterm($hex.to_string()).to_string(),
-                    }
-                ),
-                None
-            );
+/// Checks that every link points to a group that is defined wherever the
+/// link is, and that links do not form a cycle.
+fn check_links(source: &str, highlights: &[(Highlight, usize)]) -> Result<(), ParseError> {
+    let groups: HashMap<&str, &Highlight> = highlights
+        .iter()
+        .map(|(hl, _)| (hl.name.as_str(), hl))
+        .collect();
+
+    for (hl, offset) in highlights.iter() {
+        let target = match &hl.link {
+            Some(target) => target,
+            None => continue,
         };
-    }
 
-    macro_rules! extends {
-        ($parent: ident) => {
-            match p.get(stringify!($parent)) {
-                Some(highlight) => highlight.gui.to_string(),
-                None => panic!("\"{}\" does not exists", stringify!($parent)),
+        match groups.get(target.as_str()) {
+            None => {
+                return Err(ParseError::at(
+                    source,
+                    *offset,
+                    format!("\"{}\" links to unknown group \"{}\"", hl.name, target),
+                ))
             }
-        };
-        ($parent: ident, $h: expr, $s: expr, $v: expr) => {
-            conv::hue(conv::saturate(conv::lighten(extends!($parent), $v), $s), $h)
-        };
+            Some(linked) if !linked.scope.covers(hl.scope) => {
+                return Err(ParseError::at(
+                    source,
+                    *offset,
+                    format!(
+                        "\"{}\" links to \"{}\", which is not defined in its scope",
+                        hl.name, target
+                    ),
+                ))
+            }
+            Some(_) => {}
+        }
+
+        // A cycle that does not go through this group is reported when
+        // checking one of its members
+        let mut chain = vec![hl.name.as_str()];
+        let mut current = target.as_str();
+        while let Some(next) = groups.get(current) {
+            let seen = chain.contains(&current);
+            chain.push(current);
+            if current == hl.name {
+                return Err(ParseError::at(
+                    source,
+                    *offset,
+                    format!("circular link: {}", chain.join(" -> ")),
+                ));
+            } else if seen {
+                break;
+            }
+            match &next.link {
+                Some(target) => current = target,
+                None => break,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_attr(source: &str, value: &Spanned<DeValue<'_>>) -> Result<HighlightAttr, ParseError> {
+    let mut attr = HighlightAttr::NOTHING;
+
+    for name in string(source, value, "attr")?.split(',').map(str::trim) {
+        attr = attr
+            | HighlightAttr::from_name(name).ok_or_else(|| {
+                ParseError::at(
+                    source,
+                    value.span().start,
+                    format!("unknown attr \"{}\"", name),
+                )
+            })?;
+    }
+
+    if attr.contains(HighlightAttr::NONE) && attr != HighlightAttr::NONE {
+        return Err(ParseError::at(
+            source,
+            value.span().start,
+            "attr \"NONE\" cannot be combined with other attributes",
+        ));
     }
 
-    // palettes
-    def!(red, "#ff9494");
-    def!(darkred, extends!(red, 0.0, 0.0, -0.2));
-    def!(pink, "#b871b8");
-    def!(darkpink, extends!(pink, 0.0, -0.05, -0.35));
-    def!(lightpink, extends!(pink, 0.0, 0.0, 0.03));
-    def!(purple, "#929be5");
-    def!(darkpurple, extends!(purple, 0.0, 0.05, -0.15));
-    def!(darkestpurple, extends!(purple, 0.0, 0.05, -0.42));
-    def!(blue, "#589ec6");
-    def!(darkblue, extends!(blue, 0.0, 0.0, -0.2));
-    def!(darkestblue, extends!(blue, 0.0, 0.05, -0.48));
-    def!(cyan, "#59b6b6");
-    def!(darkcyan, extends!(cyan, 0.0, 0.0, -0.2));
-    def!(teal, "#73c1a9");
-    def!(darkteal, extends!(teal, 0.0, 0.0, -0.15));
-    def!(darkestteal, extends!(teal, 0.0, 0.05, -0.48));
-    def!(green, "#7cbe8c");
-    def!(darkgreen, extends!(green, 0.0, -0.05, -0.4));
-    def!(yellow, "#a8a384");
-    def!(darkyellow, extends!(yellow, 0.0, -0.15, -0.1));
-    def!(lightyellow, extends!(yellow, 0.0, 0.0, 0.09));
-    def!(orange, "#ac8b83");
-    def!(darkorange, extends!(orange, 0.0, -0.05, -0.1));
-    def!(lightorange, extends!(orange, 0.0, 0.0, 0.08));
-
-    // neutral
-    def!(mainfg, "#9ea3c0");
-    def!(mainbg, "#222433");
-    def!(weakfg, extends!(mainbg, 0.0, 0.05, 0.35));
-    def!(weakbg, extends!(mainbg, 0.0, 0.0, 0.1));
-    def!(emphasisfg, extends!(mainfg, 0.0, 0.0, 0.15));
-    def!(emphasisbg, extends!(mainbg, 0.0, 0.0, 0.05));
-    def!(darkfg, extends!(mainbg, 0.0, 0.05, 0.15));
-    def!(darkbg, extends!(mainbg, 0.0, 0.0, 0.05));
-    def!(lightfg, extends!(mainfg, 0.0, 0.05, -0.1));
-    def!(lightbg, extends!(mainbg, 0.0, 0.0, 0.2));
-    def!(white, "#ffffff");
-    def!(black, "#000000");
-
-    // messages
-    def!(morefg, extends!(teal));
-    def!(errorbg, extends!(mainbg));
-    def!(errorfg, extends!(red, 0.0, 0.0, 0.0));
-    def!(errorborder, extends!(errorfg, 0.0, -0.1, -0.2));
-    def!(warningbg, extends!(mainbg));
-    def!(warningfg, extends!(orange, 0.0, 0.0, 0.0));
-    def!(warningborder, extends!(orange, 0.0, -0.1, -0.2));
-    def!(infobg, extends!(mainbg));
-    def!(infofg, extends!(teal, 0.0, 0.0, 0.1));
-    def!(infoborder, extends!(teal, 0.0, -0.1, -0.2));
-    def!(debugbg, extends!(mainbg));
-    def!(debugfg, extends!(mainfg));
-    def!(debugborder, extends!(debugfg, 0.0, -0.1, -0.2));
-
-    // visual
-    def!(visualbg, extends!(purple, 0.0, 0.2, -0.4));
-
-    // linenr
-    def!(linenrfg, extends!(mainbg, 0.0, 0.0, 0.1));
-    def!(cursorlinebg, extends!(mainbg, 0.0, 0.0, 0.05));
-    def!(cursorlinenrfg, extends!(linenrfg, 0.0, 0.1, 0.3));
-
-    // pmenu
-    def!(pmenubg, extends!(mainbg, 0.0, 0.0, 0.1));
-    def!(pmenufg, extends!(mainfg));
-    def!(pmenuselbg, extends!(visualbg));
-    def!(pmenuselfg, extends!(mainfg));
-    def!(pmenumatchfg, extends!(purple));
-    def!(pmenubar, extends!(pmenubg, 0.0, 0.0, -0.05));
-    def!(pmenuthumb, extends!(pmenubg, 0.0, 0.1, 0.2));
-
-    // fold
-    def!(foldbg, extends!(mainbg, 0.0, 0.0, 0.1));
-    def!(foldfg, extends!(mainbg, 0.0, 0.0, 0.4));
-
-    // diff
-    def!(diffaddbg, extends!(darkestblue));
-    def!(diffchangebg, extends!(darkestteal, 0.0, 0.0, -0.05));
-    def!(diffdeletebg, extends!(darkpink, 0.0, 0.0, -0.05));
-    def!(diffdeletefg, extends!(diffdeletebg, 0.0, -0.15, 0.15));
-    def!(difftextbg, extends!(diffchangebg, 0.0, 0.1, 0.07));
-
-    // status & tab line
-    def!(statuslinebg, extends!(mainbg, 0.0, 0.0, 0.05));
-    def!(statuslinefg, extends!(statuslinebg, 0.0, -0.05, 0.4));
-    def!(statuslinencbg, extends!(mainbg, 0.0, -0.03, 0.03));
-    def!(statuslinencfg, extends!(statuslinencbg, 0.0, 0.0, 0.2));
-    def!(tablineselbg, extends!(purple));
-    def!(tablineselfg, extends!(mainbg));
-
-    // misc
-    def!(searchbg, extends!(purple, 0.0, 0.2, 0.0));
-    def!(searchfg, extends!(searchbg, 0.2, -0.2, 0.15));
-    def!(incsearchbg, extends!(searchbg, 0.0, 0.08, -0.1));
-    def!(incsearchfg, extends!(searchfg, 0.1, 0.1, 0.15));
-    def!(matchparenbg, extends!(mainbg, 0.1, 0.0, 0.08));
-
-    // terminal colors
-    def!(termblack, extends!(mainbg, 0.0, 0.0, -0.1));
-    def!(termmaroon, extends!(red, 0.0, 0.0, -0.1));
-    def!(termgreen, extends!(green, 0.0, 0.0, 0.0));
-    def!(termolive, extends!(yellow, 0.0, 0.0, -0.1));
-    def!(termnavy, extends!(blue, 0.0, 0.0, -0.1));
-    def!(termpurple, extends!(purple, 0.0, 0.1, -0.1));
-    def!(termteal, extends!(teal));
-    def!(termsilver, extends!(mainfg));
-    def!(termgray, extends!(weakfg));
-    def!(termred, extends!(pink));
-    def!(termlime, extends!(green));
-    def!(termyellow, extends!(yellow));
-    def!(termblue, extends!(blue));
-    def!(termfuchsia, extends!(purple));
-    def!(termaqua, extends!(cyan));
-    def!(termwhite, extends!(mainfg));
-
-    // defx-icons
-    def!(defxiconbrown, extends!(red, 0.0, 0.2, -0.2));
-    def!(defxiconaqua, extends!(cyan, 0.0, -0.1, -0.1));
-    def!(defxiconblue, extends!(blue, 0.0, -0.1, -0.1));
-    def!(defxicondarkblue, extends!(blue, 0.0, -0.2, -0.25));
-    def!(defxiconpurple, extends!(darkpurple));
-    def!(defxiconlightpurple, extends!(purple, 0.0, -0.1, -0.1));
-    def!(defxiconred, extends!(red, 0.0, -0.0, -0.1));
-    def!(defxiconbeige, extends!(yellow, 0.0, -0.2, -0.25));
-    def!(defxiconyellow, extends!(yellow, 0.0, 0.0, -0.1));
-    def!(defxiconorange, extends!(orange, 0.0, 0.0, 0.1));
-    def!(defxicondarkorange, extends!(orange, 0.0, 0.1, -0.2));
-    def!(defxiconpink, extends!(pink, 0.0, 0.0, -0.1));
-    def!(defxiconsalmon, extends!(pink, 0.0, 0.1, -0.05));
-    def!(defxicongreen, extends!(green, 0.0, 0.0, -0.15));
-    def!(defxiconlightgreen, extends!(green, 0.0, 0.1, -0.1));
-    def!(defxiconwhite, extends!(mainfg, 0.0, 0.0, -0.1));
-
-    // lightline
-    def!(xlinebg, extends!(statuslinencbg));
-    def!(xlinefg, extends!(statuslinencfg));
-    def!(xlineedgebg, extends!(statuslinebg));
-    def!(xlineedgefg, extends!(statuslinefg));
-    def!(xlinegradientbg, extends!(statuslinencbg));
-    def!(xlinegradientfg, extends!(statuslinencfg));
-
-    p
+    Ok(attr)
 }
 
-pub fn get_highlights() -> Vec<Highlight> {
-    vec![
-        // general
-        hi!("Normal", mainfg, mainbg, -, -, -),
-        hi!("Delimiter", lightfg, -, -, -, -),
-        hi!("NonText", darkfg, NONE, -, -, -),
-        hi!("VertSplit", weakbg, NONE, -, None, -),
-        hi!("LineNr", linenrfg, NONE, -, None, -),
-        hi!("EndOfBuffer", darkfg, NONE, -, None, -),
-        hi!("Comment", weakfg, -, -, None, -),
-        hi!("Cursor", mainbg, mainfg, -, -, -),
-        hi!("CursorIM", mainbg, mainfg, -, -, -),
-        hi!("SignColumn", weakfg, NONE, -, -, -),
-        hi!("ColorColumn", -, cursorlinebg, -, None, -),
-        hi!("CursorColumn", -, cursorlinebg, -, None, -),
-        hi!("CursorLine", -, cursorlinebg, -, None, -),
-        hi!("CursorLineNr", cursorlinenrfg, NONE, -, None, -),
-        hi!("Conceal", orange, mainbg, -, None, -),
-        hi!("NormalFloat", lightfg, NONE, -, None, -),
-        hi!("FloatBorder", weakfg, NONE, -, None, -),
-        hi!("WinSeparator", darkfg, NONE, -, None, -),
-        hi!("Folded", foldfg, foldbg, -, None, -),
-        hi!("FoldColumn", linenrfg, NONE, -, None, -),
-        hi!("MatchParen", -, matchparenbg, -, -, -),
-        hi!("Directory", purple, -, -, -, -),
-        hi!("Underlined", -, -, -, Underline, -),
-        hi!("String", green, -, -, -, -),
-        hi!("Statement", purple, -, -, None, -),
-        hi!("Label", purple, -, -, None, -),
-        hi!("Function", purple, -, -, None, -),
-        hi!("Constant", teal, -, -, -, -),
-        hi!("Boolean", teal, -, -, -, -),
-        hi!("Number", teal, -, -, -, -),
-        hi!("Float", teal, -, -, -, -),
-        hi!("Title", yellow, -, -, Bold, -),
-        hi!("Keyword", orange, -, -, -, -),
-        hi!("Identifier", orange, -, -, -, -),
-        hi!("Exception", yellow, -, -, -, -),
-        hi!("Type", yellow, -, -, None, -),
-        hi!("TypeDef", yellow, -, -, None, -),
-        hi!("PreProc", purple, -, -, -, -),
-        hi!("Special", pink, -, -, -, -),
-        hi!("SpecialKey", pink, -, -, -, -),
-        hi!("SpecialChar", pink, -, -, -, -),
-        hi!("SpecialComment", pink, -, -, -, -),
-        hi!("Error", errorfg, errorbg, -, Bold, -),
-        hi!("ErrorMsg", errorfg, NONE, -, Bold, -),
-        hi!("WarningMsg", warningfg, -, -, Bold, -),
-        hi!("MoreMsg", morefg, -, -, -, -),
-        hi!("ModeMsg", morefg, -, -, -, -),
-        hi!("Debug", mainfg, -, -, -, -),
-        hi!("Todo", yellow, NONE, -, Bold, -),
-        hi!("Pmenu", pmenufg, pmenubg, -, -, -),
-        hi!("PmenuSel", pmenuselfg, pmenuselbg, -, None, -),
-        hi!("PmenuMatch", pmenumatchfg, -, -, Bold, -),
-        hi!("PmenuSbar", -, pmenubar, -, -, -),
-        hi!("PmenuThumb", -, pmenuthumb, -, -, -),
-        hi!("Visual", -, visualbg, -, None, -),
-        hi!("Search", searchfg, searchbg, -, -, -),
-        hi!("CurSearch", searchfg, searchbg, -, -, -),
-        hi!("IncSearch", incsearchfg, incsearchbg, -, None, -),
-        hi!("Question", teal, -, -, Bold, -),
-        hi!("WildMenu", mainbg, purple, -, -, -),
-        hi!("SpellBad", errorfg, -, -, Underline, -),
-        hi!("SpellCap", -, -, -, Underline, -),
-        hi!("SpellLocal", errorfg, -, -, Underline, -),
-        hi!("SpellRare", yellow, -, -, Underline, -),
-        hi!("Added", NONE, diffaddbg, -, None, -),
-        hi!("Removed", diffdeletefg, diffdeletebg, -, None, -),
-        hi!("Changed", NONE, diffchangebg, -, None, -),
-        hi!("DiffAdd", NONE, diffaddbg, -, -, -),
-        hi!("DiffChange", NONE, diffchangebg, -, -, -),
-        hi!("DiffDelete", diffdeletefg, diffdeletebg, -, -, -),
-        hi!("DiffText", NONE, difftextbg, -, -, -),
-        hi!("QuickFixLine", mainfg, visualbg, -, -, -),
-        hi!("StatusLine", statuslinefg, statuslinebg, -, Bold, -),
-        hi!("StatusLineTerm", statuslinefg, statuslinebg, -, Bold, -),
-        hi!("StatusLineNC", statuslinencfg, statuslinencbg, -, None, -),
-        hi!("StatusLineTermNC", statuslinencfg, statuslinencbg, -, None, -),
-        hi!("TabLine", statuslinefg, statuslinebg, -, None, -),
-        hi!("TabLineFill", statuslinefg, statuslinebg, -, None, -),
-        hi!("TabLineSel", tablineselfg, tablineselbg, -, Bold, -),
-        hi!("qfFileName", teal, -, -, -, -),
-        hi!("qfLineNr", weakfg, -, -, -, -),
-        // treesitter
-        // https://github.com/nvim-treesitter/nvim-treesitter
-        hi!("@string", green, -, -, -, Nvim080OrLater),
-        hi!("@string.regex", green, -, -, -, Nvim080OrLater),
-        hi!("@string.escape", pink, -, -, -, Nvim080OrLater),
-        hi!("@string.special.url", weakfg, -, -, -, Nvim080OrLater),
-        hi!("@text.title", yellow, -, -, Bold, Nvim080OrLater),
-        hi!("@text.reference", purple, -, -, -, Nvim080OrLater),
-        hi!("@text.uri", weakfg, -, -, -, Nvim080OrLater),
-        hi!("@text.strong", -, -, -, Bold, Nvim080OrLater),
-        hi!("@text.literal", teal, -, -, -, Nvim080OrLater),
-        hi!("@parameter", purple, -, -, -, Nvim080OrLater),
-        hi!("@property", purple, -, -, -, Nvim080OrLater),
-        hi!("@keyword", pink, -, -, -, Nvim080OrLater),
-        hi!("@operator", purple, -, -, -, Nvim080OrLater),
-        hi!("@module", mainfg, -, -, -, Nvim080OrLater),
-        hi!("@type", orange, -, -, -, Nvim080OrLater),
-        hi!("@type.builtin", orange, -, -, -, Nvim080OrLater),
-        hi!("@function.tsx", mainfg, -, -, -, Nvim080OrLater),
-        hi!("@punctuation.special.typescript", lightfg, -, -, -, Nvim080OrLater),
-        hi!("@include", purple, -, -, -, Nvim080OrLater),
-        hi!("@variable", mainfg, -, -, -, Nvim080OrLater),
-        hi!("@variable.builtin", orange, -, -, -, Nvim080OrLater),
-        hi!("@constant.builtin", teal, -, -, -, Nvim080OrLater),
-        hi!("@constructor", mainfg, -, -, -, Nvim080OrLater),
-        hi!("@tag", mainfg, -, -, -, Nvim080OrLater),
-        hi!("@tag.delimiter", purple, -, -, -, Nvim080OrLater),
-        hi!("@tag.attribute", purple, -, -, -, Nvim080OrLater),
-        hi!("@tag.builtin.tsx", mainfg, -, -, -, Nvim080OrLater),
-        hi!("@markup.heading", yellow, -, -, Bold, Nvim080OrLater),
-        hi!("@markup.strong", -, -, -, Bold, Nvim080OrLater),
-        hi!("@markup.list", weakfg, -, -, -, Nvim080OrLater),
-        hi!("@markup.raw", teal, -, -, -, Nvim080OrLater),
-        hi!("@markup.link", purple, -, -, -, Nvim080OrLater),
-        hi!("@markup.link.url", lightfg, -, -, -, Nvim080OrLater),
-        hi!("@markup.quote", weakfg, -, -, -, Nvim080OrLater),
-        // LSP Semantic highlights
-        hi!("@lsp.type.class", mainfg, -, -, -, Nvim080OrLater),
-        hi!("@lsp.type.interface", orange, -, -, -, Nvim080OrLater),
-        hi!("@lsp.type.parameter", purple, -, -, -, Nvim080OrLater),
-        hi!("@lsp.type.property", purple, -, -, -, Nvim080OrLater),
-        hi!("@lsp.type.struct", mainfg, -, -, -, Nvim080OrLater),
-        hi!("@lsp.type.type", orange, -, -, -, Nvim080OrLater),
-        hi!("@lsp.type.typeParameter", mainfg, -, -, -, Nvim080OrLater),
-        hi!("@lsp.type.variable", mainfg, -, -, -, Nvim080OrLater),
-        hi!("@lsp.type.member", purple, -, -, -, Nvim080OrLater),
-        hi!("@lsp.type.namespace", mainfg, -, -, -, Nvim080OrLater),
-        // built-in LSP
-        hi!("DiagnosticError", errorfg, -, -, -, -),
-        hi!("DiagnosticVirtualTextError", errorfg, -, -, Bold, -),
-        hi!("DiagnosticUnderlineError", errorfg, -, -, Underline, -),
-        hi!("DiagnosticWarn", warningfg, -, -, -, -),
-        hi!("DiagnosticVirtualTextWarn", warningfg, -, -, Bold, -),
-        hi!("DiagnosticUnderlineWarn", warningfg, -, -, Underline, -),
-        hi!("DiagnosticInfo", infofg, -, -, -, -),
-        hi!("DiagnosticVirtualTextInfo", weakfg, -, -, Bold, -),
-        hi!("DiagnosticUnderlineInfo", -, -, -, Underline, -),
-        hi!("DiagnosticHint", infofg, -, -, -, -),
-        hi!("DiagnosticOk", infofg, -, -, -, -),
-        hi!("DiagnosticVirtualTextHint", weakfg, -, -, Bold, -),
-        hi!("DiagnosticUnderlineHint", -, -, -, Underline, -),
-        hi!("LspSignatureActiveParameter", -, -, -, Italic, -),
-        hi!("LspReferenceText", -, matchparenbg, -, -, -),
-        hi!("LspReferenceRead", -, matchparenbg, -, -, -),
-        hi!("LspReferenceWrite", -, matchparenbg, -, -, -),
-        // html
-        hi!("htmlTag", lightfg, -, -, -, -),
-        hi!("htmlEndTag", lightfg, -, -, -, -),
-        hi!("htmlSpecialTagName", orange, -, -, -, -),
-        hi!("htmlArg", lightfg, -, -, -, -),
-        // json
-        hi!("jsonQuote", lightfg, -, -, -, -),
-        // yaml
-        hi!("yamlBlockMappingKey", purple, -, -, -, -),
-        hi!("yamlAnchor", pink, -, -, -, -),
-        // python
-        hi!("pythonStatement", orange, -, -, -, -),
-        hi!("pythonBuiltin", cyan, -, -, -, -),
-        hi!("pythonRepeat", orange, -, -, -, -),
-        hi!("pythonOperator", orange, -, -, -, -),
-        hi!("pythonDecorator", pink, -, -, -, -),
-        hi!("pythonDecoratorName", pink, -, -, -, -),
-        // zsh
-        hi!("zshVariableDef", purple, -, -, -, -),
-        hi!("zshFunction", purple, -, -, -, -),
-        hi!("zshKSHFunction", purple, -, -, -, -),
-        // C
-        hi!("cPreCondit", orange, -, -, -, -),
-        hi!("cIncluded", pink, -, -, -, -),
-        hi!("cStorageClass", orange, -, -, -, -),
-        // C++
-        // octol/vim-cpp-enhanced-highlight
-        hi!("cppStructure", pink, -, -, -, -),
-        hi!("cppSTLnamespace", orange, -, -, -, -),
-        // C#
-        hi!("csStorage", orange, -, -, -, -),
-        hi!("csModifier", purple, -, -, -, -),
-        hi!("csClass", purple, -, -, -, -),
-        hi!("csClassType", pink, -, -, -, -),
-        hi!("csNewType", orange, -, -, -, -),
-        // ruby
-        hi!("rubyConstant", orange, -, -, -, -),
-        hi!("rubySymbol", purple, -, -, -, -),
-        hi!("rubyBlockParameter", purple, -, -, -, -),
-        hi!("rubyClassName", pink, -, -, -, -),
-        hi!("rubyInstanceVariable", pink, -, -, -, -),
-        // yats.vim
-        // https://github.com/HerringtonDarkholme/yats.vim
-        hi!("typescriptImport", purple, -, -, -, -),
-        hi!("typescriptDocRef", weakfg, -, -, Underline, -),
-        // vim-markdown
-        // https://github.com/plasticboy/vim-markdown
-        hi!("mkdHeading", weakfg, -, -, -, -),
-        hi!("mkdLink", purple, -, -, -, -),
-        hi!("mkdCode", purple, -, -, -, -),
-        hi!("mkdCodeStart", purple, -, -, -, -),
-        hi!("mkdCodeEnd", purple, -, -, -, -),
-        hi!("mkdCodeDelimiter", purple, -, -, -, -),
-        // vim-toml
-        // https://github.com/cespare/vim-toml
-        hi!("tomlTable", purple, -, -, -, -),
-        // rust.vim
-        // https://github.com/rust-lang/rust.vim
-        hi!("rustModPath", purple, -, -, -, -),
-        hi!("rustTypedef", purple, -, -, -, -),
-        hi!("rustStructure", purple, -, -, -, -),
-        hi!("rustMacro", purple, -, -, -, -),
-        hi!("rustExternCrate", purple, -, -, -, -),
-        // vim-graphql
-        // https://github.com/jparise/vim-graphql
-        hi!("graphqlStructure", pink, -, -, -, -),
-        hi!("graphqlDirective", pink, -, -, -, -),
-        hi!("graphqlName", purple, -, -, -, -),
-        hi!("graphqlTemplateString", mainfg, -, -, -, -),
-        // vimfiler
-        // https://github.com/Shougo/vimfiler.vim
-        hi!("vimfilerOpenedFile", darkpurple, -, -, -, -),
-        hi!("vimfilerClosedFile", darkpurple, -, -, -, -),
-        hi!("vimfilerNonMark", teal, -, -, -, -),
-        hi!("vimfilerLeaf", teal, -, -, -, -),
-        // defx-icons
-        // https://github.com/kristijanhusak/defx-icons
-        hi!("DefxIconsMarkIcon", darkpurple, -, -, None, -),
-        hi!("DefxIconsDirectory", darkpurple, -, -, None, -),
-        hi!("DefxIconsParentDirectory", darkpurple, -, -, None, -),
-        hi!("DefxIconsSymlinkDirectory", teal, -, -, None, -),
-        hi!("DefxIconsOpenedTreeIcon", darkpurple, -, -, None, -),
-        hi!("DefxIconsNestedTreeIcon", darkpurple, -, -, None, -),
-        hi!("DefxIconsClosedTreeIcon", darkpurple, -, -, None, -),
-        // defx-git
-        // https://github.com/kristijanhusak/defx-git
-        hi!("Defx_git_Untracked", purple, -, -, None, -),
-        hi!("Defx_git_Ignored", weakfg, -, -, None, -),
-        hi!("Defx_git_Unknown", weakfg, -, -, None, -),
-        hi!("Defx_git_Renamed", diffchangebg, -, -, -, -),
-        hi!("Defx_git_Modified", diffchangebg, -, -, -, -),
-        hi!("Defx_git_Unmerged", pink, -, -, -, -),
-        hi!("Defx_git_Deleted", diffdeletebg, -, -, -, -),
-        hi!("Defx_git_Staged", teal, -, -, -, -),
-        // nvim-tree/nvim-tree.lua
-        // https://github.com/nvim-tree/nvim-tree.lua
-        hi!("NvimTreeSymlink", darkteal, -, -, None, -),
-        hi!("NvimTreeSymlinkFolderName", darkteal, -, -, None, -),
-        hi!("NvimTreeFolderName", purple, -, -, None, -),
-        hi!("NvimTreeRootFolder", darkestpurple, -, -, Bold, -),
-        hi!("NvimTreeFolderIcon", darkpurple, -, -, None, -),
-        hi!("NvimTreeFileIcon", darkpurple, -, -, None, -),
-        hi!("NvimTreeEmptyFolderName", weakfg, -, -, None, -),
-        hi!("NvimTreeOpenedFolderName", purple, -, -, None, -),
-        hi!("NvimTreeExecFile", lightfg, -, -, None, -),
-        hi!("NvimTreeOpenedHL", lightfg, -, -, None, -),
-        hi!("NvimTreeSpecialFile", lightfg, -, -, Bold, -),
-        hi!("NvimTreeImageFile", lightfg, -, -, None, -),
-        hi!("NvimTreeIndentMarker", darkestpurple, -, -, None, -),
-        hi!("NvimTreeModifiedIcon", mainfg, -, -, None, -),
-        hi!("NvimTreeGitDirtyIcon", yellow, -, -, None, -),
-        hi!("NvimTreeGitStagedIcon", green, -, -, None, -),
-        hi!("NvimTreeGitMergeIcon", yellow, -, -, None, -),
-        hi!("NvimTreeGitRenamedIcon", yellow, -, -, None, -),
-        hi!("NvimTreeGitNewIcon", teal, -, -, None, -),
-        hi!("NvimTreeGitDeletedIcon", difftextbg, -, -, None, -),
-        hi!("NvimTreeWindowPicker", tablineselfg, tablineselbg, -, Bold, -),
-        hi!("NvimTreeNormal", lightfg, -, -, None, -),
-        hi!("NvimTreeLiveFilterPrefix", darkteal, -, -, None, -),
-        hi!("NvimTreeLiveFilterValue", teal, -, -, None, -),
-        hi!("NvimTreeBookmarkIcon", yellow, -, -, None, -),
-        // Fyler.nvim
-        // https://github.com/A7Lavinraj/fyler.nvim
-        hi!("FylerBlue", blue, -, -, None, -),
-        hi!("FylerGreen", teal, -, -, None, -),
-        hi!("FylerGrey", mainfg, -, -, None, -),
-        hi!("FylerRed", red, -, -, None, -),
-        hi!("FylerYellow", yellow, -, -, None, -),
-        hi!("FylerNormal", lightfg, -, -, None, -),
-        hi!("FylerNormalNC", lightfg, -, -, None, -),
-        hi!("FylerBorder", weakfg, -, -, None, -),
-        hi!("FylerFSDirectoryIcon", darkpurple, -, -, Bold, -),
-        hi!("FylerFSDirectoryName", purple, -, -, None, -),
-        hi!("FylerFSFile", lightfg, -, -, None, -),
-        hi!("FylerFSLink", darkteal, -, -, None, -),
-        hi!("FylerGitAdded", mainfg, -, -, None, -),
-        hi!("FylerGitConflict", -, -, -, None, -),
-        hi!("FylerGitDeleted", difftextbg, -, -, None, -),
-        hi!("FylerGitIgnored", -, -, -, None, -),
-        hi!("FylerGitModified", -, -, -, None, -),
-        hi!("FylerGitRenamed", yellow, -, -, None, -),
-        hi!("FylerGitStaged", green, -, -, None, -),
-        hi!("FylerGitUnstaged", -, -, -, None, -),
-        hi!("FylerGitUntracked", -, -, -, None, -),
-        hi!("FylerIndentMarker", weakfg, -, -, None, -),
-        hi!("FylerWinPick", -, -, -, None, -),
-        // eda.nvim
-        // https://github.com/wadackel/eda.nvim
-        // General
-        hi!("EdaNormal", lightfg, -, -, None, -),
-        hi!("EdaNormalNC", lightfg, -, -, None, -),
-        hi!("EdaBorder", weakfg, -, -, None, -),
-        hi!("EdaTitle", purple, -, -, Bold, -),
-        hi!("EdaCursorLine", -, cursorlinebg, -, None, -),
-        hi!("EdaIndentMarker", darkestpurple, -, -, None, -),
-        // Filesystem
-        hi!("EdaRootName", darkestpurple, -, -, Bold, -),
-        hi!("EdaDivider", weakfg, -, -, None, -),
-        hi!("EdaFilterIndicator", darkteal, -, -, None, -),
-        hi!("EdaDirectoryName", purple, -, -, None, -),
-        hi!("EdaDirectoryIcon", darkpurple, -, -, None, -),
-        hi!("EdaOpenedDirectoryName", purple, -, -, None, -),
-        hi!("EdaEmptyDirectoryName", weakfg, -, -, None, -),
-        hi!("EdaFileName", lightfg, -, -, None, -),
-        hi!("EdaFileIcon", darkpurple, -, -, None, -),
-        hi!("EdaSymlink", darkteal, -, -, None, -),
-        hi!("EdaBrokenSymlink", red, -, -, None, -),
-        hi!("EdaSymlinkTarget", weakfg, -, -, None, -),
-        hi!("EdaErrorNode", red, -, -, None, -),
-        hi!("EdaLoadingNode", weakfg, -, -, Italic, -),
-        hi!("EdaOpenedFile", lightfg, -, -, None, -),
-        hi!("EdaModifiedFile", yellow, -, -, None, -),
-        // Git status (base + Icon only)
-        hi!("EdaGitUntracked", teal, -, -, None, -),
-        hi!("EdaGitUntrackedIcon", teal, -, -, None, -),
-        hi!("EdaGitAdded", teal, -, -, None, -),
-        hi!("EdaGitAddedIcon", teal, -, -, None, -),
-        hi!("EdaGitModified", lightyellow, -, -, None, -),
-        hi!("EdaGitModifiedIcon", lightyellow, -, -, None, -),
-        hi!("EdaGitDeleted", lightpink, -, -, None, -),
-        hi!("EdaGitDeletedIcon", lightpink, -, -, None, -),
-        hi!("EdaGitRenamed", lightyellow, -, -, None, -),
-        hi!("EdaGitRenamedIcon", lightyellow, -, -, None, -),
-        hi!("EdaGitStaged", green, -, -, None, -),
-        hi!("EdaGitStagedIcon", green, -, -, None, -),
-        hi!("EdaGitConflict", lightorange, -, -, None, -),
-        hi!("EdaGitConflictIcon", lightorange, -, -, None, -),
-        hi!("EdaGitIgnored", weakfg, -, -, None, -),
-        hi!("EdaGitIgnoredIcon", weakfg, -, -, None, -),
-        // Operations
-        hi!("EdaMarked", yellow, -, -, Bold, -),
-        hi!("EdaCut", weakfg, -, -, Italic, -),
-        hi!("EdaOpDeleteSign", red, -, -, Bold, -),
-        hi!("EdaOpDeletePath", red, -, -, None, -),
-        hi!("EdaOpDeleteText", weakfg, -, -, None, -),
-        hi!("EdaOpCreateSign", teal, -, -, Bold, -),
-        hi!("EdaOpCreatePath", teal, -, -, None, -),
-        hi!("EdaOpCreateText", weakfg, -, -, None, -),
-        hi!("EdaOpMoveSign", yellow, -, -, Bold, -),
-        hi!("EdaOpMovePath", yellow, -, -, None, -),
-        hi!("EdaOpMoveText", weakfg, -, -, None, -),
-        // fern.vim
-        // https://github.com/lambdalisue/fern.vim
-        hi!("FernBranchSymbol", darkpurple, -, -, None, -),
-        hi!("FernBranchText", purple, -, -, None, -),
-        hi!("FernLeafSymbol", darkteal, -, -, None, -),
-        hi!("FernLeafText", mainfg, -, -, None, -),
-        hi!("FernMarked", cyan, -, -, None, -),
-        // gitsigns.nvim
-        // https://github.com/lewis6991/gitsigns.nvim
-        hi!("GitSignsAdd", green, -, -, -, -),
-        hi!("GitSignsChange", yellow, -, -, -, -),
-        hi!("GitSignsDelete", pink, -, -, -, -),
-        hi!("GitSignsChangeDelete", difftextbg, -, -, -, -),
-        // vim-gitgutter
-        // https://github.com/airblade/vim-gitgutter
-        hi!("GitGutterAdd", green, -, -, -, -),
-        hi!("GitGutterChange", yellow, -, -, -, -),
-        hi!("GitGutterDelete", pink, -, -, -, -),
-        hi!("GitGutterChangeDelete", difftextbg, -, -, -, -),
-        // fugitive.vim
-        // https://github.com/tpope/vim-fugitive
-        hi!("fugitiveHeader", teal, -, -, Bold, -),
-        // Diffview.nvim
-        // https://github.com/sindrets/diffview.nvim
-        hi!("DiffviewDim1", weakfg, -, -, -, -),
-        hi!("DiffviewPrimary", purple, -, -, -, -),
-        hi!("DiffviewSecondary", pink, -, -, -, -),
-        hi!("DiffviewStatusAdded", blue, -, -, -, -),
-        hi!("DiffviewStatusUntracked", yellow, -, -, -, -),
-        hi!("DiffviewStatusModified", teal, -, -, -, -),
-        hi!("DiffviewStatusRenamed", teal, -, -, -, -),
-        hi!("DiffviewStatusCopied", teal, -, -, -, -),
-        hi!("DiffviewStatusTypeChanged", teal, -, -, -, -),
-        hi!("DiffviewStatusUnmerged", pink, -, -, -, -),
-        hi!("DiffviewStatusUnknown", yellow, -, -, -, -),
-        hi!("DiffviewStatusDeleted", lightfg, -, -, -, -),
-        hi!("DiffviewStatusBroken", pink, -, -, -, -),
-        hi!("DiffviewStatusIgnored", yellow, -, -, -, -),
-        hi!("DiffviewFilePanelRootPath", darkpurple, -, -, -, -),
-        hi!("DiffviewFilePanelTitle", purple, -, -, Bold, -),
-        hi!("DiffviewFilePanelCounter", lightfg, -, -, Bold, -),
-        hi!("DiffviewFilePanelFileName", mainfg, -, -, -, -),
-        hi!("DiffviewFilePanelPath", weakfg, -, -, Bold, -),
-        hi!("DiffviewFilePanelSelected", yellow, -, -, -, -),
-        hi!("DiffviewFilePanelInsertions", teal, -, -, -, -),
-        hi!("DiffviewFilePanelDeletions", pink, -, -, -, -),
-        hi!("DiffviewFilePanelConflicts", warningfg, -, -, -, -),
-        hi!("DiffviewHash", darkpurple, -, -, -, -),
-        // ale
-        // https://github.com/dense-analysis/ale
-        hi!("ALEWarningSign", warningfg, -, -, Bold, -),
-        hi!("ALEInfoSign", infofg, -, -, None, -),
-        // null-ls.nvim
-        // https://github.com/jose-elias-alvarez/null-ls.nvim
-        hi!("NullLsInfoBorder", weakfg, mainbg, -, None, -),
-        // coc.nvim
-        // https://github.com/neoclide/coc.nvim
-        hi!("CocErrorSign", errorfg, -, -, Bold, -),
-        hi!("CocWarningSign", warningfg, -, -, Bold, -),
-        hi!("CocInfoSign", infofg, -, -, Bold, -),
-        hi!("CocHintSign", infofg, -, -, Bold, -),
-        // vim-lsp
-        // https://github.com/prabirshrestha/vim-lsp
-        hi!("LspError", errorfg, -, -, -, -),
-        hi!("LspErrorText", errorfg, -, -, Bold, -),
-        hi!("LspErrorHighlight", -, -, -, Underline, -),
-        hi!("LspErrorVirtualText", errorfg, -, -, Bold, -),
-        hi!("LspWarning", warningfg, -, -, -, -),
-        hi!("LspWarningText", warningfg, -, -, Bold, -),
-        hi!("LspWarningHighlight", -, -, -, Underline, -),
-        hi!("LspWarningVirtualText", warningfg, -, -, Bold, -),
-        hi!("LspInformation", infofg, -, -, -, -),
-        hi!("LspInformationText", infofg, -, -, Bold, -),
-        hi!("LspInformationHighlight", -, -, -, Underline, -),
-        hi!("LspInformationVirtualText", weakfg, -, -, Bold, -),
-        hi!("LspHint", infofg, -, -, -, -),
-        hi!("LspHintText", infofg, -, -, Bold, -),
-        hi!("LspHintHighlight", -, -, -, Underline, -),
-        hi!("LspHintVirtualText", weakfg, -, -, Bold, -),
-        hi!("LspCodeActionText", darkpurple, -, -, Bold, -),
-        // nvim-cmp
-        // https://github.com/hrsh7th/nvim-cmp
-        hi!("CmpItemAbbr", mainfg, -, -, -, -),
-        hi!("CmpItemAbbrMatch", purple, -, -, Bold, -),
-        hi!("CmpItemAbbrMatchFuzzy", purple, -, -, Bold, -),
-        hi!("CmpItemAbbrDeprecated", weakfg, -, -, Strikethrough, -),
-        hi!("CmpItemMenu", weakfg, -, -, Italic, -),
-        hi!("CmpItemKind", lightfg, -, -, -, -),
-        hi!("CmpItemKindText", lightfg, -, -, -, -),
-        hi!("CmpItemKindVariable", teal, -, -, -, -),
-        hi!("CmpItemKindConstant", teal, -, -, -, -),
-        hi!("CmpItemKindEnum", teal, -, -, -, -),
-        hi!("CmpItemKindInterface", teal, -, -, -, -),
-        hi!("CmpItemKindClass", teal, -, -, -, -),
-        hi!("CmpItemKindFunction", orange, -, -, -, -),
-        hi!("CmpItemKindMethod", orange, -, -, -, -),
-        hi!("CmpItemKindModule", orange, -, -, -, -),
-        hi!("CmpItemKindConstructor", orange, -, -, -, -),
-        hi!("CmpItemKindKeyword", yellow, -, -, -, -),
-        hi!("CmpItemKindProperty", yellow, -, -, -, -),
-        hi!("CmpItemKindField", yellow, -, -, -, -),
-        hi!("CmpItemKindUnit", yellow, -, -, -, -),
-        // Blink Completion
-        // https://github.com/saghen/blink.cmp
-        hi!("BlinkCmpMenu", mainfg, -, -, -, -),
-        hi!("BlinkCmpMenuSelection", -, visualbg, -, -, -),
-        hi!("BlinkCmpLabelMatch", purple, -, -, Bold, -),
-        hi!("BlinkCmpLabelDeprecated", weakfg, -, -, Strikethrough, -),
-        hi!("BlinkCmpKind", lightfg, -, -, -, -),
-        hi!("BlinkCmpKindText", lightfg, -, -, -, -),
-        hi!("BlinkCmpKindVariable", teal, -, -, -, -),
-        hi!("BlinkCmpKindConstant", teal, -, -, -, -),
-        hi!("BlinkCmpKindEnum", teal, -, -, -, -),
-        hi!("BlinkCmpKindInterface", teal, -, -, -, -),
-        hi!("BlinkCmpKindClass", teal, -, -, -, -),
-        hi!("BlinkCmpKindFunction", orange, -, -, -, -),
-        hi!("BlinkCmpKindMethod", orange, -, -, -, -),
-        hi!("BlinkCmpKindModule", orange, -, -, -, -),
-        hi!("BlinkCmpKindConstructor", orange, -, -, -, -),
-        hi!("BlinkCmpKindKeyword", yellow, -, -, -, -),
-        hi!("BlinkCmpKindProperty", yellow, -, -, -, -),
-        hi!("BlinkCmpKindField", yellow, -, -, -, -),
-        hi!("BlinkCmpKindUnit", yellow, -, -, -, -),
-        hi!("BlinkCmpSource", weakfg, -, -, Italic, -),
-        hi!("BlinkCmpDocSeparator", weakfg, -, -, -, -),
-        hi!("BlinkCmpMenuBorder", weakfg, NONE, -, None, -),
-        hi!("BlinkCmpDocBorder", weakfg, NONE, -, None, -),
-        hi!("BlinkCmpSignatureHelpBorder", weakfg, NONE, -, None, -),
-        // dressing.nvim
-        // https://github.com/stevearc/dressing.nvim
-        hi!("FloatTitle", purple, mainbg, -, None, -),
-        // telescope.nvim
-        // https://github.com/nvim-telescope/telescope.nvim
-        hi!("TelescopeNormal", lightfg, -, -, -, -),
-        hi!("TelescopeTitle", purple, -, -, -, -),
-        hi!("TelescopeMatching", emphasisfg, -, -, Bold, -),
-        hi!("TelescopeBorder", weakfg, -, -, -, -),
-        hi!("TelescopePromptPrefix", teal, -, -, -, -),
-        hi!("TelescopePromptCounter", weakfg, -, -, -, -),
-        hi!("TelescopeMultiIcon", yellow, -, -, -, -),
-        hi!("TelescopeMultiSelection", yellow, -, -, -, -),
-        // snacks.nvim
-        // https://github.com/folke/snacks.nvim
-        hi!("SnacksNormal", lightfg, -, -, -, -),
-        hi!("SnacksPickerPrompt", teal, -, -, -, -),
-        hi!("SnacksPickerMatch", purple, -, -, -, -),
-        hi!("SnacksPickerDir", weakfg, -, -, -, -),
-        // Copilot.vim
-        // https://github.com/github/copilot.vim
-        hi!("CopilotSuggestion", weakfg, -, -, -, -),
-        // clever-f.vim
-        // https://github.com/rhysd/clever-f.vim
-        hi!("CleverFChar", searchfg, searchbg, -, Underline, -),
-        // mini.nvim
-        hi!("MiniJump", searchfg, searchbg, -, Underline, -),
-        // conflict-marker.vim
-        // https://github.com/rhysd/conflict-marker.vim
-        hi!("ConflictMarkerBegin", -, darkteal, -, Bold, -),
-        hi!("ConflictMarkerOurs", -, darkestteal, -, None, -),
-        hi!("ConflictMarkerTheirs", -, darkestblue, -, None, -),
-        hi!("ConflictMarkerEnd", -, darkblue, -, Bold, -),
-        hi!("ConflictMarkerSeparator", darkfg, -, -, Bold, -),
-        // easymotion
-        // https://github.com/easymotion/vim-easymotion
-        hi!("EasyMotionTarget", yellow, -, -, Bold, -),
-        hi!("EasyMotionShade", weakfg, mainbg, -, -, -),
-        hi!("EasyMotionIncCursor", mainfg, mainbg, -, -, -),
-        // smoka7/hop.nvim
-        // https://github.com/smoka7/hop.nvim
-        hi!("HopNextKey", teal, -, -, Bold, -),
-        hi!("HopNextKey1", teal, -, -, Bold, -),
-        hi!("HopNextKey2", darkteal, -, -, Bold, -),
-        hi!("HopUnmatched", weakfg, -, -, -, -),
-        // flash.nvim
-        // https://github.com/folke/flash.nvim
-        hi!("FlashPrompt", purple, -, -, Bold, -),
-        hi!("FlashPromptIcon", teal, -, -, Bold, -),
-        hi!("FlashLabel", teal, -, -, Bold, -),
-        // fidget.nvim
-        // https://github.com/j-hui/fidget.nvim
-        hi!("FidgetTitle", teal, -, -, Bold, -),
-        hi!("FidgetTask", weakfg, -, -, -, -),
-        // nvim-hlslens
-        // https://github.com/kevinhwang91/nvim-hlslens
-        hi!("HlSearchLens", weakfg, -, -, Italic, -),
-        hi!("HlSearchLensNear", weakfg, -, -, Italic, -),
-        // nvim-notify
-        // https://github.com/rcarriga/nvim-notify
-        hi!("NotifyBackground", -, mainbg, -, -, -),
-        hi!("NotifyERRORBorder", errorborder, -, -, -, -),
-        hi!("NotifyWARNBorder", warningborder, -, -, -, -),
-        hi!("NotifyINFOBorder", infoborder, -, -, -, -),
-        hi!("NotifyDEBUGBorder", debugborder, -, -, -, -),
-        hi!("NotifyTRACEBorder", infoborder, -, -, -, -),
-        hi!("NotifyERRORIcon", errorfg, -, -, -, -),
-        hi!("NotifyWARNIcon", warningfg, -, -, -, -),
-        hi!("NotifyINFOIcon", infofg, -, -, -, -),
-        hi!("NotifyDEBUGIcon", debugfg, -, -, -, -),
-        hi!("NotifyTRACEIcon", infofg, -, -, -, -),
-        hi!("NotifyERRORTitle", errorfg, -, -, -, -),
-        hi!("NotifyWARNTitle", warningfg, -, -, -, -),
-        hi!("NotifyINFOTitle", infofg, -, -, -, -),
-        hi!("NotifyDEBUGTitle", debugfg, -, -, -, -),
-        hi!("NotifyTRACETitle", infofg, -, -, -, -),
-        hi!("NotifyERRORBody", mainfg, -, -, -, -),
-        hi!("NotifyWARNBody", mainfg, -, -, -, -),
-        hi!("NotifyINFOBody", mainfg, -, -, -, -),
-        hi!("NotifyDEBUGBody", mainfg, -, -, -, -),
-        hi!("NotifyTRACEBody", mainfg, -, -, -, -),
-        // avante.nvim
-        // https://github.com/yetone/avante.nvim
-        hi!("AvanteTitle", mainbg, purple, -, -, -),
-        hi!("AvanteReversedTitle", purple, mainbg, -, -, -),
-        hi!("AvanteSubtitle", mainbg, teal, -, -, -),
-        hi!("AvanteReversedSubtitle", teal, mainbg, -, -, -),
-        hi!("AvanteThirdTitle", mainfg, weakbg, -, -, -),
-        hi!("AvanteReversedThirdTitle", weakbg, mainbg, -, -, -),
-        hi!("AvantePopupHint", weakfg, -, -, -, -),
-        hi!("AvanteInlineHint", weakfg, -, -, -, -),
-        hi!("AvanteSidebarWinSeparator", darkfg, NONE, -, None, -),
-        hi!("AvanteSidebarWinHorizontalSeparator", mainbg, mainbg, -, None, -),
-    ]
+fn parse_scope(source: &str, value: &Spanned<DeValue<'_>>) -> Result<HighlightScope, ParseError> {
+    match string(source, value, "scope")? {
+        "all" => Ok(HighlightScope::All),
+        "nvim" => Ok(HighlightScope::Nvim),
+        "nvim-0.8" => Ok(HighlightScope::Nvim080OrLater),
+        other => Err(ParseError::at(
+            source,
+            value.span().start,
+            format!("unknown scope \"{}\"", other),
+        )),
+    }
+}
+
+fn string<'a>(
+    source: &str,
+    value: &'a Spanned<DeValue<'_>>,
+    key: &str,
+) -> Result<&'a str, ParseError> {
+    match value.get_ref() {
+        DeValue::String(s) => Ok(s),
+        _ => Err(ParseError::at(
+            source,
+            value.span().start,
+            format!("`{}` must be a string", key),
+        )),
+    }
+}
+
+fn array<'a, 'i>(
+    source: &str,
+    value: &'a Spanned<DeValue<'i>>,
+    key: &str,
+) -> Result<&'a [Spanned<DeValue<'i>>], ParseError> {
+    match value.get_ref() {
+        DeValue::Array(items) => Ok(items),
+        _ => Err(ParseError::at(
+            source,
+            value.span().start,
+            format!("`{}` must be an array", key),
+        )),
+    }
+}
+
+fn table<'a, 'i>(
+    source: &str,
+    value: &'a Spanned<DeValue<'i>>,
+    what: &str,
+) -> Result<&'a DeTable<'i>, ParseError> {
+    match value.get_ref() {
+        DeValue::Table(t) => Ok(t),
+        _ => Err(ParseError::at(
+            source,
+            value.span().start,
+            format!("{} must be a table", what),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn palette() -> Palette {
+        palette::parse_palette("red = \"#ff0000\"\nblue = \"#0000ff\"").unwrap()
+    }
+
+    #[test]
+    fn test_parse_highlights() {
+        let highlights = parse_highlights(
+            r#"
+[[section]]
+name = "general"
+highlights = [
+  { name = "Normal", fg = "red", bg = "NONE", attr = "bold, italic" },
+  { name = "Other", sp = "blue", scope = "nvim" },
+]
+
+[[section]]
+name = "treesitter"
+scope = "nvim-0.8"
+highlights = [
+  { name = "@string", fg = "blue" },
+]
+"#,
+            &palette(),
+        )
+        .unwrap();
+
+        assert_eq!(highlights.len(), 3);
+        assert_eq!(highlights[0].name, "Normal");
+        assert_eq!(highlights[0].fg.as_deref(), Some("red"));
+        assert_eq!(highlights[0].bg.as_deref(), Some("NONE"));
+        assert_eq!(
+            highlights[0].attr,
+            HighlightAttr::BOLD | HighlightAttr::ITALIC
+        );
+        assert_eq!(highlights[0].scope, HighlightScope::All);
+        assert_eq!(highlights[1].sp.as_deref(), Some("blue"));
+        assert!(highlights[1].attr.is_empty());
+        assert_eq!(highlights[1].scope, HighlightScope::Nvim);
+        assert_eq!(highlights[2].scope, HighlightScope::Nvim080OrLater);
+    }
+
+    #[test]
+    fn test_parse_highlights_errors() {
+        let cases = [
+            ("foo = 1", 1, "unknown key `foo`"),
+            (
+                "[[section]]\nhighlights = [\n  { name = \"A\", fg = \"green\" },\n]",
+                3,
+                "unknown color \"green\"",
+            ),
+            (
+                "[[section]]\nhighlights = [\n  { name = \"A\", sp = \"NONE\" },\n]",
+                3,
+                "unknown color \"NONE\"",
+            ),
+            (
+                "[[section]]\nhighlights = [\n  { name = \"A\", attr = \"blink\" },\n]",
+                3,
+                "unknown attr \"blink\"",
+            ),
+            (
+                "[[section]]\nhighlights = [\n  { name = \"A\", attr = \"bold,NONE\" },\n]",
+                3,
+                "cannot be combined",
+            ),
+            (
+                "[[section]]\nscope = \"emacs\"\nhighlights = []",
+                2,
+                "unknown scope \"emacs\"",
+            ),
+            (
+                "[[section]]\nhighlights = [\n  { fg = \"red\" },\n]",
+                3,
+                "missing `name`",
+            ),
+            ("[[section]]\nname = \"general\"", 1, "missing `highlights`"),
+        ];
+
+        for (source, line, message) in cases {
+            let err = parse_highlights(source, &palette()).unwrap_err();
+            assert_eq!(err.line, line, "{}", source);
+            assert!(err.message.contains(message), "{}: {}", source, err);
+        }
+    }
+
+    #[test]
+    fn test_parse_highlight_links() {
+        let highlights = parse_highlights(
+            r#"
+[[section]]
+name = "general"
+highlights = [
+  { name = "String", fg = "red" },
+  { name = "TSString", link = "String" },
+]
+"#,
+            &palette(),
+        )
+        .unwrap();
+
+        assert_eq!(highlights[1].name, "TSString");
+        assert_eq!(highlights[1].link.as_deref(), Some("String"));
+        assert_eq!(highlights[1].fg, None);
+    }
+
+    #[test]
+    fn test_parse_highlight_links_errors() {
+        let section = |groups: &str| format!("[[section]]\nhighlights = [\n{}\n]", groups);
+        let cases = [
+            (
+                section("  { name = \"A\", link = \"B\" },"),
+                3,
+                "\"A\" links to unknown group \"B\"",
+            ),
+            (
+                section("  { name = \"A\", fg = \"red\", link = \"B\" },"),
+                3,
+                "cannot be combined",
+            ),
+            (
+                section(
+                    "  { name = \"A\", fg = \"red\", scope = \"nvim\" },\n  { name = \"B\", link = \"A\" },",
+                ),
+                4,
+                "not defined in its scope",
+            ),
+            (
+                section(
+                    "  { name = \"A\", link = \"B\" },\n  { name = \"B\", link = \"C\" },\n  { name = \"C\", link = \"A\" },",
+                ),
+                3,
+                "circular link: A -> B -> C -> A",
+            ),
+            (
+                section(
+                    "  { name = \"A\", link = \"B\" },\n  { name = \"B\", link = \"C\" },\n  { name = \"C\", link = \"B\" },",
+                ),
+                4,
+                "circular link: B -> C -> B",
+            ),
+        ];
+
+        for (source, line, message) in cases {
+            let err = parse_highlights(&source, &palette()).unwrap_err();
+            assert_eq!(err.line, line, "{}", source);
+            assert!(err.message.contains(message), "{}: {}", source, err);
+        }
+    }
+
+    #[test]
+    fn test_highlight_attr() {
+        let attr = HighlightAttr::UNDERCURL | HighlightAttr::BOLD;
+
+        assert!(attr.contains(HighlightAttr::BOLD));
+        assert!(!attr.contains(HighlightAttr::ITALIC));
+        assert!(HighlightAttr::NOTHING.is_empty());
+        assert_eq!(attr.names(), vec!["bold", "undercurl"]);
+        assert_eq!(HighlightAttr::NONE.names(), vec!["NONE"]);
+        assert_eq!(
+            HighlightAttr::from_name("altfont"),
+            Some(HighlightAttr::ALTFONT)
+        );
+        assert_eq!(HighlightAttr::from_name("blink"), None);
+    }
+
+    #[test]
+    fn test_highlight_scope_covers() {
+        assert!(HighlightScope::All.covers(HighlightScope::Nvim080OrLater));
+        assert!(HighlightScope::Nvim.covers(HighlightScope::Nvim080OrLater));
+        assert!(!HighlightScope::Nvim.covers(HighlightScope::All));
+        assert!(!HighlightScope::Nvim080OrLater.covers(HighlightScope::Nvim));
+    }
+
+    #[test]
+    fn test_default_highlights() {
+        let palette = get_palette().unwrap();
+        let highlights = parse_highlights(DEFAULT_HIGHLIGHTS, &palette).unwrap();
+        assert_eq!(validate::validate(&palette, &highlights), vec![]);
+    }
 }
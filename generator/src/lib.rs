@@ -2,7 +2,21 @@
 extern crate lazy_static;
 extern crate empfindung;
 extern crate lab;
-extern crate tint;
 
+pub mod audit;
+pub mod contrast;
 pub mod conv;
+pub mod cvd;
+pub mod error;
+pub mod gamut;
+pub mod graph;
 pub mod highlight;
+pub mod output;
+pub mod palette;
+pub mod parse;
+pub mod preview;
+pub mod sample;
+pub mod specimen;
+pub mod style;
+pub mod terminal;
+pub mod validate;
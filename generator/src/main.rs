@@ -1,21 +1,68 @@
 #![allow(clippy::deprecated_cfg_attr)]
 
-use clap::{crate_authors, crate_name, crate_version, Arg, Command};
+use clap::builder::PossibleValuesParser;
+use clap::{
+    crate_authors, crate_name, crate_version, value_parser, Arg, ArgAction, ArgMatches, Command,
+};
+use dogrun::audit::{self, Thresholds};
+use dogrun::conv::{CtermColors, CtermOptions, Metric};
+use dogrun::cvd;
+use dogrun::error::{self, Error};
+use dogrun::gamut;
+use dogrun::graph::{self, Format};
 use dogrun::highlight::*;
+use dogrun::output::{self, Output};
+use dogrun::palette::{
+    default_palette, light_palette_with, load_palette, lookup, Derivations, ResolvedPalette,
+};
+use dogrun::preview;
+use dogrun::sample::{self, Groups, Language};
+use dogrun::specimen;
+use dogrun::style::Mode;
+use dogrun::terminal::{self, TerminalColors};
+use dogrun::validate::validate;
+use similar::TextDiff;
 use std::env;
-use std::fs::{self, create_dir_all, File};
-use std::io;
+use std::fs;
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
+use std::process;
+
+/// defx-icons color names and the palette entries they map to.
+const DEFX_ICONS: [(&str, &str); 16] = [
+    ("brown", "defxiconbrown"),
+    ("aqua", "defxiconaqua"),
+    ("blue", "defxiconblue"),
+    ("darkBlue", "defxicondarkblue"),
+    ("purple", "defxiconpurple"),
+    ("lightPurple", "defxiconlightpurple"),
+    ("red", "defxiconred"),
+    ("beige", "defxiconbeige"),
+    ("yellow", "defxiconyellow"),
+    ("orange", "defxiconorange"),
+    ("darkOrange", "defxicondarkorange"),
+    ("pink", "defxiconpink"),
+    ("salmon", "defxiconsalmon"),
+    ("green", "defxicongreen"),
+    ("lightGreen", "defxiconlightgreen"),
+    ("white", "defxiconwhite"),
+];
+
+fn highlight(palette: &Palette, hl: &Highlight) -> error::Result<String> {
+    if let Some(target) = &hl.link {
+        return Ok(format!("hi! link {} {}", hl.name, target));
+    }
+
+    let referenced_by = format!("highlight {}", hl.name);
 
-fn highlight(palette: &Palette, hl: &Highlight) -> String {
     let mut args = vec![hl.name.to_string()];
     let variants = &[(&hl.fg, "guifg", "ctermfg"), (&hl.bg, "guibg", "ctermbg")];
 
     // fg, bg
     for (color_name, gui, cterm) in variants {
         if let Some(name) = color_name {
-            if name != &"NONE" {
-                let color = &palette[name];
+            if name != "NONE" {
+                let color = lookup(palette, name, &referenced_by)?;
                 args.push(format!("{}={}", gui, color.gui));
                 args.push(format!("{}={}", cterm, color.cterm));
             } else {
@@ -26,39 +73,196 @@ fn highlight(palette: &Palette, hl: &Highlight) -> String {
     }
 
     // sp
-    if let Some(name) = hl.sp {
-        let color = &palette[name];
+    if let Some(name) = &hl.sp {
+        let color = lookup(palette, name, &referenced_by)?;
         args.push(format!("guisp={}", color.gui));
     }
 
     // attr
-    let attr = match hl.attr {
-        HighlightAttr::Nothing => "",
-        HighlightAttr::Bold => "gui=bold cterm=bold",
-        HighlightAttr::Italic => "gui=italic cterm=italic",
-        HighlightAttr::Underline => "gui=underline cterm=underline",
-        HighlightAttr::Strikethrough => "gui=strikethrough cterm=strikethrough",
-        HighlightAttr::Reverse => "gui=reverse cterm=reverse",
-        HighlightAttr::None => "gui=NONE cterm=NONE",
-    };
+    if !hl.attr.is_empty() {
+        let attr = hl.attr.names().join(",");
+        args.push(format!("gui={} cterm={}", attr, attr));
+    }
+
+    Ok(format!("hi {}", args.join(" ")))
+}
+
+/// Renders a highlight as a `nvim_set_hl()` call. Since `nvim_set_hl()`
+/// replaces the whole group, "NONE" colors and attributes are left out.
+fn lua_highlight(palette: &Palette, hl: &Highlight) -> error::Result<String> {
+    if let Some(target) = &hl.link {
+        return Ok(format!("hl(0, '{}', {{ link = '{}' }})", hl.name, target));
+    }
+
+    let referenced_by = format!("highlight {}", hl.name);
+
+    let mut args = vec![];
+    let variants = &[(&hl.fg, "fg", "ctermfg"), (&hl.bg, "bg", "ctermbg")];
+
+    // fg, bg
+    for (color_name, gui, cterm) in variants {
+        if let Some(name) = color_name {
+            if name != "NONE" {
+                let color = lookup(palette, name, &referenced_by)?;
+                args.push(format!("{} = '{}'", gui, color.gui));
+                args.push(format!("{} = {}", cterm, color.cterm));
+            }
+        }
+    }
+
+    // sp
+    if let Some(name) = &hl.sp {
+        let color = lookup(palette, name, &referenced_by)?;
+        args.push(format!("sp = '{}'", color.gui));
+    }
+
+    // attr
+    if hl.attr != HighlightAttr::NONE {
+        for name in hl.attr.names() {
+            args.push(format!("{} = true", name));
+        }
+    }
+
+    if args.is_empty() {
+        Ok(format!("hl(0, '{}', {{}})", hl.name))
+    } else {
+        Ok(format!("hl(0, '{}', {{ {} }})", hl.name, args.join(", ")))
+    }
+}
+
+/// Renders an entry of the vim-clap palette dictionary.
+fn clap_palette_entry(target: &str, fg: &Color, bg: Option<&Color>, attr: HighlightAttr) -> String {
+    let mut args = vec![format!("'ctermfg': '{}', 'guifg': '{}'", fg.cterm, fg.gui)];
+
+    if let Some(bg) = bg {
+        args.push(format!("'ctermbg': '{}', 'guibg': '{}'", bg.cterm, bg.gui));
+    }
+
+    if !attr.is_empty() {
+        let attr = attr.names().join(",");
+        args.push(format!("'gui': '{}', 'cterm': '{}'", attr, attr));
+    }
+
+    format!("  let s:palette.{} = {{ {} }}", target, args.join(", "))
+}
+
+/// Renders a vim-clap highlight group. Without `bg`, the background is reset.
+fn clap_highlight(name: &str, fg: &Color, bg: Option<&Color>, attr: HighlightAttr) -> String {
+    let mut args = vec![format!("guifg={}", fg.gui), format!("ctermfg={}", fg.cterm)];
+
+    match bg {
+        Some(bg) => {
+            args.push(format!("guibg={}", bg.gui));
+            args.push(format!("ctermbg={}", bg.cterm));
+        }
+        None => {
+            args.push("guibg=NONE".to_string());
+            args.push("ctermbg=NONE".to_string());
+        }
+    }
 
     if !attr.is_empty() {
-        args.push(attr.to_string());
+        let attr = attr.names().join(",");
+        args.push(format!("gui={} cterm={}", attr, attr));
     }
 
-    format!("hi {}", args.join(" "))
+    format!("  hi {} {}", name, args.join(" "))
+}
+
+/// Formats a table key, quoting names that are not valid Lua identifiers.
+fn lua_key(name: &str) -> String {
+    let is_identifier = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if is_identifier {
+        name.to_string()
+    } else {
+        format!("['{}']", name)
+    }
+}
+
+/// An artifact the generator can produce, selectable with `--target`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Target {
+    Vim,
+    Lua,
+    Lightline,
+    Clap,
+    Wezterm,
+    Iterm,
+    Alacritty,
+    Kitty,
+    Foot,
+    Ghostty,
+    Fzf,
+    Specimen,
+    Samples,
+}
+
+impl Target {
+    const ALL: [Target; 13] = [
+        Target::Vim,
+        Target::Lua,
+        Target::Lightline,
+        Target::Clap,
+        Target::Wezterm,
+        Target::Iterm,
+        Target::Alacritty,
+        Target::Kitty,
+        Target::Foot,
+        Target::Ghostty,
+        Target::Fzf,
+        Target::Specimen,
+        Target::Samples,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Target::Vim => "vim",
+            Target::Lua => "lua",
+            Target::Lightline => "lightline",
+            Target::Clap => "clap",
+            Target::Wezterm => "wezterm",
+            Target::Iterm => "iterm",
+            Target::Alacritty => "alacritty",
+            Target::Kitty => "kitty",
+            Target::Foot => "foot",
+            Target::Ghostty => "ghostty",
+            Target::Fzf => "fzf",
+            Target::Specimen => "specimen",
+            Target::Samples => "samples",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|target| target.name() == name)
+    }
+}
+
+/// Runs a writer against an in-memory buffer.
+fn capture<E: Into<Error>>(
+    write: impl FnOnce(&mut Vec<u8>) -> Result<(), E>,
+) -> error::Result<String> {
+    let mut buf = vec![];
+    write(&mut buf).map_err(Into::into)?;
+
+    String::from_utf8(buf).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err).into())
 }
 
 /// Helper struct for updating sections in README.md marked with HTML comments.
 /// This allows automated regeneration of configuration examples while preserving
 /// the rest of the README content.
 struct ReadmeUpdater {
+    path: PathBuf,
     content: String,
 }
 
 impl ReadmeUpdater {
-    fn new(content: String) -> Self {
-        Self { content }
+    fn new(path: &Path, content: String) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            content,
+        }
     }
 
     /// Replaces content between start_marker and end_marker with new_content.
@@ -68,26 +272,24 @@ impl ReadmeUpdater {
         start_marker: &str,
         end_marker: &str,
         new_content: &str,
-    ) -> io::Result<()> {
+    ) -> error::Result<()> {
+        let missing = |marker: &str| Error::MissingMarker {
+            marker: marker.to_string(),
+            path: self.path.clone(),
+        };
+
         // Find start marker position
-        let start_idx = self.content.find(start_marker).ok_or_else(|| {
-            io::Error::new(
-                io::ErrorKind::NotFound,
-                format!("Marker '{}' not found in README", start_marker),
-            )
-        })?;
+        let start_idx = self
+            .content
+            .find(start_marker)
+            .ok_or_else(|| missing(start_marker))?;
 
         // Find end marker AFTER the start marker to avoid matching earlier occurrences
         // (e.g., if end marker appears in documentation or other sections)
         let search_start = start_idx + start_marker.len();
         let end_idx_relative = self.content[search_start..]
             .find(end_marker)
-            .ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::NotFound,
-                    format!("Marker '{}' not found after start marker", end_marker),
-                )
-            })?;
+            .ok_or_else(|| missing(end_marker))?;
         let end_idx = search_start + end_idx_relative;
 
         // Detect line ending style from the original content
@@ -117,18 +319,34 @@ impl ReadmeUpdater {
 #[derive(Debug)]
 struct Writer {
     palette: Palette,
+    light_palette: Palette,
+    derivations: Derivations,
     highlights: Vec<Highlight>,
 }
 
 impl Writer {
-    fn new(palette: Palette, highlights: Vec<Highlight>) -> Self {
+    fn new(
+        palette: Palette,
+        light_palette: Palette,
+        derivations: Derivations,
+        highlights: Vec<Highlight>,
+    ) -> Self {
         Self {
             palette,
+            light_palette,
+            derivations,
             highlights,
         }
     }
 
-    fn write_colorscheme<W: io::Write>(&mut self, mut out: W) -> io::Result<()> {
+    fn palette(&self, background: Background) -> &Palette {
+        match background {
+            Background::Dark => &self.palette,
+            Background::Light => &self.light_palette,
+        }
+    }
+
+    fn write_colorscheme<W: io::Write>(&mut self, mut out: W) -> error::Result<()> {
         // header
         write!(
             out,
@@ -138,10 +356,6 @@ impl Writer {
 " License: MIT
 "   Copyright (c) 2020 wadackel
 
-if &background !=# 'dark'
-  set background=dark
-endif
-
 if exists('g:colors_name')
   hi clear
 endif
@@ -155,131 +369,280 @@ let g:colors_name = 'dogrun'
 "#
         )?;
 
+        writeln!(out, "if &background ==# 'light'")?;
+        self.write_highlights(&mut out, Background::Light)?;
+        writeln!(out, "else")?;
+        self.write_highlights(&mut out, Background::Dark)?;
+        writeln!(out, "endif")?;
+
+        // fzf.vim colors
+        writeln!(
+            out,
+            r#"let g:fzf_colors = {{
+  \ 'fg':      ['fg', 'Normal'],
+  \ 'bg':      ['bg', 'Normal'],
+  \ 'hl':      ['fg', 'Comment'],
+  \ 'fg+':     ['fg', 'CursorLine'],
+  \ 'bg+':     ['bg', 'CursorLine'],
+  \ 'hl+':     ['fg', 'Statement'],
+  \ 'info':    ['fg', 'Comment'],
+  \ 'gutter':  ['bg', 'Normal'],
+  \ 'border':  ['fg', 'Ignore'],
+  \ 'prompt':  ['fg', 'Label'],
+  \ 'pointer': ['fg', 'Boolean'],
+  \ 'marker':  ['fg', 'Boolean'],
+  \ 'spinner': ['fg', 'Title'],
+  \ 'header':  ['fg', 'Comment'],
+  \ }}"#
+        )?;
+
+        Ok(())
+    }
+
+    /// Writes the highlights and palette-dependent variables of one
+    /// background variant, indented to sit inside an `if &background` block.
+    fn write_highlights<W: io::Write>(
+        &self,
+        mut out: W,
+        background: Background,
+    ) -> error::Result<()> {
+        let palette = self.palette(background);
+
         // vim & nvim
         for hl in self.highlights.iter() {
             if hl.scope == HighlightScope::All {
-                writeln!(out, "{}", highlight(&self.palette, hl))?;
+                writeln!(out, "  {}", highlight(palette, hl)?)?;
             }
         }
 
-        writeln!(out, r#"if has("nvim")"#)?;
+        writeln!(out, r#"  if has("nvim")"#)?;
 
         // only nvim
         for hl in self.highlights.iter() {
             if hl.scope == HighlightScope::Nvim {
-                writeln!(out, "  {}", highlight(&self.palette, hl))?;
+                writeln!(out, "    {}", highlight(palette, hl)?)?;
             }
         }
 
         // term colors
-        let termcolors = vec![
-            "termblack",
-            "termmaroon",
-            "termgreen",
-            "termolive",
-            "termnavy",
-            "termpurple",
-            "termteal",
-            "termsilver",
-            "termgray",
-            "termred",
-            "termlime",
-            "termyellow",
-            "termblue",
-            "termfuchsia",
-            "termaqua",
-            "termwhite",
-        ];
-
-        for (index, name) in termcolors.iter().enumerate() {
-            let color = &self.palette[name].gui;
-            writeln!(out, "  let g:terminal_color_{} = '{}'", index, color)?;
+        for (index, color) in TerminalColors::new(palette)?.ansi.iter().enumerate() {
+            writeln!(out, "    let g:terminal_color_{} = '{}'", index, color)?;
         }
 
         writeln!(
             out,
-            "  let g:terminal_color_background = g:terminal_color_0"
+            "    let g:terminal_color_background = g:terminal_color_0"
         )?;
 
         writeln!(
             out,
-            "  let g:terminal_color_foreground = g:terminal_color_7"
+            "    let g:terminal_color_foreground = g:terminal_color_7"
         )?;
 
         // end nvim
-        writeln!(out, "endif")?;
+        writeln!(out, "  endif")?;
 
         // only nvim x version >= 800
-        writeln!(out, r#"if has("nvim-0.8.0")"#)?;
+        writeln!(out, r#"  if has("nvim-0.8.0")"#)?;
         for hl in self.highlights.iter() {
             if hl.scope == HighlightScope::Nvim080OrLater {
-                writeln!(out, "  {}", highlight(&self.palette, hl))?;
+                writeln!(out, "    {}", highlight(palette, hl)?)?;
             }
         }
-        writeln!(out, "endif")?;
+        writeln!(out, "  endif")?;
 
         // defx-icons palette
-        let defxicons = vec![
-            ("brown", &self.palette["defxiconbrown"]),
-            ("aqua", &self.palette["defxiconaqua"]),
-            ("blue", &self.palette["defxiconblue"]),
-            ("darkBlue", &self.palette["defxicondarkblue"]),
-            ("purple", &self.palette["defxiconpurple"]),
-            ("lightPurple", &self.palette["defxiconlightpurple"]),
-            ("red", &self.palette["defxiconred"]),
-            ("beige", &self.palette["defxiconbeige"]),
-            ("yellow", &self.palette["defxiconyellow"]),
-            ("orange", &self.palette["defxiconorange"]),
-            ("darkOrange", &self.palette["defxicondarkorange"]),
-            ("pink", &self.palette["defxiconpink"]),
-            ("salmon", &self.palette["defxiconsalmon"]),
-            ("green", &self.palette["defxicongreen"]),
-            ("lightGreen", &self.palette["defxiconlightgreen"]),
-            ("white", &self.palette["defxiconwhite"]),
-        ];
+        let defxicons: Vec<_> = DEFX_ICONS
+            .iter()
+            .map(|(name, color)| Ok((name, lookup(palette, color, "defx-icons")?)))
+            .collect::<error::Result<_>>()?;
 
-        writeln!(out, "let g:defx_icons_gui_colors = {{")?;
+        writeln!(out, "  let g:defx_icons_gui_colors = {{")?;
         for (name, color) in defxicons.iter() {
-            writeln!(
-                out,
-                "  \\ '{}': '{}',",
-                name,
-                &color.gui[1..color.gui.len()]
-            )?;
+            let gui = color.gui.to_string();
+            writeln!(out, "    \\ '{}': '{}',", name, &gui[1..])?;
         }
-        writeln!(out, "  \\ }}")?;
+        writeln!(out, "    \\ }}")?;
 
-        writeln!(out, "let g:defx_icons_term_colors = {{")?;
+        writeln!(out, "  let g:defx_icons_term_colors = {{")?;
         for (name, color) in defxicons.iter() {
-            writeln!(out, "  \\ '{}': {},", name, color.cterm)?;
+            writeln!(out, "    \\ '{}': {},", name, color.cterm)?;
         }
-        writeln!(out, "  \\ }}")?;
+        writeln!(out, "    \\ }}")?;
+
+        Ok(())
+    }
+
+    fn write_lua<W: io::Write>(&mut self, mut out: W) -> error::Result<()> {
+        // header
+        write!(
+            out,
+            r#"-- dogrun: Take a sweet dog with you.
+--
+-- Author: wadackel
+-- License: MIT
+--   Copyright (c) 2020 wadackel
+
+local M = {{}}
+
+function M.load()
+  if vim.g.colors_name then
+    vim.cmd('hi clear')
+  end
+
+  if vim.fn.exists('g:syntax_on') == 1 then
+    vim.cmd('syntax reset')
+  end
+
+  vim.g.colors_name = 'dogrun'
+
+  local hl = vim.api.nvim_set_hl
+
+"#
+        )?;
+
+        writeln!(out, "  if vim.o.background == 'light' then")?;
+        self.write_lua_highlights(&mut out, Background::Light)?;
+        writeln!(out, "  else")?;
+        self.write_lua_highlights(&mut out, Background::Dark)?;
+        writeln!(out, "  end")?;
 
         // fzf.vim colors
         writeln!(
             out,
-            r#"let g:fzf_colors = {{
-  \ 'fg':      ['fg', 'Normal'],
-  \ 'bg':      ['bg', 'Normal'],
-  \ 'hl':      ['fg', 'Comment'],
-  \ 'fg+':     ['fg', 'CursorLine'],
-  \ 'bg+':     ['bg', 'CursorLine'],
-  \ 'hl+':     ['fg', 'Statement'],
-  \ 'info':    ['fg', 'Comment'],
-  \ 'gutter':  ['bg', 'Normal'],
-  \ 'border':  ['fg', 'Ignore'],
-  \ 'prompt':  ['fg', 'Label'],
-  \ 'pointer': ['fg', 'Boolean'],
-  \ 'marker':  ['fg', 'Boolean'],
-  \ 'spinner': ['fg', 'Title'],
-  \ 'header':  ['fg', 'Comment'],
-  \ }}"#
+            r#"
+  vim.g.fzf_colors = {{
+    ['fg'] = {{ 'fg', 'Normal' }},
+    ['bg'] = {{ 'bg', 'Normal' }},
+    ['hl'] = {{ 'fg', 'Comment' }},
+    ['fg+'] = {{ 'fg', 'CursorLine' }},
+    ['bg+'] = {{ 'bg', 'CursorLine' }},
+    ['hl+'] = {{ 'fg', 'Statement' }},
+    ['info'] = {{ 'fg', 'Comment' }},
+    ['gutter'] = {{ 'bg', 'Normal' }},
+    ['border'] = {{ 'fg', 'Ignore' }},
+    ['prompt'] = {{ 'fg', 'Label' }},
+    ['pointer'] = {{ 'fg', 'Boolean' }},
+    ['marker'] = {{ 'fg', 'Boolean' }},
+    ['spinner'] = {{ 'fg', 'Title' }},
+    ['header'] = {{ 'fg', 'Comment' }},
+  }}
+end
+
+return M"#
         )?;
 
         Ok(())
     }
 
-    fn write_lightline<W: io::Write>(&mut self, mut out: W) -> io::Result<()> {
+    /// Lua counterpart of `write_highlights`, indented to sit inside the
+    /// `vim.o.background` branch of `M.load()`.
+    fn write_lua_highlights<W: io::Write>(
+        &self,
+        mut out: W,
+        background: Background,
+    ) -> error::Result<()> {
+        let palette = self.palette(background);
+
+        // vim & nvim, only nvim
+        for hl in self.highlights.iter() {
+            if hl.scope != HighlightScope::Nvim080OrLater {
+                writeln!(out, "    {}", lua_highlight(palette, hl)?)?;
+            }
+        }
+
+        // only nvim x version >= 800
+        writeln!(out, "    if vim.fn.has('nvim-0.8.0') == 1 then")?;
+        for hl in self.highlights.iter() {
+            if hl.scope == HighlightScope::Nvim080OrLater {
+                writeln!(out, "      {}", lua_highlight(palette, hl)?)?;
+            }
+        }
+        writeln!(out, "    end")?;
+
+        // term colors
+        for (index, color) in TerminalColors::new(palette)?.ansi.iter().enumerate() {
+            writeln!(out, "    vim.g.terminal_color_{} = '{}'", index, color)?;
+        }
+
+        writeln!(
+            out,
+            "    vim.g.terminal_color_background = vim.g.terminal_color_0"
+        )?;
+
+        writeln!(
+            out,
+            "    vim.g.terminal_color_foreground = vim.g.terminal_color_7"
+        )?;
+
+        // defx-icons palette
+        writeln!(out, "    vim.g.defx_icons_gui_colors = {{")?;
+        for (name, color) in DEFX_ICONS.iter() {
+            let gui = lookup(palette, color, "defx-icons")?.gui.to_string();
+            writeln!(out, "      {} = '{}',", name, &gui[1..])?;
+        }
+        writeln!(out, "    }}")?;
+
+        writeln!(out, "    vim.g.defx_icons_term_colors = {{")?;
+        for (name, color) in DEFX_ICONS.iter() {
+            let color = lookup(palette, color, "defx-icons")?;
+            writeln!(out, "      {} = {},", name, color.cterm)?;
+        }
+        writeln!(out, "    }}")?;
+
+        Ok(())
+    }
+
+    /// Writes `lua/dogrun/palette.lua`. Indexing the module resolves colors
+    /// against the current `'background'`, while the `dark` and `light`
+    /// fields give access to a specific variant.
+    fn write_lua_palette<W: io::Write>(&mut self, mut out: W) -> error::Result<()> {
+        // header
+        write!(
+            out,
+            r#"-- dogrun palette
+--
+-- Author: wadackel
+-- License: MIT
+--   Copyright (c) 2020 wadackel
+--
+-- local palette = require('dogrun.palette')
+-- palette.purple       -- color for the current 'background'
+-- palette.dark.purple  -- color of the dark variant
+-- palette.light.purple -- color of the light variant
+
+local palettes = {{}}
+
+"#
+        )?;
+
+        for (background, name) in [(Background::Dark, "dark"), (Background::Light, "light")] {
+            let palette = self.palette(background);
+            let mut names: Vec<&String> = palette.keys().collect();
+            names.sort();
+
+            writeln!(out, "palettes.{} = {{", name)?;
+            for name in names {
+                writeln!(out, "  {} = '{}',", lua_key(name), palette[name].gui)?;
+            }
+            writeln!(out, "}}")?;
+            writeln!(out)?;
+        }
+
+        // footer
+        writeln!(
+            out,
+            r#"return setmetatable({{ dark = palettes.dark, light = palettes.light }}, {{
+  __index = function(_, key)
+    return palettes[vim.o.background == 'light' and 'light' or 'dark'][key]
+  end,
+}})"#
+        )?;
+
+        Ok(())
+    }
+
+    fn write_lightline<W: io::Write>(&mut self, mut out: W) -> error::Result<()> {
         // header
         write!(
             out,
@@ -295,60 +658,69 @@ let s:p = {{'normal': {{}}, 'inactive': {{}}, 'insert': {{}}, 'replace': {{}}, '
         )?;
 
         // body
-        let palette = &self.palette;
+        for (background, condition) in [
+            (Background::Light, "if &background ==# 'light'"),
+            (Background::Dark, "else"),
+        ] {
+            writeln!(out, "{}", condition)?;
 
-        let color = |name: &str| {
-            let highlight = palette.get(name).expect("error");
-            format!("['{}', {}]", highlight.gui, highlight.cterm)
-        };
+            let palette = self.palette(background);
 
-        macro_rules! p {
-            ($target: ident, $direction: ident, $fg1: ident, $bg1: ident) => {
-                writeln!(
-                    out,
-                    "let s:p.{}.{} = [[{}, {}]]",
-                    stringify!($target),
-                    stringify!($direction),
-                    color(stringify!($fg1)),
-                    color(stringify!($bg1))
-                )?;
+            let color = |name: &str| -> error::Result<String> {
+                let color = lookup(palette, name, "the lightline theme")?;
+                Ok(format!("['{}', {}]", color.gui, color.cterm))
             };
-            ($target: ident, $direction: ident, $fg1: ident, $bg1: ident, $fg2: ident, $bg2: ident) => {
-                writeln!(
-                    out,
-                    "let s:p.{}.{} = [[{}, {}], [{}, {}]]",
-                    stringify!($target),
-                    stringify!($direction),
-                    color(stringify!($fg1)),
-                    color(stringify!($bg1)),
-                    color(stringify!($fg2)),
-                    color(stringify!($bg2))
-                )?;
-            };
-        }
 
-        #[cfg_attr(rustfmt, rustfmt_skip)]
-        {
-            p!(normal, left, mainbg, purple, purple, xlinegradientbg);
-            p!(normal, middle, xlinefg, xlinebg);
-            p!(normal, right, mainbg, purple, purple, xlinegradientbg);
-            p!(normal, error, errorfg, xlinebg);
-            p!(normal, warning, warningfg, xlinebg);
-            p!(inactive, left, statuslinencfg, statuslinencbg, statuslinencfg, statuslinencbg);
-            p!(inactive, middle, statuslinencfg, statuslinencbg);
-            p!(inactive, right, statuslinencfg, statuslinencbg, statuslinencfg, statuslinencbg);
-            p!(insert, left, mainbg, teal, teal, xlinegradientbg);
-            p!(insert, right, mainbg, teal, teal, xlinegradientbg);
-            p!(visual, left, mainbg, pink, pink, xlinegradientbg);
-            p!(visual, right, mainbg, pink, pink, xlinegradientbg);
-            p!(replace, left, mainbg, red, red, xlinegradientbg);
-            p!(replace, right, mainbg, red, red, xlinegradientbg);
-            p!(tabline, left, xlinefg, xlinebg);
-            p!(tabline, tabsel, mainbg, purple);
-            p!(tabline, middle, xlinefg, xlinebg);
-            p!(tabline, right, xlinefg, xlinebg);
+            macro_rules! p {
+                ($target: ident, $direction: ident, $fg1: ident, $bg1: ident) => {
+                    writeln!(
+                        out,
+                        "  let s:p.{}.{} = [[{}, {}]]",
+                        stringify!($target),
+                        stringify!($direction),
+                        color(stringify!($fg1))?,
+                        color(stringify!($bg1))?
+                    )?;
+                };
+                ($target: ident, $direction: ident, $fg1: ident, $bg1: ident, $fg2: ident, $bg2: ident) => {
+                    writeln!(
+                        out,
+                        "  let s:p.{}.{} = [[{}, {}], [{}, {}]]",
+                        stringify!($target),
+                        stringify!($direction),
+                        color(stringify!($fg1))?,
+                        color(stringify!($bg1))?,
+                        color(stringify!($fg2))?,
+                        color(stringify!($bg2))?
+                    )?;
+                };
+            }
+
+            #[cfg_attr(rustfmt, rustfmt_skip)]
+            {
+                p!(normal, left, mainbg, purple, purple, xlinegradientbg);
+                p!(normal, middle, xlinefg, xlinebg);
+                p!(normal, right, mainbg, purple, purple, xlinegradientbg);
+                p!(normal, error, errorfg, xlinebg);
+                p!(normal, warning, warningfg, xlinebg);
+                p!(inactive, left, statuslinencfg, statuslinencbg, statuslinencfg, statuslinencbg);
+                p!(inactive, middle, statuslinencfg, statuslinencbg);
+                p!(inactive, right, statuslinencfg, statuslinencbg, statuslinencfg, statuslinencbg);
+                p!(insert, left, mainbg, teal, teal, xlinegradientbg);
+                p!(insert, right, mainbg, teal, teal, xlinegradientbg);
+                p!(visual, left, mainbg, pink, pink, xlinegradientbg);
+                p!(visual, right, mainbg, pink, pink, xlinegradientbg);
+                p!(replace, left, mainbg, red, red, xlinegradientbg);
+                p!(replace, right, mainbg, red, red, xlinegradientbg);
+                p!(tabline, left, xlinefg, xlinebg);
+                p!(tabline, tabsel, mainbg, purple);
+                p!(tabline, middle, xlinefg, xlinebg);
+                p!(tabline, right, xlinefg, xlinebg);
+            }
         }
 
+        writeln!(out, "endif")?;
+
         // footer
         writeln!(
             out,
@@ -359,7 +731,7 @@ let g:lightline#colorscheme#dogrun#palette = lightline#colorscheme#flatten(s:p)"
         Ok(())
     }
 
-    fn write_clap<W: io::Write>(&mut self, mut out: W) -> io::Result<()> {
+    fn write_clap<W: io::Write>(&mut self, mut out: W) -> error::Result<()> {
         // header
         write!(
             out,
@@ -377,117 +749,82 @@ let s:palette = {{}}
         )?;
 
         // body
-        let palette = &self.palette;
-
-        macro_rules! p {
-            ($target: ident, $fg: ident, $bg: ident, $attr: ident) => {
-                let mut args: Vec<String> = vec![];
-
-                if let Some(hi) = palette.get(stringify!($fg)) {
-                    args.push(format!("'ctermfg': '{}', 'guifg': '{}'", hi.cterm, hi.gui,));
-                }
+        for (background, condition) in [
+            (Background::Light, "if &background ==# 'light'"),
+            (Background::Dark, "else"),
+        ] {
+            writeln!(out, "{}", condition)?;
+
+            let palette = self.palette(background);
+            let color = |name: &str| lookup(palette, name, "the vim-clap theme");
+
+            macro_rules! p {
+                ($target: ident, $fg: ident, -, $attr: expr) => {
+                    let fg = color(stringify!($fg))?;
+                    writeln!(
+                        out,
+                        "{}",
+                        clap_palette_entry(stringify!($target), fg, None, $attr)
+                    )?;
+                };
+                ($target: ident, $fg: ident, $bg: ident, $attr: expr) => {
+                    let (fg, bg) = (color(stringify!($fg))?, color(stringify!($bg))?);
+                    writeln!(
+                        out,
+                        "{}",
+                        clap_palette_entry(stringify!($target), fg, Some(bg), $attr)
+                    )?;
+                };
+            }
 
-                if let Some(hi) = palette.get(stringify!($bg)) {
-                    args.push(format!("'ctermbg': '{}', 'guibg': '{}'", hi.cterm, hi.gui,));
-                }
+            macro_rules! h {
+                ($name: ident, $fg: ident, -, $attr: expr) => {
+                    let fg = color(stringify!($fg))?;
+                    writeln!(
+                        out,
+                        "{}",
+                        clap_highlight(stringify!($name), fg, None, $attr)
+                    )?;
+                };
+                ($name: ident, $fg: ident, $bg: ident, $attr: expr) => {
+                    let (fg, bg) = (color(stringify!($fg))?, color(stringify!($bg))?);
+                    writeln!(
+                        out,
+                        "{}",
+                        clap_highlight(stringify!($name), fg, Some(bg), $attr)
+                    )?;
+                };
+            }
 
-                match HighlightAttr::$attr {
-                    HighlightAttr::None => args.push("'gui': 'NONE', 'cterm': 'NONE'".to_string()),
-                    HighlightAttr::Bold => args.push("'gui': 'bold', 'cterm': 'bold'".to_string()),
-                    HighlightAttr::Italic => {
-                        args.push("'gui': 'italic', 'cterm': 'italic'".to_string())
-                    }
-                    HighlightAttr::Underline => {
-                        args.push("'gui': 'underline', 'cterm': 'underline'".to_string())
-                    }
-                    HighlightAttr::Reverse => {
-                        args.push("'gui': 'reverse', 'cterm': 'reverse'".to_string())
-                    }
-                    _ => {}
-                }
+            use HighlightAttr as A;
+
+            #[cfg_attr(rustfmt, rustfmt_skip)]
+            {
+                p!(input, purple, pmenubar, A::BOLD);
+                p!(display, pmenufg, pmenubg, A::NONE);
+                p!(spinner, purple, pmenubar, A::BOLD);
+                p!(search_text, mainfg, pmenubar, A::NONE);
+                p!(preview, pmenuselfg, pmenuselbg, A::NONE);
+                p!(selected, cyan, -, A::BOLD);
+                p!(current_selection, emphasisfg, -, A::BOLD);
+            }
 
-                writeln!(
-                    out,
-                    "let s:palette.{} = {{ {} }}",
-                    stringify!($target),
-                    args.join(", "),
-                )?;
-            };
-            ($target: ident, $fg: ident, -, $attr: ident) => {
-                p!($target, $fg, None, $attr);
-            };
+            #[cfg_attr(rustfmt, rustfmt_skip)]
+            {
+                h!(ClapMatches, teal, -, A::BOLD);
+                h!(ClapNoMatchesFound, warningfg, -, A::BOLD);
+            }
         }
 
-        macro_rules! h {
-            ($name: ident, $fg: ident, $bg: ident, $attr: ident) => {
-                let mut args: Vec<String> = vec![];
-
-                if let Some(fg) = palette.get(stringify!($fg)) {
-                    args.push(format!("guifg={}", fg.gui));
-                    args.push(format!("ctermfg={}", fg.cterm));
-                }
-
-                if let Some(bg) = palette.get(stringify!($bg)) {
-                    args.push(format!("guibg={}", bg.gui));
-                    args.push(format!("ctermfg={}", bg.cterm));
-                } else if stringify!($bg) == "None" {
-                    args.push("guibg=NONE".to_string());
-                    args.push("cterm=NONE".to_string());
-                }
-
-                match HighlightAttr::$attr {
-                    HighlightAttr::None => args.push("gui=NONE cterm=NONE".to_string()),
-                    HighlightAttr::Bold => args.push("gui=bold cterm=bold".to_string()),
-                    HighlightAttr::Italic => args.push("gui=italic cterm=italic".to_string()),
-                    HighlightAttr::Underline => {
-                        args.push("gui=underline cterm=underline".to_string())
-                    }
-                    HighlightAttr::Reverse => args.push("gui=reverse cterm=reverse".to_string()),
-                    _ => {}
-                }
+        writeln!(out, "endif")?;
 
-                writeln!(out, "hi {} {}", stringify!($name), args.join(" "),)?;
-            };
-            ($target: ident, $fg: ident, -, $attr: ident) => {
-                h!($target, $fg, None, $attr);
-            };
+        // the numbered match groups share the colors of ClapMatches
+        for i in 1..=8 {
+            writeln!(out, "hi! link ClapMatches{} ClapMatches", i)?;
         }
 
-        #[cfg_attr(rustfmt, rustfmt_skip)]
-        {
-            p!(input, purple, pmenubar, Bold);
-            p!(display, pmenufg, pmenubg, None);
-            p!(spinner, purple, pmenubar, Bold);
-            p!(search_text, mainfg, pmenubar, None);
-            p!(preview, pmenuselfg, pmenuselbg, None);
-            p!(selected, cyan, -, Bold);
-            p!(current_selection, emphasisfg, -, Bold);
-        }
-
-        #[cfg_attr(rustfmt, rustfmt_skip)]
-        {
-            h!(ClapMatches, teal, -, Bold);
-            h!(ClapMatches1, teal, -, Bold);
-            h!(ClapMatches2, teal, -, Bold);
-            h!(ClapMatches3, teal, -, Bold);
-            h!(ClapMatches4, teal, -, Bold);
-            h!(ClapMatches5, teal, -, Bold);
-            h!(ClapMatches6, teal, -, Bold);
-            h!(ClapMatches7, teal, -, Bold);
-            h!(ClapMatches8, teal, -, Bold);
-            h!(ClapFuzzyMatches1, teal, -, Bold);
-            h!(ClapFuzzyMatches2, teal, -, Bold);
-            h!(ClapFuzzyMatches3, teal, -, Bold);
-            h!(ClapFuzzyMatches4, teal, -, Bold);
-            h!(ClapFuzzyMatches5, teal, -, Bold);
-            h!(ClapFuzzyMatches6, teal, -, Bold);
-            h!(ClapFuzzyMatches7, teal, -, Bold);
-            h!(ClapFuzzyMatches8, teal, -, Bold);
-            h!(ClapFuzzyMatches9, teal, -, Bold);
-            h!(ClapFuzzyMatches10, teal, -, Bold);
-            h!(ClapFuzzyMatches11, teal, -, Bold);
-            h!(ClapFuzzyMatches12, teal, -, Bold);
-            h!(ClapNoMatchesFound, warningfg, -, Bold);
+        for i in 1..=12 {
+            writeln!(out, "hi! link ClapFuzzyMatches{} ClapMatches", i)?;
         }
 
         // footer
@@ -503,153 +840,162 @@ unlet s:save_cpo
         Ok(())
     }
 
-    fn write_wezterm<W: io::Write>(&mut self, mut out: W) -> io::Result<()> {
-        // [colors] section
-        writeln!(out, "[colors]")?;
-        writeln!(out, "background = \"{}\"", self.palette["mainbg"].gui)?;
-        writeln!(out, "foreground = \"{}\"", self.palette["mainfg"].gui)?;
-        writeln!(out, "cursor_bg = \"{}\"", self.palette["mainfg"].gui)?;
-        writeln!(out, "cursor_fg = \"{}\"", self.palette["mainbg"].gui)?;
-        writeln!(out, "cursor_border = \"{}\"", self.palette["mainfg"].gui)?;
-        writeln!(out, "selection_bg = \"{}\"", self.palette["visualbg"].gui)?;
-
-        // ansi array (0-7)
-        writeln!(out, "ansi = [")?;
-        for name in [
-            "termblack",
-            "termmaroon",
-            "termgreen",
-            "termolive",
-            "termnavy",
-            "termpurple",
-            "termteal",
-            "termsilver",
-        ] {
-            writeln!(out, "  \"{}\",", self.palette[name].gui)?;
-        }
-        writeln!(out, "]")?;
-
-        // brights array (8-15)
-        writeln!(out, "brights = [")?;
-        for name in [
-            "termgray",
-            "termred",
-            "termlime",
-            "termyellow",
-            "termblue",
-            "termfuchsia",
-            "termaqua",
-            "termwhite",
-        ] {
-            writeln!(out, "  \"{}\",", self.palette[name].gui)?;
-        }
-        writeln!(out, "]")?;
+    /// Renders the files of a target. The fzf target updates `README.md`
+    /// in `dir` when it exists, or renders the bare export statements when
+    /// there is no output directory.
+    fn render(&mut self, target: Target, dir: Option<&Path>) -> error::Result<Vec<Output>> {
+        let output = |path: &str, content: String| Output::new(path, content);
+
+        let outputs = match target {
+            Target::Vim => vec![output(
+                "colors/dogrun.vim",
+                capture(|out| self.write_colorscheme(out))?,
+            )],
+            Target::Lua => vec![
+                output("lua/dogrun/init.lua", capture(|out| self.write_lua(out))?),
+                output(
+                    "lua/dogrun/palette.lua",
+                    capture(|out| self.write_lua_palette(out))?,
+                ),
+            ],
+            Target::Lightline => vec![output(
+                "autoload/lightline/colorscheme/dogrun.vim",
+                capture(|out| self.write_lightline(out))?,
+            )],
+            Target::Clap => vec![output(
+                "autoload/clap/themes/dogrun.vim",
+                capture(|out| self.write_clap(out))?,
+            )],
+            Target::Specimen => {
+                let palettes = [
+                    (Background::Dark, &self.palette),
+                    (Background::Light, &self.light_palette),
+                ];
+
+                vec![
+                    output(
+                        "docs/specimen.html",
+                        capture(|out| {
+                            specimen::write_html(
+                                out,
+                                &palettes,
+                                &self.derivations,
+                                &self.highlights,
+                            )
+                        })?,
+                    ),
+                    output(
+                        "docs/specimen.svg",
+                        capture(|out| specimen::write_svg(out, &palettes, &self.derivations))?,
+                    ),
+                ]
+            }
+            Target::Samples => {
+                let palettes = [
+                    (Background::Dark, &self.palette),
+                    (Background::Light, &self.light_palette),
+                ];
+
+                Language::ALL
+                    .into_iter()
+                    .map(|language| {
+                        Ok(output(
+                            &format!("docs/samples/{}.html", language.name()),
+                            capture(|out| {
+                                sample::write_html(out, &palettes, &self.highlights, language)
+                            })?,
+                        ))
+                    })
+                    .collect::<error::Result<_>>()?
+            }
+            Target::Fzf => match dir.map(|dir| dir.join("README.md")) {
+                Some(path) if path.exists() => {
+                    vec![output("README.md", update_readme_fzf(self, &path)?)]
+                }
+                Some(_) => vec![],
+                None => vec![output(
+                    "fzf",
+                    format!(
+                        "{}\n{}\n",
+                        self.generate_fzf_export(Background::Dark)?,
+                        self.generate_fzf_export(Background::Light)?
+                    ),
+                )],
+            },
+            terminal => {
+                let mut outputs = vec![];
+
+                for background in [Background::Dark, Background::Light] {
+                    let colors = TerminalColors::new(self.palette(background))?;
+                    let name = match background {
+                        Background::Dark => "dogrun",
+                        Background::Light => "dogrun-light",
+                    };
+
+                    outputs.push(match terminal {
+                        Target::Wezterm => output(
+                            &format!("wezterm/{}.toml", name),
+                            capture(|out| terminal::write_wezterm(out, &colors, name))?,
+                        ),
+                        Target::Iterm => output(
+                            &format!("term/{}.itermcolors", name),
+                            capture(|out| terminal::write_iterm(out, &colors))?,
+                        ),
+                        Target::Alacritty => output(
+                            &format!("alacritty/{}.toml", name),
+                            capture(|out| terminal::write_alacritty(out, &colors, name))?,
+                        ),
+                        Target::Kitty => output(
+                            &format!("kitty/{}.conf", name),
+                            capture(|out| terminal::write_kitty(out, &colors, name))?,
+                        ),
+                        Target::Foot => output(
+                            &format!("foot/{}.ini", name),
+                            capture(|out| terminal::write_foot(out, &colors, name))?,
+                        ),
+                        _ => output(
+                            &format!("ghostty/{}", name),
+                            capture(|out| terminal::write_ghostty(out, &colors, name))?,
+                        ),
+                    });
+                }
 
-        // [metadata] section
-        writeln!(out)?;
-        writeln!(out, "[metadata]")?;
-        writeln!(out, "name = \"dogrun\"")?;
-        writeln!(out, "author = \"wadackel\"")?;
-        writeln!(
-            out,
-            "origin_url = \"https://github.com/wadackel/vim-dogrun\""
-        )?;
+                outputs
+            }
+        };
 
-        Ok(())
+        Ok(outputs)
     }
 
     /// Generates fzf color configuration as a shell export statement.
-    /// Returns Result to handle missing palette colors gracefully.
-    fn generate_fzf_export(&self) -> io::Result<String> {
-        // Define fzf color mappings to palette colors
-        // Using .get() with explicit error messages per Codex review
-        let colors = vec![
-            (
-                "fg",
-                self.palette.get("lightfg").ok_or_else(|| {
-                    io::Error::new(io::ErrorKind::NotFound, "missing weakfg in palette")
-                })?,
-            ),
-            (
-                "bg",
-                self.palette.get("mainbg").ok_or_else(|| {
-                    io::Error::new(io::ErrorKind::NotFound, "missing mainbg in palette")
-                })?,
-            ),
-            (
-                "hl",
-                self.palette.get("emphasisfg").ok_or_else(|| {
-                    io::Error::new(io::ErrorKind::NotFound, "missing emphasisfg in palette")
-                })?,
-            ),
-            (
-                "fg+",
-                self.palette.get("lightfg").ok_or_else(|| {
-                    io::Error::new(io::ErrorKind::NotFound, "missing weakfg in palette")
-                })?,
-            ),
-            (
-                "bg+",
-                self.palette.get("visualbg").ok_or_else(|| {
-                    io::Error::new(io::ErrorKind::NotFound, "missing visualbg in palette")
-                })?,
-            ),
-            (
-                "hl+",
-                self.palette.get("emphasisfg").ok_or_else(|| {
-                    io::Error::new(io::ErrorKind::NotFound, "missing emphasisfg in palette")
-                })?,
-            ),
-            (
-                "info",
-                self.palette.get("purple").ok_or_else(|| {
-                    io::Error::new(io::ErrorKind::NotFound, "missing purple in palette")
-                })?,
-            ),
-            (
-                "prompt",
-                self.palette.get("linenrfg").ok_or_else(|| {
-                    io::Error::new(io::ErrorKind::NotFound, "missing linenrfg in palette")
-                })?,
-            ),
-            // Using pink from palette for consistency (instead of hardcoded #ff79c6)
-            (
-                "pointer",
-                self.palette.get("pink").ok_or_else(|| {
-                    io::Error::new(io::ErrorKind::NotFound, "missing pink in palette")
-                })?,
-            ),
-            (
-                "marker",
-                self.palette.get("pink").ok_or_else(|| {
-                    io::Error::new(io::ErrorKind::NotFound, "missing pink in palette")
-                })?,
-            ),
-            (
-                "spinner",
-                self.palette.get("teal").ok_or_else(|| {
-                    io::Error::new(io::ErrorKind::NotFound, "missing teal in palette")
-                })?,
-            ),
-            (
-                "header",
-                self.palette.get("linenrfg").ok_or_else(|| {
-                    io::Error::new(io::ErrorKind::NotFound, "missing linenrfg in palette")
-                })?,
-            ),
-            (
-                "border",
-                self.palette.get("linenrfg").ok_or_else(|| {
-                    io::Error::new(io::ErrorKind::NotFound, "missing linenrfg in palette")
-                })?,
-            ),
+    fn generate_fzf_export(&self, background: Background) -> error::Result<String> {
+        let palette = self.palette(background);
+
+        // fzf color keys and the palette entries they map to
+        let colors = [
+            ("fg", "lightfg"),
+            ("bg", "mainbg"),
+            ("hl", "emphasisfg"),
+            ("fg+", "lightfg"),
+            ("bg+", "visualbg"),
+            ("hl+", "emphasisfg"),
+            ("info", "purple"),
+            ("prompt", "linenrfg"),
+            ("pointer", "pink"),
+            ("marker", "pink"),
+            ("spinner", "teal"),
+            ("header", "linenrfg"),
+            ("border", "linenrfg"),
         ];
 
         // Build --color format strings
-        let color_parts: Vec<String> = colors
+        let color_parts = colors
             .iter()
-            .map(|(key, color)| format!("{}:{}", key, color.gui))
-            .collect();
+            .map(|(key, name)| {
+                let color = lookup(palette, name, "the fzf colors")?;
+                Ok(format!("{}:{}", key, color.gui))
+            })
+            .collect::<error::Result<Vec<_>>>()?;
 
         // Split into two --color groups for readability (matching README format)
         let first_group = &color_parts[0..7]; // fg ~ hl+
@@ -673,73 +1019,493 @@ fn abs(path: PathBuf) -> io::Result<PathBuf> {
 
 /// Updates README.md's fzf section with generated color configuration.
 /// Uses HTML comment markers (<!-- fzf:start --> and <!-- fzf:end -->)
-/// to identify the section to replace.
-fn update_readme_fzf(writer: &mut Writer, readme_path: &Path) -> io::Result<()> {
-    // Read README content
-    let content = fs::read_to_string(readme_path)?;
-
+/// to identify the section to replace. The light variant is written to
+/// <!-- fzf-light:start --> and <!-- fzf-light:end --> when present.
+fn update_readme_fzf(writer: &mut Writer, path: &Path) -> error::Result<String> {
     // Create updater
-    let mut updater = ReadmeUpdater::new(content);
+    let mut updater = ReadmeUpdater::new(path, fs::read_to_string(path)?);
+
+    for (background, start_marker, end_marker) in [
+        (Background::Dark, "<!-- fzf:start -->", "<!-- fzf:end -->"),
+        (
+            Background::Light,
+            "<!-- fzf-light:start -->",
+            "<!-- fzf-light:end -->",
+        ),
+    ] {
+        // The light section is optional
+        if background == Background::Light && !updater.content.contains(start_marker) {
+            continue;
+        }
 
-    // Generate fzf export string
-    let fzf_export = writer.generate_fzf_export()?;
+        // Generate fzf export string
+        let fzf_export = writer.generate_fzf_export(background)?;
 
-    // Wrap in markdown code block
-    let code_block = format!("```bash\n{}\n```", fzf_export);
+        // Wrap in markdown code block
+        let code_block = format!("```bash\n{}\n```", fzf_export);
 
-    // Replace HTML comment section
-    updater.replace_section("<!-- fzf:start -->", "<!-- fzf:end -->", &code_block)?;
+        // Replace HTML comment section
+        updater.replace_section(start_marker, end_marker, &code_block)?;
+    }
 
-    // Write back to file
-    fs::write(readme_path, updater.into_string())?;
+    Ok(updater.into_string())
+}
 
-    Ok(())
+/// Prints a unified diff between the file on disk and its new content,
+/// returning whether they differ. Missing files are diffed as empty.
+fn print_diff(dir: &Path, output: &Output) -> io::Result<bool> {
+    let path = dir.join(&output.path);
+    let current = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err),
+    };
+
+    if current == output.content {
+        return Ok(false);
+    }
+
+    let name = output.path.display().to_string();
+    let diff = TextDiff::from_lines(&current, &output.content);
+    write!(
+        io::stdout(),
+        "{}",
+        diff.unified_diff()
+            .header(&format!("a/{}", name), &format!("b/{}", name))
+    )?;
+
+    Ok(true)
 }
 
-fn main() -> io::Result<()> {
+fn dir_arg() -> Arg {
+    Arg::new("dir")
+        .help("Output directory path")
+        .short('d')
+        .long("dir")
+        .required(true)
+}
+
+fn target_arg() -> Arg {
+    Arg::new("target")
+        .help("Comma separated list of targets (defaults to all)")
+        .short('t')
+        .long("target")
+        .value_delimiter(',')
+        .value_parser(PossibleValuesParser::new(Target::ALL.map(Target::name)))
+}
+
+fn run() -> error::Result<()> {
     let matches = Command::new(crate_name!())
         .version(crate_version!())
         .author(crate_authors!())
+        .subcommand_required(true)
+        .arg(
+            Arg::new("highlights")
+                .help("Highlights file path (defaults to the bundled highlights.toml)")
+                .long("highlights")
+                .global(true),
+        )
         .arg(
-            Arg::new("dir")
-                .help("Output directory path")
-                .short('d')
-                .long("dir"),
+            Arg::new("palette")
+                .help("Palette file path (defaults to the bundled palette.toml)")
+                .short('p')
+                .long("palette")
+                .global(true),
+        )
+        .arg(
+            Arg::new("cterm-colors")
+                .help("Pick cterm colors from all 256, or only from the cube and grays (16-255)")
+                .long("cterm-colors")
+                .value_parser(PossibleValuesParser::new(
+                    CtermColors::ALL.map(CtermColors::name),
+                ))
+                .default_value("all")
+                .global(true),
+        )
+        .arg(
+            Arg::new("cterm-metric")
+                .help("Color difference cterm colors are matched by")
+                .long("cterm-metric")
+                .value_parser(PossibleValuesParser::new(Metric::ALL.map(Metric::name)))
+                .default_value("ciede2000")
+                .global(true),
+        )
+        .subcommand(
+            Command::new("generate")
+                .about("Write the generated files into a directory")
+                .arg(dir_arg())
+                .arg(target_arg())
+                .arg(
+                    Arg::new("check")
+                        .help("Don't write anything, exit with 1 if any file is out of date")
+                        .long("check")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("export")
+                .about("Print the generated files to stdout")
+                .arg(target_arg().default_value("vim")),
+        )
+        .subcommand(
+            Command::new("diff")
+                .about(
+                    "Show how the files in a directory differ from the generated ones, \
+                     exiting with status 1 if any do",
+                )
+                .arg(dir_arg())
+                .arg(target_arg()),
+        )
+        .subcommand(
+            Command::new("audit")
+                .about("Report highlight groups whose fg/bg contrast is below the thresholds")
+                .arg(
+                    Arg::new("min-contrast")
+                        .help("Minimum WCAG 2.x contrast ratio")
+                        .long("min-contrast")
+                        .value_parser(value_parser!(f64))
+                        .default_value("4.5"),
+                )
+                .arg(
+                    Arg::new("min-lc")
+                        .help("Minimum absolute APCA Lc")
+                        .long("min-lc")
+                        .value_parser(value_parser!(f64))
+                        .default_value("60"),
+                ),
+        )
+        .subcommand(
+            Command::new("cvd")
+                .about(
+                    "Report paired highlight groups that collapse with color vision deficiencies",
+                )
+                .arg(
+                    Arg::new("min-distance")
+                        .help("Minimum CIEDE2000 distance between paired colors")
+                        .long("min-distance")
+                        .value_parser(value_parser!(f32))
+                        .default_value("10"),
+                ),
+        )
+        .subcommand(
+            Command::new("gamut")
+                .about("Report derived palette entries whose deltas leave the valid range"),
+        )
+        .subcommand(
+            Command::new("graph")
+                .about("Print how the palette entries are derived from each other")
+                .arg(
+                    Arg::new("format")
+                        .help("Graph language")
+                        .short('f')
+                        .long("format")
+                        .value_parser(PossibleValuesParser::new(Format::ALL.map(Format::name)))
+                        .default_value("dot"),
+                )
+                .arg(
+                    Arg::new("from")
+                        .help("Only print this entry and the entries derived from it")
+                        .long("from"),
+                ),
+        )
+        .subcommand(
+            Command::new("preview")
+                .about("Print every highlight group styled with its own colors")
+                .arg(
+                    Arg::new("cterm")
+                        .help("Only show the 256-color approximations, without the 24-bit colors")
+                        .long("cterm")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("background")
+                        .help("Palette variant to preview")
+                        .short('b')
+                        .long("background")
+                        .value_parser(["dark", "light"])
+                        .default_value("dark"),
+                )
+                .arg(
+                    Arg::new("sample")
+                        .help("Print the code sample of a language instead of the groups")
+                        .long("sample")
+                        .value_parser(PossibleValuesParser::new(Language::ALL.map(Language::name))),
+                )
+                .arg(
+                    Arg::new("syntax")
+                        .help(
+                            "Draw the sample with the syntax groups instead of treesitter captures",
+                        )
+                        .long("syntax")
+                        .requires("sample")
+                        .action(ArgAction::SetTrue),
+                ),
         )
         .get_matches();
 
-    match matches.get_one::<String>("dir") {
-        Some(dir) => {
-            let dir = abs(PathBuf::from(dir))?;
-            let mut writer = Writer::new(get_palette(), get_highlights());
+    let cterm = CtermOptions {
+        colors: CtermColors::from_name(matches.get_one::<String>("cterm-colors").unwrap()).unwrap(),
+        metric: Metric::from_name(matches.get_one::<String>("cterm-metric").unwrap()).unwrap(),
+    };
+
+    let resolved = match matches.get_one::<String>("palette") {
+        Some(path) => load_palette(&abs(PathBuf::from(path))?, &cterm)?,
+        None => default_palette(&cterm)?,
+    };
+
+    // these only look at the palette, so the highlights don't need to match it
+    if matches.subcommand_matches("gamut").is_some() {
+        let clipped = gamut::report(io::stdout(), &resolved.clipped)?;
+        let total = resolved
+            .derivations
+            .iter()
+            .filter(|(_, d)| d.is_some())
+            .count();
+
+        println!("{} of {} derived entries clipped", clipped, total);
+
+        if clipped > 0 {
+            process::exit(1);
+        }
+
+        return Ok(());
+    }
 
-            let path = File::create(dir.join("colors/dogrun.vim"))?;
-            writer.write_colorscheme(io::BufWriter::new(path))?;
+    if let Some(matches) = matches.subcommand_matches("graph") {
+        let format = Format::from_name(matches.get_one::<String>("format").unwrap()).unwrap();
+        let from = matches.get_one::<String>("from").map(String::as_str);
+
+        let mut stdout = io::stdout();
+        graph::write(&mut stdout, format, &resolved, from)?;
+        stdout.flush()?;
+
+        return Ok(());
+    }
 
-            let path = File::create(dir.join("autoload/lightline/colorscheme/dogrun.vim"))?;
-            writer.write_lightline(io::BufWriter::new(path))?;
+    let light_palette = light_palette_with(&resolved, &cterm);
+    let ResolvedPalette {
+        colors: palette,
+        derivations,
+        ..
+    } = resolved;
+
+    // the bundled highlights may still reference colors missing from a
+    // custom palette
+    let highlights = match matches.get_one::<String>("highlights") {
+        Some(path) => load_highlights(&abs(PathBuf::from(path))?, &palette)?,
+        None => default_highlights(&palette)?,
+    };
+
+    let problems = validate(&palette, &highlights);
+    if !problems.is_empty() {
+        return Err(Error::Invalid(problems));
+    }
+
+    if let Some(matches) = matches.subcommand_matches("audit") {
+        let thresholds = Thresholds {
+            contrast: *matches.get_one::<f64>("min-contrast").unwrap(),
+            lc: *matches.get_one::<f64>("min-lc").unwrap(),
+        };
+
+        let mut failures = 0;
+        let mut total = 0;
+        for (background, palette) in [
+            (Background::Dark, &palette),
+            (Background::Light, &light_palette),
+        ] {
+            let measurements = audit::audit(palette, &highlights)?;
+            total += measurements.len();
+            failures += audit::report(io::stdout(), background, &measurements, &thresholds)?;
+        }
+
+        println!(
+            "{} of {} pairs below contrast {} / Lc {}",
+            failures, total, thresholds.contrast, thresholds.lc
+        );
+
+        if failures > 0 {
+            process::exit(1);
+        }
+
+        return Ok(());
+    }
+
+    if let Some(matches) = matches.subcommand_matches("cvd") {
+        let threshold = *matches.get_one::<f32>("min-distance").unwrap();
+
+        let (mut collisions, mut indistinct, mut unresolved) = (0, 0, 0);
+        for (background, palette) in [
+            (Background::Dark, &palette),
+            (Background::Light, &light_palette),
+        ] {
+            let findings = cvd::collisions(palette, &highlights, threshold)?;
+            cvd::report(io::stdout(), background, &findings)?;
+            collisions += findings.collisions.len();
+            indistinct += findings.indistinct.len();
+            unresolved += findings.unresolved.len();
+        }
+
+        println!(
+            "{} pairs closer than {} with a color vision deficiency",
+            collisions, threshold
+        );
+        if indistinct > 0 {
+            println!(
+                "{} pairs closer than {} with normal vision",
+                indistinct, threshold
+            );
+        }
+        if unresolved > 0 {
+            println!("{} pairs couldn't be compared", unresolved);
+        }
+
+        if collisions + indistinct + unresolved > 0 {
+            process::exit(1);
+        }
+
+        return Ok(());
+    }
 
-            let path = File::create(dir.join("autoload/clap/themes/dogrun.vim"))?;
-            writer.write_clap(io::BufWriter::new(path))?;
+    if let Some(matches) = matches.subcommand_matches("preview") {
+        let cterm = matches.get_flag("cterm");
 
-            let wezterm_dir = dir.join("wezterm");
-            create_dir_all(&wezterm_dir)?;
-            let path = File::create(wezterm_dir.join("dogrun.toml"))?;
-            writer.write_wezterm(io::BufWriter::new(path))?;
+        let palette = match matches.get_one::<String>("background").unwrap().as_str() {
+            "light" => light_palette,
+            _ => palette,
+        };
 
-            // Update README.md with generated fzf colors (if it exists)
-            let readme_path = dir.join("README.md");
-            if readme_path.exists() {
-                update_readme_fzf(&mut writer, &readme_path)?;
+        let mut out = io::stdout().lock();
+        match matches.get_one::<String>("sample") {
+            Some(name) => {
+                let language = Language::from_name(name).unwrap();
+                let groups = if matches.get_flag("syntax") {
+                    Groups::Syntax
+                } else {
+                    Groups::Treesitter
+                };
+
+                let mode = if cterm { Mode::Cterm } else { Mode::Gui };
+                sample::write_ansi(&mut out, &palette, &highlights, language, groups, mode)?;
+            }
+            None => {
+                let modes: &[Mode] = if cterm {
+                    &[Mode::Cterm]
+                } else {
+                    &[Mode::Gui, Mode::Cterm]
+                };
+                preview::write_preview(&mut out, &palette, &highlights, modes)?;
             }
         }
-        None => {
-            let mut writer = Writer::new(get_palette(), get_highlights());
-            writer.write_colorscheme(io::stdout())?;
-            writer.write_lightline(io::stdout())?;
-            writer.write_clap(io::stdout())?;
+        out.flush()?;
+
+        return Ok(());
+    }
+
+    let targets = |matches: &ArgMatches| -> Vec<Target> {
+        match matches.get_many::<String>("target") {
+            Some(names) => names.filter_map(|name| Target::from_name(name)).collect(),
+            None => Target::ALL.to_vec(),
         }
     };
 
+    let mut writer = Writer::new(palette, light_palette, derivations, highlights);
+
+    match matches.subcommand() {
+        Some(("generate", matches)) => {
+            let dir = abs(PathBuf::from(matches.get_one::<String>("dir").unwrap()))?;
+
+            let mut outputs = vec![];
+            for target in targets(matches) {
+                outputs.extend(writer.render(target, Some(&dir))?);
+            }
+
+            if matches.get_flag("check") {
+                let mut stale = 0;
+                for output in outputs.iter() {
+                    if print_diff(&dir, output)? {
+                        stale += 1;
+                    }
+                }
+
+                if stale > 0 {
+                    eprintln!(
+                        "{} generated file(s) out of date, run `dogrun generate` to update them",
+                        stale
+                    );
+                    process::exit(1);
+                }
+
+                return Ok(());
+            }
+
+            output::write_all(&dir, &outputs)?;
+        }
+        Some(("export", matches)) => {
+            for target in targets(matches) {
+                for output in writer.render(target, None)? {
+                    io::stdout().write_all(output.content.as_bytes())?;
+                }
+            }
+        }
+        Some(("diff", matches)) => {
+            let dir = abs(PathBuf::from(matches.get_one::<String>("dir").unwrap()))?;
+
+            let mut changed = false;
+            for target in targets(matches) {
+                for output in writer.render(target, Some(&dir))? {
+                    changed |= print_diff(&dir, &output)?;
+                }
+            }
+
+            // like diff(1), so scripts can tell whether anything changed
+            if changed {
+                process::exit(1);
+            }
+        }
+        _ => unreachable!(),
+    }
+
     Ok(())
 }
+
+fn main() {
+    match run() {
+        Ok(()) => {}
+        // the output was piped into e.g. `head`
+        Err(Error::Io(err)) if err.kind() == io::ErrorKind::BrokenPipe => {}
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dogrun::conv::Rgb;
+
+    fn color(gui: &str, cterm: u8) -> Color {
+        Color {
+            gui: Rgb::parse(gui).unwrap(),
+            cterm,
+        }
+    }
+
+    #[test]
+    fn test_clap_combined_attr() {
+        let (fg, bg) = (color("#929be5", 104), color("#2a2c3f", 236));
+        let attr = HighlightAttr::BOLD | HighlightAttr::ITALIC;
+
+        assert_eq!(
+            clap_palette_entry("input", &fg, Some(&bg), attr),
+            "  let s:palette.input = { 'ctermfg': '104', 'guifg': '#929be5', \
+             'ctermbg': '236', 'guibg': '#2a2c3f', 'gui': 'bold,italic', 'cterm': 'bold,italic' }"
+        );
+        assert_eq!(
+            clap_highlight("ClapMatches", &fg, None, attr),
+            "  hi ClapMatches guifg=#929be5 ctermfg=104 guibg=NONE ctermbg=NONE \
+             gui=bold,italic cterm=bold,italic"
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A generated file, with its path relative to the output directory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Output {
+    pub path: PathBuf,
+    pub content: String,
+}
+
+impl Output {
+    pub fn new(path: impl Into<PathBuf>, content: String) -> Self {
+        Self {
+            path: path.into(),
+            content,
+        }
+    }
+}
+
+/// Writes all outputs into `dir` as a single transaction: every file is
+/// first written to a temporary file next to its destination, then the
+/// temporary files are renamed over the destinations. If any step fails,
+/// the files written so far are restored and the directories created for
+/// the outputs are removed, so the tree is left as it was.
+pub fn write_all(dir: &Path, outputs: &[Output]) -> io::Result<()> {
+    let mut transaction = Transaction::default();
+
+    let result = transaction
+        .stage(dir, outputs)
+        .and_then(|_| transaction.commit());
+
+    if result.is_err() {
+        transaction.rollback();
+    }
+
+    result
+}
+
+/// A file staged for writing.
+struct Staged {
+    temp: PathBuf,
+    path: PathBuf,
+    /// The content of the destination before the transaction, if it existed.
+    original: Option<Vec<u8>>,
+    committed: bool,
+}
+
+#[derive(Default)]
+struct Transaction {
+    created_dirs: Vec<PathBuf>,
+    staged: Vec<Staged>,
+}
+
+impl Transaction {
+    fn stage(&mut self, dir: &Path, outputs: &[Output]) -> io::Result<()> {
+        for output in outputs {
+            let path = dir.join(&output.path);
+            let parent = path.parent().unwrap_or(dir);
+            self.create_dir_all(parent)?;
+
+            let original = match fs::read(&path) {
+                Ok(content) => Some(content),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+                Err(err) => return Err(err),
+            };
+
+            let file_name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let temp = parent.join(format!(".{}.dogrun-tmp", file_name));
+
+            fs::write(&temp, &output.content)?;
+
+            self.staged.push(Staged {
+                temp,
+                path,
+                original,
+                committed: false,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn commit(&mut self) -> io::Result<()> {
+        for staged in self.staged.iter_mut() {
+            fs::rename(&staged.temp, &staged.path)?;
+            staged.committed = true;
+        }
+
+        Ok(())
+    }
+
+    /// Best effort: errors are ignored since the original error is the one
+    /// worth reporting.
+    fn rollback(&mut self) {
+        for staged in self.staged.iter().rev() {
+            if !staged.committed {
+                let _ = fs::remove_file(&staged.temp);
+                continue;
+            }
+
+            let _ = match &staged.original {
+                Some(content) => fs::write(&staged.path, content),
+                None => fs::remove_file(&staged.path),
+            };
+        }
+
+        for dir in self.created_dirs.iter().rev() {
+            let _ = fs::remove_dir(dir);
+        }
+    }
+
+    /// Like `fs::create_dir_all`, but remembers the directories it creates.
+    fn create_dir_all(&mut self, dir: &Path) -> io::Result<()> {
+        if dir.is_dir() {
+            return Ok(());
+        }
+
+        if let Some(parent) = dir.parent() {
+            self.create_dir_all(parent)?;
+        }
+
+        fs::create_dir(dir)?;
+        self.created_dirs.push(dir.to_path_buf());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_write_all() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        fs::write(dir.join("a.txt"), "old").unwrap();
+
+        write_all(
+            dir,
+            &[
+                Output::new("a.txt", "a".into()),
+                Output::new("nested/dir/b.txt", "b".into()),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(fs::read_to_string(dir.join("a.txt")).unwrap(), "a");
+        assert_eq!(
+            fs::read_to_string(dir.join("nested/dir/b.txt")).unwrap(),
+            "b"
+        );
+        assert!(!dir.join(".a.txt.dogrun-tmp").exists());
+    }
+
+    #[test]
+    fn test_write_all_leaves_tree_untouched_on_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        fs::write(dir.join("a.txt"), "old").unwrap();
+        fs::write(dir.join("file"), "").unwrap();
+
+        let result = write_all(
+            dir,
+            &[
+                Output::new("a.txt", "a".into()),
+                Output::new("new/b.txt", "b".into()),
+                // a directory can't be created where a file exists
+                Output::new("file/c.txt", "c".into()),
+            ],
+        );
+
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(dir.join("a.txt")).unwrap(), "old");
+        assert!(!dir.join("new").exists());
+
+        let mut entries: Vec<_> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        entries.sort();
+        assert_eq!(entries, vec!["a.txt", "file"]);
+    }
+
+    #[test]
+    fn test_rollback_restores_committed_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        fs::write(dir.join("a.txt"), "old").unwrap();
+
+        let mut transaction = Transaction::default();
+        transaction
+            .stage(
+                dir,
+                &[
+                    Output::new("a.txt", "a".into()),
+                    Output::new("new/b.txt", "b".into()),
+                ],
+            )
+            .unwrap();
+        transaction.commit().unwrap();
+        transaction.rollback();
+
+        assert_eq!(fs::read_to_string(dir.join("a.txt")).unwrap(), "old");
+        assert!(!dir.join("new").exists());
+    }
+}
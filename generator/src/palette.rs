@@ -0,0 +1,967 @@
+use crate::conv::{self, Clip, CtermOptions, Rgb};
+use crate::error::{self, Error};
+use crate::highlight::{Color, Palette};
+use crate::parse::ParseError;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::de::{DeTable, DeValue};
+use toml::Spanned;
+
+/// The palette shipped with the generator.
+pub const DEFAULT_PALETTE: &str = include_str!("../palette.toml");
+
+enum Source {
+    Hex(Rgb),
+    Derived {
+        parent: String,
+        space: Space,
+        amounts: Option<[f64; 3]>,
+        blend: Option<Blend>,
+    },
+}
+
+struct Entry {
+    offset: usize,
+    source: Source,
+    /// A cterm color pinned in the file instead of the approximation.
+    cterm: Option<u8>,
+    /// Whether the light palette mirrors the lightness of the entry.
+    invert: bool,
+}
+
+/// The color space a derived entry applies its deltas in.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Space {
+    /// Hue, saturation and value. Saturation and value are clamped to 0-1.
+    #[default]
+    Hsv,
+    /// OKLCH lightness, chroma and hue, which change the perceived color
+    /// by about the same amount whatever the hue.
+    Oklch,
+}
+
+impl Space {
+    pub fn name(self) -> &'static str {
+        match self {
+            Space::Hsv => "hsv",
+            Space::Oklch => "oklch",
+        }
+    }
+
+    /// The names of the deltas, in the order of `Derivation::amounts`.
+    pub fn components(self) -> [&'static str; 3] {
+        match self {
+            Space::Hsv => ["h", "s", "v"],
+            Space::Oklch => ["l", "c", "h"],
+        }
+    }
+}
+
+/// Another entry a derived entry blends its parent with, before its deltas
+/// are applied.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Blend {
+    /// `parent.mix(color, t)`: `0.0` is the parent and `1.0` is `color`.
+    Mix { color: String, t: f64 },
+    /// The parent drawn with `alpha` opacity over `color`.
+    Over { color: String, alpha: f64 },
+}
+
+impl Blend {
+    /// The entry blended with the parent.
+    pub fn color(&self) -> &str {
+        match self {
+            Blend::Mix { color, .. } | Blend::Over { color, .. } => color,
+        }
+    }
+
+    fn apply(&self, parent: Rgb, color: Rgb) -> Rgb {
+        match self {
+            Blend::Mix { t, .. } => parent.mix(color, *t),
+            Blend::Over { alpha, .. } => parent.blend_over(color, *alpha),
+        }
+    }
+}
+
+/// How a derived palette entry is made from its parent. Deltas that are
+/// omitted in the file are `0.0`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Derivation {
+    pub parent: String,
+    pub space: Space,
+    /// The deltas of `space.components()`.
+    pub amounts: [f64; 3],
+    pub blend: Option<Blend>,
+}
+
+impl Derivation {
+    /// Formats the blend and the non-zero deltas, e.g. "s +0.05 v -0.15" or
+    /// "over mainbg alpha 0.08". The deltas are prefixed with the space
+    /// unless it is HSV, e.g. "oklch l -0.1".
+    pub fn deltas(&self) -> String {
+        let blend = self.blend.as_ref().map(|blend| match blend {
+            Blend::Mix { color, t } => format!("mix {} t {}", color, t),
+            Blend::Over { color, alpha } => format!("over {} alpha {}", color, alpha),
+        });
+        let prefix = match self.space {
+            Space::Hsv => None,
+            space => Some(space.name().to_string()),
+        };
+
+        blend
+            .into_iter()
+            .chain(prefix)
+            .chain(
+                self.space
+                    .components()
+                    .iter()
+                    .zip(self.amounts)
+                    .filter(|(_, delta)| *delta != 0.0)
+                    .map(|(name, delta)| format!("{} {:+}", name, delta)),
+            )
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// The entries of a palette file in file order, with the derivation of
+/// the derived ones.
+pub type Derivations = Vec<(String, Option<Derivation>)>;
+
+/// A derived entry whose deltas pushed components of its space out of their
+/// valid range, so they were clamped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clipped {
+    pub name: String,
+    pub space: Space,
+    pub clips: Vec<Clip>,
+}
+
+/// A parsed palette file: the colors of its entries, along with how they
+/// were derived and which of them were clamped.
+#[derive(Debug)]
+pub struct ResolvedPalette {
+    pub colors: Palette,
+    pub derivations: Derivations,
+    /// The clamped derived entries, in file order.
+    pub clipped: Vec<Clipped>,
+    /// The entries with `invert = false`, which the light palette keeps.
+    pub fixed: HashSet<String>,
+}
+
+/// Parses a palette file. See `palette.toml` for the format.
+pub fn parse_palette(source: &str) -> Result<Palette, ParseError> {
+    Ok(parse_palette_with(source, &CtermOptions::default())?.colors)
+}
+
+/// Parses a palette file, approximating the cterm colors that aren't
+/// pinned in the file with `options`.
+pub fn parse_palette_with(
+    source: &str,
+    options: &CtermOptions,
+) -> Result<ResolvedPalette, ParseError> {
+    let (names, entries) = parse_entries(source)?;
+    let (resolved, mut clipped) = resolve_all(source, &names, &entries)?;
+
+    let colors = resolved
+        .into_iter()
+        .map(|(name, rgb)| {
+            let cterm = entries[&name]
+                .cterm
+                .unwrap_or_else(|| conv::to_cterm_with(rgb, options));
+            let gui = rgb.round();
+            (name, Color { gui, cterm })
+        })
+        .collect();
+
+    let derivations = names
+        .iter()
+        .map(|name| {
+            let derivation = match &entries[name].source {
+                Source::Derived {
+                    parent,
+                    space,
+                    amounts,
+                    blend,
+                } => Some(Derivation {
+                    parent: parent.clone(),
+                    space: *space,
+                    amounts: amounts.unwrap_or_default(),
+                    blend: blend.clone(),
+                }),
+                Source::Hex(_) => None,
+            };
+            (name.clone(), derivation)
+        })
+        .collect();
+
+    let clipped = names
+        .iter()
+        .filter_map(|name| clipped.remove(name))
+        .collect();
+
+    let fixed = names
+        .into_iter()
+        .filter(|name| !entries[name].invert)
+        .collect();
+
+    Ok(ResolvedPalette {
+        colors,
+        derivations,
+        clipped,
+        fixed,
+    })
+}
+
+/// Derives the light background variant of a (dark) palette by mirroring
+/// the lightness of every entry except the fixed ones. Relationships between
+/// entries are kept, so a background that is slightly lighter than `mainbg`
+/// in the dark palette becomes slightly darker than `mainbg` in the light one.
+pub fn light_palette(palette: &ResolvedPalette) -> Palette {
+    light_palette_with(palette, &CtermOptions::default())
+}
+
+/// Derives the light palette, approximating its cterm colors with
+/// `options`. Cterm colors pinned in the palette file only apply to the
+/// dark palette and to fixed entries, since the other entries of the light
+/// one have other gui colors.
+pub fn light_palette_with(palette: &ResolvedPalette, options: &CtermOptions) -> Palette {
+    palette
+        .colors
+        .iter()
+        .map(|(name, color)| {
+            if palette.fixed.contains(name) {
+                return (name.clone(), *color);
+            }
+
+            let rgb = color.gui.invert_lightness();
+            let cterm = conv::to_cterm_with(rgb, options);
+            let gui = rgb.round();
+            (name.clone(), Color { gui, cterm })
+        })
+        .collect()
+}
+
+/// Returns the chain of derivations leading to `name`, starting with the
+/// base color, e.g. `[purple, darkpurple, defxiconpurple]`.
+pub fn derivation_chain<'a>(derivations: &'a Derivations, name: &'a str) -> Vec<&'a str> {
+    let mut chain = vec![name];
+
+    while let Some(Some(derivation)) = derivations
+        .iter()
+        .find(|(n, _)| n == chain[chain.len() - 1])
+        .map(|(_, derivation)| derivation)
+    {
+        if chain.contains(&derivation.parent.as_str()) {
+            break;
+        }
+        chain.push(&derivation.parent);
+    }
+
+    chain.reverse();
+    chain
+}
+
+/// Reads and parses a palette file.
+pub fn load_palette(path: &Path, options: &CtermOptions) -> error::Result<ResolvedPalette> {
+    let source = fs::read_to_string(path)?;
+
+    parse_palette_with(&source, options).map_err(|error| Error::Parse {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+/// Parses `palette.toml`.
+pub fn default_palette(options: &CtermOptions) -> error::Result<ResolvedPalette> {
+    parse_palette_with(DEFAULT_PALETTE, options).map_err(|error| Error::Parse {
+        path: PathBuf::from("palette.toml"),
+        error,
+    })
+}
+
+/// Looks up a palette entry, reporting `referenced_by` (a highlight group
+/// or a writer) when the entry is missing.
+pub fn lookup<'a>(
+    palette: &'a Palette,
+    name: &str,
+    referenced_by: &str,
+) -> error::Result<&'a Color> {
+    palette.get(name).ok_or_else(|| Error::UnknownColor {
+        name: name.to_string(),
+        referenced_by: referenced_by.to_string(),
+    })
+}
+
+fn parse_entries(source: &str) -> Result<(Vec<String>, HashMap<String, Entry>), ParseError> {
+    let table = DeTable::parse(source).map_err(|err| ParseError::from_toml(source, &err))?;
+
+    let mut names = vec![];
+    let mut entries = HashMap::new();
+
+    for (key, value) in table.get_ref().iter() {
+        let name = key.get_ref().to_string();
+        let entry = parse_entry(source, &name, value)?;
+        names.push(name.clone());
+        entries.insert(name, entry);
+    }
+
+    Ok((names, entries))
+}
+
+fn parse_entry(
+    source: &str,
+    name: &str,
+    value: &Spanned<DeValue<'_>>,
+) -> Result<Entry, ParseError> {
+    let offset = value.span().start;
+    let error = |offset: usize, message: String| ParseError::at(source, offset, message);
+
+    match value.get_ref() {
+        DeValue::String(hex) => match Rgb::parse(hex) {
+            Some(rgb) => Ok(Entry {
+                offset,
+                source: Source::Hex(rgb),
+                cterm: None,
+                invert: true,
+            }),
+            None => Err(error(
+                offset,
+                format!("\"{}\" has an invalid color \"{}\"", name, hex),
+            )),
+        },
+        DeValue::Table(table) => {
+            let mut gui = None;
+            let mut parent = None;
+            let mut space = None;
+            let mut deltas = vec![];
+            let (mut mix, mut t, mut over, mut alpha) = (None, None, None, None);
+            let mut cterm = None;
+            let mut invert = true;
+
+            for (key, value) in table.iter() {
+                let key_offset = key.span().start;
+
+                match key.get_ref().as_ref() {
+                    "gui" => match value.get_ref() {
+                        DeValue::String(hex) if Rgb::parse(hex).is_some() => gui = Rgb::parse(hex),
+                        _ => {
+                            return Err(error(
+                                value.span().start,
+                                format!("\"{}\": `gui` must be a hex string", name),
+                            ))
+                        }
+                    },
+                    "cterm" => {
+                        let id = parse_number(value.get_ref())
+                            .filter(|id| id.fract() == 0.0 && (0.0..=255.0).contains(id))
+                            .ok_or_else(|| {
+                                error(
+                                    value.span().start,
+                                    format!("\"{}\": `cterm` must be an integer in 0-255", name),
+                                )
+                            })?;
+                        cterm = Some(id as u8);
+                    }
+                    "invert" => match value.get_ref() {
+                        DeValue::Boolean(b) => invert = *b,
+                        _ => {
+                            return Err(error(
+                                value.span().start,
+                                format!("\"{}\": `invert` must be a boolean", name),
+                            ))
+                        }
+                    },
+                    "extends" => match value.get_ref() {
+                        DeValue::String(s) => parent = Some(s.to_string()),
+                        _ => {
+                            return Err(error(
+                                value.span().start,
+                                format!("\"{}\": `extends` must be a color name", name),
+                            ))
+                        }
+                    },
+                    key @ ("mix" | "over") => match value.get_ref() {
+                        DeValue::String(s) if key == "mix" => mix = Some(s.to_string()),
+                        DeValue::String(s) => over = Some(s.to_string()),
+                        _ => {
+                            return Err(error(
+                                value.span().start,
+                                format!("\"{}\": `{}` must be a color name", name, key),
+                            ))
+                        }
+                    },
+                    key @ ("t" | "alpha") => {
+                        let amount = parse_number(value.get_ref())
+                            .filter(|amount| (0.0..=1.0).contains(amount))
+                            .ok_or_else(|| {
+                                error(
+                                    value.span().start,
+                                    format!("\"{}\": `{}` must be a number in 0-1", name, key),
+                                )
+                            })?;
+
+                        match key {
+                            "t" => t = Some(amount),
+                            _ => alpha = Some(amount),
+                        }
+                    }
+                    "space" => {
+                        space = match value.get_ref() {
+                            DeValue::String(s) if s == "hsv" => Some(Space::Hsv),
+                            DeValue::String(s) if s == "oklch" => Some(Space::Oklch),
+                            _ => {
+                                return Err(error(
+                                    value.span().start,
+                                    format!("\"{}\": `space` must be \"hsv\" or \"oklch\"", name),
+                                ))
+                            }
+                        }
+                    }
+                    delta @ ("h" | "s" | "v" | "l" | "c") => {
+                        let amount = parse_number(value.get_ref()).ok_or_else(|| {
+                            error(
+                                value.span().start,
+                                format!("\"{}\": `{}` must be a number", name, delta),
+                            )
+                        })?;
+
+                        deltas.push((delta.to_string(), amount, key_offset));
+                    }
+                    other => {
+                        return Err(error(
+                            key_offset,
+                            format!("\"{}\": unknown key `{}`", name, other),
+                        ))
+                    }
+                }
+            }
+
+            // the space may come after the deltas, so they are checked last
+            let mut amounts = None;
+            for (delta, amount, key_offset) in deltas.iter() {
+                let space = space.unwrap_or_default();
+                let Some(i) = space.components().iter().position(|c| c == delta) else {
+                    return Err(error(
+                        *key_offset,
+                        format!(
+                            "\"{}\": `{}` isn't a delta of the {} space",
+                            name,
+                            delta,
+                            space.name()
+                        ),
+                    ));
+                };
+                amounts.get_or_insert([0.0; 3])[i] = *amount;
+            }
+
+            // a blend needs both its color and its amount
+            let pair = |keys: [&str; 2], color: Option<String>, amount: Option<f64>| {
+                let [key, missing] = match (&color, amount) {
+                    (Some(_), None) => keys,
+                    (None, Some(_)) => [keys[1], keys[0]],
+                    _ => return Ok(color.zip(amount)),
+                };
+                Err(error(
+                    offset,
+                    format!("\"{}\": `{}` needs `{}`", name, key, missing),
+                ))
+            };
+            let blend = match (
+                pair(["mix", "t"], mix, t)?,
+                pair(["over", "alpha"], over, alpha)?,
+            ) {
+                (None, None) => None,
+                (Some((color, t)), None) => Some(Blend::Mix { color, t }),
+                (None, Some((color, alpha))) => Some(Blend::Over { color, alpha }),
+                (Some(_), Some(_)) => {
+                    return Err(error(
+                        offset,
+                        format!("\"{}\": `mix` can't be combined with `over`", name),
+                    ))
+                }
+            };
+
+            let source = match (gui, parent) {
+                (Some(hex), None) if deltas.is_empty() && blend.is_none() && space.is_none() => {
+                    Source::Hex(hex)
+                }
+                (Some(_), _) => {
+                    return Err(error(
+                        offset,
+                        format!(
+                            "\"{}\": `gui` can't be combined with `extends`, `space`, \
+                             deltas or blends",
+                            name
+                        ),
+                    ))
+                }
+                (None, Some(parent)) => Source::Derived {
+                    parent,
+                    space: space.unwrap_or_default(),
+                    amounts,
+                    blend,
+                },
+                (None, None) => {
+                    return Err(error(
+                        offset,
+                        format!("\"{}\": missing `extends` or `gui`", name),
+                    ))
+                }
+            };
+
+            Ok(Entry {
+                offset,
+                source,
+                cterm,
+                invert,
+            })
+        }
+        _ => Err(error(
+            offset,
+            format!("\"{}\" must be a hex string or a table", name),
+        )),
+    }
+}
+
+fn parse_number(value: &DeValue<'_>) -> Option<f64> {
+    match value {
+        DeValue::Float(f) => f.as_str().parse().ok(),
+        DeValue::Integer(i) => i64::from_str_radix(i.as_str(), i.radix())
+            .ok()
+            .map(|i| i as f64),
+        _ => None,
+    }
+}
+
+type Resolved = (HashMap<String, Rgb>, HashMap<String, Clipped>);
+
+fn resolve_all(
+    source: &str,
+    names: &[String],
+    entries: &HashMap<String, Entry>,
+) -> Result<Resolved, ParseError> {
+    let mut resolved = HashMap::new();
+    let mut clipped = HashMap::new();
+
+    for name in names.iter() {
+        resolve(
+            source,
+            name,
+            entries,
+            &mut resolved,
+            &mut clipped,
+            &mut vec![],
+        )?;
+    }
+
+    Ok((resolved, clipped))
+}
+
+fn resolve(
+    source: &str,
+    name: &str,
+    entries: &HashMap<String, Entry>,
+    resolved: &mut HashMap<String, Rgb>,
+    clipped: &mut HashMap<String, Clipped>,
+    visiting: &mut Vec<String>,
+) -> Result<Rgb, ParseError> {
+    if let Some(rgb) = resolved.get(name) {
+        return Ok(*rgb);
+    }
+
+    let entry = &entries[name];
+
+    if visiting.iter().any(|n| n == name) {
+        visiting.push(name.to_string());
+        return Err(ParseError::at(
+            source,
+            entry.offset,
+            format!("circular extends: {}", visiting.join(" -> ")),
+        ));
+    }
+
+    let rgb = match &entry.source {
+        Source::Hex(rgb) => *rgb,
+        Source::Derived {
+            parent,
+            space,
+            amounts,
+            blend,
+        } => {
+            if !entries.contains_key(parent) {
+                return Err(ParseError::at(
+                    source,
+                    entry.offset,
+                    format!("\"{}\" extends unknown color \"{}\"", name, parent),
+                ));
+            }
+            if let Some(color) = blend.as_ref().map(Blend::color) {
+                if !entries.contains_key(color) {
+                    return Err(ParseError::at(
+                        source,
+                        entry.offset,
+                        format!("\"{}\" blends unknown color \"{}\"", name, color),
+                    ));
+                }
+            }
+
+            visiting.push(name.to_string());
+            let mut base = resolve(source, parent, entries, resolved, clipped, visiting)?;
+            if let Some(blend) = blend {
+                let color = resolve(source, blend.color(), entries, resolved, clipped, visiting)?;
+                base = blend.apply(base, color);
+            }
+            visiting.pop();
+
+            let (rgb, clips) = match (space, amounts) {
+                (Space::Hsv, Some([h, s, v])) => base.shift_hsv(*h, *s, *v),
+                (Space::Oklch, Some([l, c, h])) => base.shift_oklch(*l, *c, *h),
+                (_, None) => (base, vec![]),
+            };
+
+            if !clips.is_empty() {
+                clipped.insert(
+                    name.to_string(),
+                    Clipped {
+                        name: name.to_string(),
+                        space: *space,
+                        clips,
+                    },
+                );
+            }
+
+            rgb
+        }
+    };
+
+    resolved.insert(name.to_string(), rgb);
+
+    Ok(rgb)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_palette() {
+        let palette = parse_palette(
+            r##"
+red = "#FF0000"
+alias = { extends = "red" }
+darkred = { extends = "red", v = -0.2 }
+cyan = { extends = "red", h = 180 }
+"##,
+        )
+        .unwrap();
+
+        assert_eq!(palette["red"].gui.to_string(), "#ff0000");
+        assert_eq!(palette["alias"].gui.to_string(), "#ff0000");
+        assert_eq!(
+            palette["darkred"].gui,
+            Rgb::new(1.0, 0.0, 0.0).darken(0.2).round()
+        );
+        assert_eq!(palette["cyan"].gui.to_string(), "#00ffff");
+        assert_eq!(palette["cyan"].cterm, 14);
+    }
+
+    #[test]
+    fn test_parse_palette_oklch() {
+        let palette = parse_palette(
+            r##"
+purple = "#929be5"
+teal = "#73c1a9"
+orange = "#ac8b83"
+darkpurple = { extends = "purple", space = "oklch", l = -0.15 }
+darkteal = { extends = "teal", space = "oklch", l = -0.15 }
+darkorange = { extends = "orange", space = "oklch", l = -0.15 }
+alias = { extends = "teal", space = "oklch" }
+"##,
+        )
+        .unwrap();
+
+        for (base, derived) in [
+            ("purple", "darkpurple"),
+            ("teal", "darkteal"),
+            ("orange", "darkorange"),
+        ] {
+            let base = palette[base].gui.to_oklch();
+            let derived_lch = palette[derived].gui.to_oklch();
+            assert!(
+                (derived_lch.l - (base.l - 0.15)).abs() < 0.01,
+                "{}",
+                derived
+            );
+            assert!((derived_lch.c - base.c).abs() < 0.01, "{}", derived);
+            assert!((derived_lch.h - base.h).abs() < 2.0, "{}", derived);
+        }
+
+        assert_eq!(palette["alias"].gui, palette["teal"].gui);
+    }
+
+    #[test]
+    fn test_parse_palette_blend() {
+        let palette = parse_palette(
+            r##"
+bg = "#000000"
+red = "#ff0000"
+blue = "#0000ff"
+redbg = { extends = "red", over = "bg", alpha = 0.2 }
+purple = { extends = "red", mix = "blue", t = 0.5 }
+darkredbg = { extends = "red", over = "bg", alpha = 0.4, v = -0.2 }
+"##,
+        )
+        .unwrap();
+
+        assert_eq!(palette["redbg"].gui.to_string(), "#330000");
+        assert_eq!(palette["purple"].gui.to_string(), "#800080");
+        assert_eq!(palette["darkredbg"].gui.to_string(), "#330000");
+    }
+
+    #[test]
+    fn test_parse_palette_cterm() {
+        let source = r##"
+red = { gui = "#ff0000", cterm = 160 }
+darkred = { extends = "red", v = -0.2 }
+black = "#000000"
+white = { gui = "#ffffff", cterm = 15, invert = false }
+"##;
+
+        let palette = parse_palette(source).unwrap();
+        assert_eq!(palette["red"].gui.to_string(), "#ff0000");
+        assert_eq!(palette["red"].cterm, 160);
+        assert_eq!(palette["black"].cterm, 0);
+
+        let cube = CtermOptions {
+            colors: conv::CtermColors::Cube,
+            ..CtermOptions::default()
+        };
+        let resolved = parse_palette_with(source, &cube).unwrap();
+        assert_eq!(resolved.colors["red"].cterm, 160);
+        assert_eq!(resolved.colors["black"].cterm, 16);
+        let light = light_palette_with(&resolved, &cube);
+        assert_eq!(light["black"].cterm, 231);
+        // fixed entries keep their pinned cterm color
+        assert_eq!(light["white"].cterm, 15);
+        assert_eq!(resolved.derivations[0], ("red".to_string(), None));
+    }
+
+    #[test]
+    fn test_lookup() {
+        let palette = parse_palette(r##"red = "#ff0000""##).unwrap();
+
+        assert_eq!(
+            lookup(&palette, "red", "test").unwrap().gui.to_string(),
+            "#ff0000"
+        );
+        assert_eq!(
+            lookup(&palette, "blue", "highlight Normal")
+                .unwrap_err()
+                .to_string(),
+            "unknown color \"blue\" referenced by highlight Normal"
+        );
+    }
+
+    #[test]
+    fn test_parse_derivations() {
+        let ResolvedPalette { derivations, .. } = parse_palette_with(
+            r##"
+red = "#ff0000"
+darkred = { extends = "red", v = -0.2 }
+darkestred = { extends = "darkred", s = 0.05, v = -0.1 }
+alias = { extends = "red" }
+shiftedred = { extends = "red", h = 20, l = -0.1, space = "oklch" }
+redbg = { extends = "red", over = "bg", alpha = 0.1, s = -0.1 }
+bg = "#000000"
+"##,
+            &CtermOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(derivations[0], ("red".to_string(), None));
+        assert_eq!(
+            derivations[2].1,
+            Some(Derivation {
+                parent: "darkred".into(),
+                space: Space::Hsv,
+                amounts: [0.0, 0.05, -0.1],
+                blend: None,
+            })
+        );
+        assert_eq!(
+            derivations[2].1.as_ref().unwrap().deltas(),
+            "s +0.05 v -0.1"
+        );
+        assert_eq!(derivations[3].1.as_ref().unwrap().deltas(), "");
+        assert_eq!(
+            derivations[4].1,
+            Some(Derivation {
+                parent: "red".into(),
+                space: Space::Oklch,
+                amounts: [-0.1, 0.0, 20.0],
+                blend: None,
+            })
+        );
+        assert_eq!(
+            derivations[4].1.as_ref().unwrap().deltas(),
+            "oklch l -0.1 h +20"
+        );
+        assert_eq!(
+            derivations[5].1.as_ref().unwrap().blend,
+            Some(Blend::Over {
+                color: "bg".into(),
+                alpha: 0.1
+            })
+        );
+        assert_eq!(
+            derivations[5].1.as_ref().unwrap().deltas(),
+            "over bg alpha 0.1 s -0.1"
+        );
+        assert_eq!(
+            derivation_chain(&derivations, "redbg"),
+            vec!["red", "redbg"]
+        );
+        assert_eq!(
+            derivation_chain(&derivations, "darkestred"),
+            vec!["red", "darkred", "darkestred"]
+        );
+        assert_eq!(derivation_chain(&derivations, "red"), vec!["red"]);
+    }
+
+    #[test]
+    fn test_parse_palette_in_any_order() {
+        let palette = parse_palette(
+            r##"
+child = { extends = "parent" }
+parent = "#123456"
+"##,
+        )
+        .unwrap();
+
+        assert_eq!(palette["child"].gui.to_string(), "#123456");
+    }
+
+    #[test]
+    fn test_parse_palette_errors() {
+        let cases = [
+            ("red = \"#ff00\"", 1, "invalid color"),
+            (
+                "red = \"#ff0000\"\n\nfoo = { extends = \"bar\" }",
+                3,
+                "unknown color \"bar\"",
+            ),
+            ("red = { v = 0.1 }", 1, "missing `extends`"),
+            (
+                "red = \"#ff0000\"\nfoo = { extends = \"red\", x = 1 }",
+                2,
+                "unknown key `x`",
+            ),
+            (
+                "red = \"#ff0000\"\nfoo = { extends = \"red\", l = 1 }",
+                2,
+                "`l` isn't a delta of the hsv space",
+            ),
+            (
+                "red = \"#ff0000\"\nfoo = { extends = \"red\", s = 1, space = \"oklch\" }",
+                2,
+                "`s` isn't a delta of the oklch space",
+            ),
+            (
+                "red = \"#ff0000\"\nfoo = { extends = \"red\", space = \"lab\" }",
+                2,
+                "`space` must be \"hsv\" or \"oklch\"",
+            ),
+            (
+                "red = \"#ff0000\"\nfoo = { extends = \"red\", v = \"x\" }",
+                2,
+                "`v` must be a number",
+            ),
+            (
+                "a = { extends = \"b\" }\nb = { extends = \"a\" }",
+                1,
+                "circular extends: a -> b -> a",
+            ),
+            (
+                "red = \"#ff0000\"\nfoo = { extends = \"red\", over = \"bar\", alpha = 0.5 }",
+                2,
+                "blends unknown color \"bar\"",
+            ),
+            (
+                "red = \"#ff0000\"\nfoo = { extends = \"red\", mix = \"red\" }",
+                2,
+                "`mix` needs `t`",
+            ),
+            (
+                "red = \"#ff0000\"\nfoo = { extends = \"red\", alpha = 0.5 }",
+                2,
+                "`alpha` needs `over`",
+            ),
+            (
+                "red = \"#ff0000\"\nfoo = { extends = \"red\", over = \"red\", alpha = 2 }",
+                2,
+                "`alpha` must be a number in 0-1",
+            ),
+            (
+                "r = \"#ff0000\"\nf = { extends = \"r\", mix = \"r\", t = 1, over = \"r\", alpha = 1 }",
+                2,
+                "`mix` can't be combined with `over`",
+            ),
+            (
+                "c = \"#ff0000\"\na = { extends = \"b\" }\nb = { extends = \"c\", mix = \"a\", t = 1 }",
+                2,
+                "circular extends: a -> b -> a",
+            ),
+            ("red = 1", 1, "must be a hex string"),
+            ("red = { gui = \"red\" }", 1, "`gui` must be a hex string"),
+            (
+                "red = { gui = \"#ff0000\", invert = 0 }",
+                1,
+                "`invert` must be a boolean",
+            ),
+            (
+                "red = { gui = \"#ff0000\", cterm = 256 }",
+                1,
+                "`cterm` must be an integer in 0-255",
+            ),
+            (
+                "red = \"#ff0000\"\nfoo = { gui = \"#ff0000\", extends = \"red\" }",
+                2,
+                "`gui` can't be combined",
+            ),
+            ("red = \"#ff0000\"\nred = \"#00ff00\"", 2, "duplicate key"),
+        ];
+
+        for (source, line, message) in cases {
+            let err = parse_palette(source).unwrap_err();
+            assert_eq!(err.line, line, "{}", source);
+            assert!(err.message.contains(message), "{}: {}", source, err);
+        }
+    }
+
+    #[test]
+    fn test_light_palette() {
+        let dark = parse_palette_with(
+            r##"
+bg = "#000000"
+fg = "#ffffff"
+black = { gui = "#000000", invert = false }
+"##,
+            &CtermOptions::default(),
+        )
+        .unwrap();
+        let light = light_palette(&dark);
+
+        assert_eq!(light["bg"].gui.to_string(), "#ffffff");
+        assert_eq!(light["bg"].cterm, 15);
+        assert_eq!(light["fg"].gui.to_string(), "#000000");
+        assert_eq!(light["black"].gui.to_string(), "#000000");
+        assert_eq!(light["black"].cterm, 0);
+    }
+
+    #[test]
+    fn test_default_palette() {
+        assert!(parse_palette(DEFAULT_PALETTE).is_ok());
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt;
+
+/// An error found while reading one of the generator's data files.
+/// `line` is 1-based and points at the entry that caused the error.
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error located at the byte `offset` of `source`.
+    pub fn at(source: &str, offset: usize, message: impl Into<String>) -> Self {
+        Self {
+            line: line_of(source, offset),
+            message: message.into(),
+        }
+    }
+
+    /// Converts a TOML syntax error into a located error.
+    pub fn from_toml(source: &str, err: &toml::de::Error) -> Self {
+        let offset = err.span().map(|span| span.start).unwrap_or(0);
+        Self::at(source, offset, err.message().trim_end())
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Returns the 1-based line number of the byte `offset` in `source`.
+pub fn line_of(source: &str, offset: usize) -> usize {
+    let offset = offset.min(source.len());
+    source.as_bytes()[..offset]
+        .iter()
+        .filter(|&&b| b == b'\n')
+        .count()
+        + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_of() {
+        let source = "a = 1\nb = 2\n\nc = 3";
+        assert_eq!(line_of(source, 0), 1);
+        assert_eq!(line_of(source, 6), 2);
+        assert_eq!(line_of(source, 13), 4);
+        assert_eq!(line_of(source, 100), 4);
+    }
+}
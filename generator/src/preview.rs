@@ -0,0 +1,101 @@
+use crate::error;
+use crate::highlight::{Highlight, Palette};
+use crate::style::{sgr, Mode, Styles};
+use std::io;
+
+/// Prints every highlight group name styled with its own colors and
+/// attributes, once per mode side by side: as 24-bit escapes for
+/// `Mode::Gui` and as 256-color ones for `Mode::Cterm`. Links are styled as
+/// their target, and groups without a fg or bg (or a "NONE" one) fall back
+/// to `Normal`, as they would in the editor.
+pub fn write_preview<W: io::Write>(
+    mut out: W,
+    palette: &Palette,
+    highlights: &[Highlight],
+    modes: &[Mode],
+) -> error::Result<()> {
+    let styles = Styles::new(palette, highlights);
+    let width = highlights.iter().map(|hl| hl.name.len()).max().unwrap_or(0);
+
+    for hl in highlights.iter() {
+        let style = styles.get(&hl.name)?;
+        let columns: Vec<_> = modes
+            .iter()
+            .map(|mode| {
+                format!(
+                    "{} {:<width$} \x1b[0m",
+                    sgr(&style, *mode),
+                    hl.name,
+                    width = width
+                )
+            })
+            .collect();
+        write!(out, "{}", columns.join(" "))?;
+
+        match &hl.link {
+            Some(link) => writeln!(out, " -> {}", link)?,
+            None => writeln!(out)?,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::highlight::parse_highlights;
+    use crate::palette::parse_palette;
+
+    #[test]
+    fn test_write_preview() {
+        let palette = parse_palette(
+            r##"
+mainfg = "#ffffff"
+mainbg = "#000000"
+red = "#ff0000"
+"##,
+        )
+        .unwrap();
+        let highlights = parse_highlights(
+            r#"
+[[section]]
+highlights = [
+  { name = "Normal", fg = "mainfg", bg = "mainbg" },
+  { name = "Error", fg = "red", attr = "bold,undercurl" },
+  { name = "Todo", link = "Error" },
+]
+"#,
+            &palette,
+        )
+        .unwrap();
+
+        let render = |modes: &[Mode]| {
+            let mut buf = vec![];
+            write_preview(&mut buf, &palette, &highlights, modes).unwrap();
+            String::from_utf8(buf).unwrap()
+        };
+
+        let lines: Vec<String> = render(&[Mode::Gui]).lines().map(String::from).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "\x1b[0;38;2;255;255;255;48;2;0;0;0m Normal \x1b[0m",
+                "\x1b[0;38;2;255;0;0;48;2;0;0;0;1;4:3m Error  \x1b[0m",
+                "\x1b[0;38;2;255;0;0;48;2;0;0;0;1;4:3m Todo   \x1b[0m -> Error",
+            ]
+        );
+
+        // the 24-bit and the 256-color versions of a group share its line
+        let lines: Vec<String> = render(&[Mode::Gui, Mode::Cterm])
+            .lines()
+            .map(String::from)
+            .collect();
+        assert_eq!(
+            lines[1],
+            "\x1b[0;38;2;255;0;0;48;2;0;0;0;1;4:3m Error  \x1b[0m \
+             \x1b[0;38;5;9;48;5;0;1;4:3m Error  \x1b[0m"
+        );
+        assert!(lines[2].ends_with("\x1b[0;38;5;9;48;5;0;1;4:3m Todo   \x1b[0m -> Error"));
+    }
+}
@@ -0,0 +1,687 @@
+use crate::error;
+use crate::highlight::{Background, Highlight, Palette};
+use crate::style::{css, escape, sgr, Mode, Styles};
+use std::io;
+
+/// A language a sample source file is written in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    Rust,
+    TypeScript,
+    Python,
+    Markdown,
+}
+
+impl Language {
+    pub const ALL: [Language; 4] = [
+        Language::Rust,
+        Language::TypeScript,
+        Language::Python,
+        Language::Markdown,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::Rust => "rust",
+            Language::TypeScript => "typescript",
+            Language::Python => "python",
+            Language::Markdown => "markdown",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|language| language.name() == name)
+    }
+
+    /// The sample source file of the language.
+    pub fn source(self) -> &'static str {
+        match self {
+            Language::Rust => include_str!("../samples/sample.rs"),
+            Language::TypeScript => include_str!("../samples/sample.ts"),
+            Language::Python => include_str!("../samples/sample.py"),
+            Language::Markdown => include_str!("../samples/sample.md"),
+        }
+    }
+}
+
+/// Which highlight groups tokens are drawn with: the standard syntax groups
+/// Vim uses, or the treesitter captures of Neovim.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Groups {
+    Syntax,
+    Treesitter,
+}
+
+impl Groups {
+    pub fn name(self) -> &'static str {
+        match self {
+            Groups::Syntax => "syntax",
+            Groups::Treesitter => "treesitter",
+        }
+    }
+}
+
+/// The kind of a token.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Class {
+    Text,
+    Comment,
+    Keyword,
+    Include,
+    String,
+    Number,
+    Boolean,
+    Constant,
+    Function,
+    Macro,
+    Type,
+    TypeBuiltin,
+    Attribute,
+    Variable,
+    VariableBuiltin,
+    Property,
+    Operator,
+    Punctuation,
+    Heading,
+    Strong,
+    Emphasis,
+    Code,
+    Link,
+    Url,
+    Quote,
+    ListMarker,
+}
+
+impl Class {
+    /// The groups a token may be drawn with, most specific first. The
+    /// treesitter captures come first, followed by the syntax group Neovim
+    /// links them to by default and the group Vim links that one to.
+    fn candidates(self) -> &'static [&'static str] {
+        match self {
+            Class::Text => &[],
+            Class::Comment => &["@comment", "Comment"],
+            Class::Keyword => &["@keyword", "Keyword", "Statement"],
+            Class::Include => &["@include", "Include", "PreProc"],
+            Class::String => &["@string", "String", "Constant"],
+            Class::Number => &["@number", "Number", "Constant"],
+            Class::Boolean => &["@boolean", "Boolean", "Constant"],
+            Class::Constant => &["@constant.builtin", "Constant"],
+            Class::Function => &["@function", "Function", "Identifier"],
+            Class::Macro => &["@function.macro", "Macro", "PreProc"],
+            Class::Type => &["@type", "Type"],
+            Class::TypeBuiltin => &["@type.builtin", "Type"],
+            Class::Attribute => &["@attribute", "PreProc"],
+            Class::Variable => &["@variable"],
+            Class::VariableBuiltin => &["@variable.builtin", "Identifier"],
+            Class::Property => &["@property", "Identifier"],
+            Class::Operator => &["@operator", "Operator", "Statement"],
+            Class::Punctuation => &["@punctuation.delimiter", "Delimiter", "Special"],
+            Class::Heading => &["@markup.heading", "Title"],
+            Class::Strong => &["@markup.strong", "htmlBold"],
+            Class::Emphasis => &["@markup.italic", "htmlItalic"],
+            Class::Code => &["@markup.raw", "String"],
+            Class::Link => &["@markup.link", "Underlined"],
+            Class::Url => &["@markup.link.url", "Underlined"],
+            Class::Quote => &["@markup.quote", "Comment"],
+            Class::ListMarker => &["@markup.list", "Statement"],
+        }
+    }
+
+    /// Returns the first of the candidate groups defined in `highlights`,
+    /// or `Normal`.
+    pub fn group(self, groups: Groups, highlights: &[Highlight]) -> &'static str {
+        self.candidates()
+            .iter()
+            .filter(|name| groups == Groups::Treesitter || !name.starts_with('@'))
+            .find(|name| highlights.iter().any(|hl| hl.name == **name))
+            .copied()
+            .unwrap_or("Normal")
+    }
+}
+
+/// Writes a sample as an HTML page with a block for each kind of groups.
+/// Tokens are tagged with their group and the colors of the groups are
+/// CSS rules, so changing the palette only changes the style sheet while
+/// changing which group a token gets only changes the markup. The dark
+/// palette is shown unless the "light" checkbox is checked.
+pub fn write_html<W: io::Write>(
+    mut out: W,
+    palettes: &[(Background, &Palette)],
+    highlights: &[Highlight],
+    language: Language,
+) -> error::Result<()> {
+    let tokens = tokenize(language, language.source());
+
+    // the groups the tokens are drawn with, in order of appearance
+    let mut groups = vec!["Normal"];
+    for kind in [Groups::Treesitter, Groups::Syntax] {
+        for (class, _) in tokens.iter() {
+            let group = class.group(kind, highlights);
+            if !groups.contains(&group) {
+                groups.push(group);
+            }
+        }
+    }
+
+    writeln!(
+        out,
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>dogrun sample: {}</title>
+<style>
+body {{ margin: 2rem; font-family: ui-monospace, SFMono-Regular, Menlo, Consolas, monospace; font-size: 14px; }}
+pre {{ padding: 1rem 1.5rem; line-height: 1.5; }}"#,
+        language.name()
+    )?;
+
+    for (i, (background, palette)) in palettes.iter().enumerate() {
+        let styles = Styles::new(palette, highlights);
+        let scope = if i == 0 {
+            "main".to_string()
+        } else {
+            format!("#{}:checked ~ main", background)
+        };
+
+        writeln!(out, "/* {} */", background)?;
+        writeln!(out, "{} pre {{ {} }}", scope, css(&styles.get("Normal")?))?;
+        for group in groups.iter().skip(1) {
+            writeln!(
+                out,
+                "{} [data-group=\"{}\"] {{ {} }}",
+                scope,
+                group,
+                css(&styles.get(group)?)
+            )?;
+        }
+    }
+
+    writeln!(out, "</style>")?;
+    writeln!(out, "</head>")?;
+    writeln!(out, "<body>")?;
+
+    for (background, _) in palettes.iter().skip(1) {
+        writeln!(
+            out,
+            r#"<input type="checkbox" id="{}"><label for="{}">{}</label>"#,
+            background, background, background
+        )?;
+    }
+
+    writeln!(out, "<main>")?;
+    for kind in [Groups::Treesitter, Groups::Syntax] {
+        writeln!(out, "<h2>{}</h2>", kind.name())?;
+        write!(out, "<pre>")?;
+        for (class, text) in tokens.iter() {
+            match class.group(kind, highlights) {
+                "Normal" => write!(out, "{}", escape(text))?,
+                group => write!(
+                    out,
+                    "<span data-group=\"{}\">{}</span>",
+                    group,
+                    escape(text)
+                )?,
+            }
+        }
+        writeln!(out, "</pre>")?;
+    }
+    writeln!(out, "</main>")?;
+    writeln!(out, "</body>")?;
+    writeln!(out, "</html>")?;
+
+    Ok(())
+}
+
+/// Prints a sample with ANSI escapes, like `preview` does for the groups.
+pub fn write_ansi<W: io::Write>(
+    mut out: W,
+    palette: &Palette,
+    highlights: &[Highlight],
+    language: Language,
+    groups: Groups,
+    mode: Mode,
+) -> error::Result<()> {
+    let styles = Styles::new(palette, highlights);
+    let mut current = String::new();
+
+    for (class, text) in tokenize(language, language.source()) {
+        let style = sgr(&styles.get(class.group(groups, highlights))?, mode);
+
+        // reset at the end of lines so the background doesn't bleed
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                if !current.is_empty() {
+                    write!(out, "\x1b[0m")?;
+                    current.clear();
+                }
+                writeln!(out)?;
+            }
+            if line.is_empty() {
+                continue;
+            }
+            if style != current {
+                write!(out, "{}", style)?;
+                current.clone_from(&style);
+            }
+            write!(out, "{}", line)?;
+        }
+    }
+    if !current.is_empty() {
+        write!(out, "\x1b[0m")?;
+    }
+
+    Ok(())
+}
+
+/// Keywords and names with a meaning of their own in a language.
+struct Grammar {
+    line_comment: &'static str,
+    block_comment: Option<(&'static str, &'static str)>,
+    quotes: &'static [char],
+    /// Starts an attribute (`#[`) or a decorator (`@`).
+    attribute: &'static str,
+    keywords: &'static [&'static str],
+    includes: &'static [&'static str],
+    booleans: &'static [&'static str],
+    constants: &'static [&'static str],
+    types: &'static [&'static str],
+    variables: &'static [&'static str],
+}
+
+const RUST: Grammar = Grammar {
+    line_comment: "//",
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"'],
+    attribute: "#[",
+    keywords: &[
+        "as", "async", "await", "break", "const", "continue", "crate", "else", "enum", "fn", "for",
+        "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+        "static", "struct", "super", "trait", "type", "unsafe", "where", "while",
+    ],
+    includes: &["use", "extern"],
+    booleans: &["true", "false"],
+    constants: &["None"],
+    types: &[
+        "bool", "char", "f32", "f64", "i8", "i16", "i32", "i64", "isize", "str", "u8", "u16",
+        "u32", "u64", "usize", "Self",
+    ],
+    variables: &["self"],
+};
+
+const TYPESCRIPT: Grammar = Grammar {
+    line_comment: "//",
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"', '\'', '`'],
+    attribute: "@",
+    keywords: &[
+        "as",
+        "async",
+        "await",
+        "break",
+        "class",
+        "const",
+        "constructor",
+        "continue",
+        "else",
+        "export",
+        "extends",
+        "for",
+        "function",
+        "if",
+        "implements",
+        "in",
+        "instanceof",
+        "interface",
+        "let",
+        "new",
+        "of",
+        "private",
+        "protected",
+        "public",
+        "readonly",
+        "return",
+        "static",
+        "throw",
+        "try",
+        "type",
+        "typeof",
+        "var",
+        "while",
+    ],
+    includes: &["import", "from"],
+    booleans: &["true", "false"],
+    constants: &["null", "undefined"],
+    types: &[
+        "any", "boolean", "never", "number", "string", "unknown", "void",
+    ],
+    variables: &["this", "super"],
+};
+
+const PYTHON: Grammar = Grammar {
+    line_comment: "#",
+    block_comment: None,
+    quotes: &['"', '\''],
+    attribute: "@",
+    keywords: &[
+        "and", "as", "async", "await", "break", "class", "continue", "def", "del", "elif", "else",
+        "except", "finally", "for", "global", "if", "in", "is", "lambda", "not", "or", "pass",
+        "raise", "return", "try", "while", "with", "yield",
+    ],
+    includes: &["import", "from"],
+    booleans: &["True", "False"],
+    constants: &["None", "__name__"],
+    types: &[
+        "bool", "bytes", "dict", "float", "int", "list", "set", "str", "tuple",
+    ],
+    variables: &["self", "cls"],
+};
+
+const OPERATORS: &str = "+-*/%=<>!&|^~?:";
+
+/// Splits a sample into classified tokens. Adjacent tokens of the same
+/// class are merged.
+pub fn tokenize(language: Language, source: &str) -> Vec<(Class, &str)> {
+    let tokens = match language {
+        Language::Rust => tokenize_code(&RUST, source),
+        Language::TypeScript => tokenize_code(&TYPESCRIPT, source),
+        Language::Python => tokenize_code(&PYTHON, source),
+        Language::Markdown => tokenize_markdown(source),
+    };
+
+    // tokens are slices of `source`, so their offsets can be recovered
+    let offset = |text: &str| text.as_ptr() as usize - source.as_ptr() as usize;
+
+    let mut merged: Vec<(Class, &str)> = vec![];
+    for (class, text) in tokens {
+        match merged.last_mut() {
+            Some((last, last_text)) if *last == class => {
+                let start = offset(last_text);
+                *last_text = &source[start..offset(text) + text.len()];
+            }
+            _ => merged.push((class, text)),
+        }
+    }
+
+    merged
+}
+
+fn tokenize_code<'a>(grammar: &Grammar, source: &'a str) -> Vec<(Class, &'a str)> {
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    while i < source.len() {
+        let rest = &source[i..];
+        let c = rest.chars().next().unwrap();
+
+        let (class, len) = if c.is_whitespace() {
+            (Class::Text, span(rest, |c| c.is_whitespace()))
+        } else if rest.starts_with(grammar.line_comment) {
+            (Class::Comment, rest.find('\n').unwrap_or(rest.len()))
+        } else if let Some(len) = block_comment(grammar, rest) {
+            (Class::Comment, len)
+        } else if rest.starts_with(grammar.attribute) {
+            let len = if grammar.attribute == "#[" {
+                rest.find(']').map(|i| i + 1).unwrap_or(rest.len())
+            } else {
+                1 + span(&rest[1..], |c| c.is_alphanumeric() || c == '_' || c == '.')
+            };
+            (Class::Attribute, len)
+        } else if grammar.quotes.contains(&c) {
+            (Class::String, string(rest, c))
+        } else if c.is_ascii_digit() {
+            (
+                Class::Number,
+                span(rest, |c| c.is_alphanumeric() || c == '_' || c == '.'),
+            )
+        } else if c.is_alphabetic() || c == '_' {
+            let len = span(rest, |c| c.is_alphanumeric() || c == '_');
+            let word = &rest[..len];
+            let next = rest[len..].chars().next();
+            let previous = source[..i].chars().next_back();
+
+            let class = if grammar.keywords.contains(&word) {
+                Class::Keyword
+            } else if grammar.includes.contains(&word) {
+                Class::Include
+            } else if grammar.booleans.contains(&word) {
+                Class::Boolean
+            } else if grammar.constants.contains(&word) {
+                Class::Constant
+            } else if grammar.variables.contains(&word) {
+                Class::VariableBuiltin
+            } else if grammar.types.contains(&word) {
+                Class::TypeBuiltin
+            } else if next == Some('!') && grammar.attribute == "#[" {
+                // Rust macros include the bang
+                tokens.push((Class::Macro, &source[i..i + len + 1]));
+                i += len + 1;
+                continue;
+            } else if next == Some('(') {
+                Class::Function
+            } else if word.starts_with(|c: char| c.is_uppercase()) {
+                Class::Type
+            } else if previous == Some('.') {
+                Class::Property
+            } else {
+                Class::Variable
+            };
+
+            (class, len)
+        } else if OPERATORS.contains(c) {
+            (Class::Operator, span(rest, |c| OPERATORS.contains(c)))
+        } else {
+            (Class::Punctuation, c.len_utf8())
+        };
+
+        tokens.push((class, &source[i..i + len]));
+        i += len;
+    }
+
+    tokens
+}
+
+fn tokenize_markdown(source: &str) -> Vec<(Class, &str)> {
+    let mut tokens = vec![];
+    let mut in_fence = false;
+
+    for line in source.split_inclusive('\n') {
+        let content = line.trim_end_matches('\n');
+        let newline = &line[content.len()..];
+
+        if content.starts_with("```") || in_fence {
+            if content.starts_with("```") {
+                in_fence = !in_fence;
+            }
+            tokens.push((Class::Code, content));
+        } else if content.starts_with('#') {
+            tokens.push((Class::Heading, content));
+        } else if content.starts_with('>') {
+            tokens.push((Class::Quote, content));
+        } else {
+            let digits = span(content, |c| c.is_ascii_digit());
+            let marker = if content.starts_with("- ") || content.starts_with("* ") {
+                1
+            } else if digits > 0 && content[digits..].starts_with(". ") {
+                digits + 1
+            } else {
+                0
+            };
+
+            if marker > 0 {
+                tokens.push((Class::ListMarker, &content[..marker]));
+            }
+            tokenize_inline(&content[marker..], &mut tokens);
+        }
+
+        if !newline.is_empty() {
+            tokens.push((Class::Text, newline));
+        }
+    }
+
+    tokens
+}
+
+fn tokenize_inline<'a>(text: &'a str, tokens: &mut Vec<(Class, &'a str)>) {
+    let mut i = 0;
+    let mut text_start = 0;
+
+    while i < text.len() {
+        let rest = &text[i..];
+
+        // the classes and lengths of the tokens found at `i`
+        let found: Option<Vec<(Class, usize)>> = if let Some(inner) = rest.strip_prefix("**") {
+            inner.find("**").map(|end| vec![(Class::Strong, end + 4)])
+        } else if rest.starts_with('*') || rest.starts_with('_') {
+            rest[1..]
+                .find(&rest[..1])
+                .map(|end| vec![(Class::Emphasis, end + 2)])
+        } else if let Some(inner) = rest.strip_prefix('`') {
+            inner.find('`').map(|end| vec![(Class::Code, end + 2)])
+        } else if rest.starts_with('[') {
+            rest.find("](").and_then(|link| {
+                rest[link + 1..]
+                    .find(')')
+                    .map(|url| vec![(Class::Link, link + 1), (Class::Url, url + 1)])
+            })
+        } else {
+            None
+        };
+
+        match found {
+            Some(found) => {
+                if text_start < i {
+                    tokens.push((Class::Text, &text[text_start..i]));
+                }
+
+                for (class, len) in found {
+                    tokens.push((class, &text[i..i + len]));
+                    i += len;
+                }
+                text_start = i;
+            }
+            None => i += rest.chars().next().unwrap().len_utf8(),
+        }
+    }
+
+    if text_start < text.len() {
+        tokens.push((Class::Text, &text[text_start..]));
+    }
+}
+
+/// Returns the length of the block comment at the start of `s`.
+fn block_comment(grammar: &Grammar, s: &str) -> Option<usize> {
+    let (start, end) = grammar.block_comment?;
+    if !s.starts_with(start) {
+        return None;
+    }
+
+    Some(
+        s[start.len()..]
+            .find(end)
+            .map(|i| start.len() + i + end.len())
+            .unwrap_or(s.len()),
+    )
+}
+
+/// Returns the length of the leading characters of `s` matching `f`.
+fn span(s: &str, f: impl Fn(char) -> bool) -> usize {
+    s.find(|c: char| !f(c)).unwrap_or(s.len())
+}
+
+/// Returns the length of the string literal at the start of `s`.
+fn string(s: &str, quote: char) -> usize {
+    let mut escaped = false;
+
+    for (i, c) in s.char_indices().skip(1) {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == quote {
+            return i + 1;
+        }
+    }
+
+    s.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::highlight::parse_highlights;
+    use crate::palette::parse_palette;
+
+    fn classes(language: Language, source: &str) -> Vec<(Class, &str)> {
+        tokenize(language, source)
+            .into_iter()
+            .filter(|(class, _)| *class != Class::Text)
+            .collect()
+    }
+
+    #[test]
+    fn test_tokenize() {
+        let source = "// hi\nlet n = println!(\"{}\", 42);\n";
+        assert_eq!(
+            tokenize(Language::Rust, source)
+                .iter()
+                .map(|(_, text)| *text)
+                .collect::<String>(),
+            source
+        );
+        assert_eq!(
+            classes(Language::Rust, source),
+            vec![
+                (Class::Comment, "// hi"),
+                (Class::Keyword, "let"),
+                (Class::Variable, "n"),
+                (Class::Operator, "="),
+                (Class::Macro, "println!"),
+                (Class::Punctuation, "("),
+                (Class::String, "\"{}\""),
+                (Class::Punctuation, ","),
+                (Class::Number, "42"),
+                (Class::Punctuation, ");"),
+            ]
+        );
+
+        assert_eq!(
+            classes(Language::Markdown, "# Title\n[Vim](https://www.vim.org)\n"),
+            vec![
+                (Class::Heading, "# Title"),
+                (Class::Link, "[Vim]"),
+                (Class::Url, "(https://www.vim.org)"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group() {
+        let palette = parse_palette("red = \"#ff0000\"").unwrap();
+        let highlights = parse_highlights(
+            r#"
+[[section]]
+highlights = [
+  { name = "Normal", fg = "red" },
+  { name = "Statement", fg = "red" },
+  { name = "@keyword", link = "Statement" },
+]
+"#,
+            &palette,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Class::Keyword.group(Groups::Treesitter, &highlights),
+            "@keyword"
+        );
+        assert_eq!(
+            Class::Keyword.group(Groups::Syntax, &highlights),
+            "Statement"
+        );
+        assert_eq!(Class::Variable.group(Groups::Syntax, &highlights), "Normal");
+        assert_eq!(Class::Text.group(Groups::Treesitter, &highlights), "Normal");
+    }
+}
@@ -0,0 +1,385 @@
+use crate::error;
+use crate::highlight::{Background, Color, Highlight, Palette};
+use crate::palette::{derivation_chain, lookup, Derivations};
+use crate::style::{css, escape, Styles};
+use std::io;
+
+const REFERENCED_BY: &str = "the specimen";
+
+/// Sample code as (group, text) tokens. An empty group is `Normal` text.
+const SAMPLE: &[(&str, &str)] = &[
+    ("Comment", "// Take a sweet dog with you."),
+    ("", "\n"),
+    ("Statement", "use"),
+    ("", " std::fmt;\n\n"),
+    ("PreProc", "#[derive(Debug)]"),
+    ("", "\n"),
+    ("Statement", "pub struct"),
+    ("", " "),
+    ("Type", "Dog"),
+    ("", " {\n    "),
+    ("Identifier", "name"),
+    ("", ": "),
+    ("Type", "String"),
+    ("", ",\n    "),
+    ("Identifier", "age"),
+    ("", ": "),
+    ("Type", "u8"),
+    ("", ",\n}\n\n"),
+    ("Statement", "impl"),
+    ("", " "),
+    ("Type", "Dog"),
+    ("", " {\n    "),
+    ("Statement", "pub fn"),
+    ("", " "),
+    ("Function", "bark"),
+    ("", "(&"),
+    ("Keyword", "self"),
+    ("", ") -> "),
+    ("Type", "String"),
+    ("", " {\n        "),
+    ("Todo", "TODO"),
+    ("Comment", ": teach more words"),
+    ("", "\n        "),
+    ("Function", "format!"),
+    ("", "("),
+    ("String", "\""),
+    ("Special", "{}"),
+    ("String", " says woof "),
+    ("Special", "{}"),
+    ("String", " times\""),
+    ("", ", "),
+    ("Keyword", "self"),
+    ("", ".name, "),
+    ("Number", "3"),
+    ("", ")\n    }\n}\n"),
+];
+
+/// Writes a self-contained HTML page with every palette entry (hex value,
+/// cterm index and derivation chain) for each background, followed by the
+/// sample code rendered with the real highlight groups.
+pub fn write_html<W: io::Write>(
+    mut out: W,
+    palettes: &[(Background, &Palette)],
+    derivations: &Derivations,
+    highlights: &[Highlight],
+) -> error::Result<()> {
+    writeln!(
+        out,
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>dogrun specimen</title>
+<style>
+body {{ margin: 2rem; font-family: ui-monospace, SFMono-Regular, Menlo, Consolas, monospace; font-size: 14px; }}
+table {{ border-collapse: collapse; }}
+th, td {{ padding: 2px 12px 2px 0; text-align: left; white-space: nowrap; }}
+.swatch {{ display: inline-block; width: 40px; height: 16px; vertical-align: middle; border: 1px solid #8888; }}
+.samples {{ display: flex; flex-wrap: wrap; gap: 1rem; }}
+pre {{ margin: 0; padding: 1rem 1.5rem; line-height: 1.5; }}
+</style>
+</head>
+<body>
+<h1>dogrun</h1>
+<h2>Palette</h2>
+<table>"#
+    )?;
+
+    write!(out, "<tr><th>name</th>")?;
+    for (background, _) in palettes {
+        write!(out, "<th colspan=\"3\">{}</th>", background)?;
+    }
+    writeln!(out, "<th>derivation</th></tr>")?;
+
+    for (name, _) in derivations.iter() {
+        write!(out, "<tr><td>{}</td>", escape(name))?;
+        for (_, palette) in palettes {
+            let color = lookup(palette, name, REFERENCED_BY)?;
+            write!(
+                out,
+                "<td><span class=\"swatch\" style=\"background-color: {}\"></span></td><td>{}</td><td>{}</td>",
+                color.gui, color.gui, color.cterm
+            )?;
+        }
+        writeln!(
+            out,
+            "<td>{}</td></tr>",
+            escape(&derivation(derivations, name))
+        )?;
+    }
+
+    writeln!(out, "</table>")?;
+    writeln!(out, "<h2>Highlights</h2>")?;
+    writeln!(out, "<div class=\"samples\">")?;
+
+    for (_, palette) in palettes {
+        let styles = Styles::new(palette, highlights);
+        let normal = styles.get("Normal")?;
+
+        write!(out, "<pre style=\"{}\">", css(&normal))?;
+        for (group, text) in SAMPLE {
+            if group.is_empty() {
+                write!(out, "{}", escape(text))?;
+            } else {
+                write!(
+                    out,
+                    "<span title=\"{}\" style=\"{}\">{}</span>",
+                    group,
+                    css(&styles.get(group)?),
+                    escape(text)
+                )?;
+            }
+        }
+        writeln!(out, "</pre>")?;
+    }
+
+    writeln!(out, "</div>")?;
+    writeln!(out, "</body>")?;
+    writeln!(out, "</html>")?;
+
+    Ok(())
+}
+
+/// Writes an SVG swatch sheet with a row per palette entry: a swatch for
+/// each background, then the name, hex values, cterm indexes and the
+/// derivation chain.
+pub fn write_svg<W: io::Write>(
+    mut out: W,
+    palettes: &[(Background, &Palette)],
+    derivations: &Derivations,
+) -> error::Result<()> {
+    const ROW: usize = 24;
+    const SWATCH: usize = 48;
+    const PADDING: usize = 16;
+
+    // the sheet is drawn with the colors of the first palette
+    let Some((_, first)) = palettes.first() else {
+        return Ok(());
+    };
+    let bg = lookup(first, "mainbg", REFERENCED_BY)?;
+    let fg = lookup(first, "mainfg", REFERENCED_BY)?;
+
+    let text_x = PADDING + palettes.len() * (SWATCH + 8) + 8;
+    let name_width = derivations
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0)
+        + 2;
+    let column = |i: usize| text_x + (name_width + i * 9) * 7;
+    let derivation_width = derivations
+        .iter()
+        .map(|(name, _)| derivation(derivations, name).chars().count())
+        .max()
+        .unwrap_or(0);
+    let width = column(palettes.len() * 2) + derivation_width * 7 + PADDING;
+    let height = PADDING * 2 + (derivations.len() + 1) * ROW;
+
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}" font-family="ui-monospace, SFMono-Regular, Menlo, Consolas, monospace" font-size="12">"#,
+        width, height, width, height
+    )?;
+    writeln!(
+        out,
+        r#"  <rect width="100%" height="100%" fill="{}"/>"#,
+        bg.gui
+    )?;
+    writeln!(out, r#"  <g fill="{}">"#, fg.gui)?;
+
+    // header
+    let y = PADDING + ROW / 2 + 4;
+    for (i, (background, _)) in palettes.iter().enumerate() {
+        writeln!(
+            out,
+            r#"    <text x="{}" y="{}">{}</text>"#,
+            PADDING + i * (SWATCH + 8),
+            y,
+            background
+        )?;
+        writeln!(
+            out,
+            r#"    <text x="{}" y="{}">{}</text>"#,
+            column(i * 2),
+            y,
+            background
+        )?;
+    }
+    writeln!(out, r#"    <text x="{}" y="{}">name</text>"#, text_x, y)?;
+    writeln!(
+        out,
+        r#"    <text x="{}" y="{}">derivation</text>"#,
+        column(palettes.len() * 2),
+        y
+    )?;
+
+    for (row, (name, _)) in derivations.iter().enumerate() {
+        let top = PADDING + (row + 1) * ROW;
+        let y = top + ROW / 2 + 4;
+
+        let mut cells = vec![];
+        for (i, (_, palette)) in palettes.iter().enumerate() {
+            let color: &Color = lookup(palette, name, REFERENCED_BY)?;
+            writeln!(
+                out,
+                r#"    <rect x="{}" y="{}" width="{}" height="{}" rx="3" fill="{}"/>"#,
+                PADDING + i * (SWATCH + 8),
+                top + 2,
+                SWATCH,
+                ROW - 4,
+                color.gui
+            )?;
+            cells.push((column(i * 2), color.gui.to_string()));
+            cells.push((column(i * 2 + 1), color.cterm.to_string()));
+        }
+
+        writeln!(
+            out,
+            r#"    <text x="{}" y="{}">{}</text>"#,
+            text_x,
+            y,
+            escape(name)
+        )?;
+        for (x, text) in cells {
+            writeln!(out, r#"    <text x="{}" y="{}">{}</text>"#, x, y, text)?;
+        }
+
+        let derivation = derivation(derivations, name);
+        if !derivation.is_empty() {
+            writeln!(
+                out,
+                r#"    <text x="{}" y="{}">{}</text>"#,
+                column(palettes.len() * 2),
+                y,
+                escape(&derivation)
+            )?;
+        }
+    }
+
+    writeln!(out, "  </g>")?;
+    writeln!(out, "</svg>")?;
+
+    Ok(())
+}
+
+/// Formats the derivation chain of an entry, e.g.
+/// "purple → darkpurple (s +0.05 v -0.15) → defxiconpurple". Base colors
+/// have an empty chain.
+fn derivation(derivations: &Derivations, name: &str) -> String {
+    let chain = derivation_chain(derivations, name);
+    if chain.len() < 2 {
+        return String::new();
+    }
+
+    chain
+        .iter()
+        .map(|name| {
+            let deltas = derivations
+                .iter()
+                .find(|(n, _)| n == name)
+                .and_then(|(_, derivation)| derivation.as_ref())
+                .map(|derivation| derivation.deltas())
+                .unwrap_or_default();
+
+            if deltas.is_empty() {
+                name.to_string()
+            } else {
+                format!("{} ({})", name, deltas)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" → ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conv::CtermOptions;
+    use crate::highlight::parse_highlights;
+    use crate::palette::{parse_palette_with, ResolvedPalette};
+
+    const PALETTE: &str = r##"
+mainfg = "#ffffff"
+mainbg = "#000000"
+red = "#ff0000"
+darkred = { extends = "red", v = -0.2 }
+errorfg = { extends = "darkred" }
+"##;
+
+    fn resolve() -> ResolvedPalette {
+        parse_palette_with(PALETTE, &CtermOptions::default()).unwrap()
+    }
+
+    #[test]
+    fn test_derivation() {
+        let derivations = resolve().derivations;
+
+        assert_eq!(derivation(&derivations, "red"), "");
+        assert_eq!(
+            derivation(&derivations, "errorfg"),
+            "red → darkred (v -0.2) → errorfg"
+        );
+    }
+
+    #[test]
+    fn test_write_html() {
+        let ResolvedPalette {
+            colors: palette,
+            derivations,
+            ..
+        } = resolve();
+        let highlights = parse_highlights(
+            r#"
+[[section]]
+highlights = [
+  { name = "Normal", fg = "mainfg", bg = "mainbg" },
+  { name = "Comment", fg = "darkred", attr = "italic,undercurl", sp = "red" },
+]
+"#,
+            &palette,
+        )
+        .unwrap();
+
+        let mut buf = vec![];
+        write_html(
+            &mut buf,
+            &[(Background::Dark, &palette)],
+            &derivations,
+            &highlights,
+        )
+        .unwrap();
+        let html = String::from_utf8(buf).unwrap();
+
+        assert!(html.contains("<td>errorfg</td><td><span class=\"swatch\" style=\"background-color: #cc0000\"></span></td><td>#cc0000</td><td>160</td><td>red → darkred (v -0.2) → errorfg</td>"));
+        assert!(html.contains("<span title=\"Comment\" style=\"color: #cc0000; background-color: #000000; font-style: italic; text-decoration: underline wavy #ff0000\">// Take a sweet dog with you.</span>"));
+        // groups missing from the highlights are drawn as Normal
+        assert!(html.contains(
+            "<span title=\"Type\" style=\"color: #ffffff; background-color: #000000\">Dog</span>"
+        ));
+    }
+
+    #[test]
+    fn test_write_svg() {
+        let ResolvedPalette {
+            colors: palette,
+            derivations,
+            ..
+        } = resolve();
+
+        let mut buf = vec![];
+        write_svg(
+            &mut buf,
+            &[(Background::Dark, &palette), (Background::Light, &palette)],
+            &derivations,
+        )
+        .unwrap();
+        let svg = String::from_utf8(buf).unwrap();
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        // a swatch per entry and background, plus the background
+        assert_eq!(svg.matches("<rect ").count(), 5 * 2 + 1);
+        assert!(svg.contains(">red → darkred (v -0.2)</text>"));
+    }
+}
@@ -0,0 +1,244 @@
+use crate::error;
+use crate::highlight::{Color, Highlight, HighlightAttr, Palette};
+use crate::palette::lookup;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Whether colors are taken from the GUI values or from the 256-color
+/// approximations used in the terminal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    Gui,
+    Cterm,
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Mode::Gui => "gui",
+            Mode::Cterm => "cterm",
+        })
+    }
+}
+
+/// SGR parameters of the attributes a terminal can show. `nocombine` and
+/// `altfont` have no visible effect and are left out.
+const ATTRS: [(HighlightAttr, &str); 10] = [
+    (HighlightAttr::BOLD, "1"),
+    (HighlightAttr::ITALIC, "3"),
+    (HighlightAttr::UNDERLINE, "4"),
+    (HighlightAttr::UNDERCURL, "4:3"),
+    (HighlightAttr::UNDERDOUBLE, "4:2"),
+    (HighlightAttr::UNDERDOTTED, "4:4"),
+    (HighlightAttr::UNDERDASHED, "4:5"),
+    (HighlightAttr::STRIKETHROUGH, "9"),
+    (HighlightAttr::REVERSE, "7"),
+    (HighlightAttr::STANDOUT, "7"),
+];
+
+/// The colors and attributes a group is drawn with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style<'a> {
+    pub fg: Option<&'a Color>,
+    pub bg: Option<&'a Color>,
+    pub sp: Option<&'a Color>,
+    pub attr: HighlightAttr,
+}
+
+/// Resolves highlight groups to the style they end up with in the editor:
+/// links are followed, and a fg or bg that is unset (or "NONE") falls back
+/// to the one of `Normal`.
+pub struct Styles<'a> {
+    palette: &'a Palette,
+    groups: HashMap<&'a str, &'a Highlight>,
+}
+
+impl<'a> Styles<'a> {
+    pub fn new(palette: &'a Palette, highlights: &'a [Highlight]) -> Self {
+        Self {
+            palette,
+            groups: highlights.iter().map(|hl| (hl.name.as_str(), hl)).collect(),
+        }
+    }
+
+    /// Returns the group `name` links to, or the group itself.
+    pub fn target(&self, name: &str) -> Option<&'a Highlight> {
+        let mut target = *self.groups.get(name)?;
+        let mut depth = 0;
+
+        // links are validated to be acyclic, the depth is a safeguard
+        while let Some(next) = target.link.as_deref().and_then(|n| self.groups.get(n)) {
+            target = next;
+            depth += 1;
+            if depth > self.groups.len() {
+                break;
+            }
+        }
+
+        Some(target)
+    }
+
+    /// Returns the style of `name`. Undefined groups are drawn as `Normal`.
+    pub fn get(&self, name: &str) -> error::Result<Style<'a>> {
+        let normal = self.groups.get("Normal").copied();
+        let target = self.target(name);
+
+        let color = |get: fn(&Highlight) -> &Option<String>| -> error::Result<_> {
+            let defined = |hl: &'a Highlight| {
+                get(hl)
+                    .as_deref()
+                    .filter(|name| *name != "NONE")
+                    .map(|name| (hl, name))
+            };
+
+            match target
+                .and_then(defined)
+                .or_else(|| normal.and_then(defined))
+            {
+                Some((hl, name)) => Ok(Some(lookup(
+                    self.palette,
+                    name,
+                    &format!("highlight {}", hl.name),
+                )?)),
+                None => Ok(None),
+            }
+        };
+
+        let sp = match target.and_then(|hl| hl.sp.as_deref().map(|sp| (hl, sp))) {
+            Some((hl, sp)) => Some(lookup(self.palette, sp, &format!("highlight {}", hl.name))?),
+            None => None,
+        };
+
+        Ok(Style {
+            fg: color(|hl| &hl.fg)?,
+            bg: color(|hl| &hl.bg)?,
+            sp,
+            attr: target.map(|hl| hl.attr).unwrap_or_default(),
+        })
+    }
+}
+
+/// Builds the escape sequence that sets the colors and attributes.
+pub fn sgr(style: &Style, mode: Mode) -> String {
+    let mut params = vec!["0".to_string()];
+
+    for (color, prefix) in [(style.fg, "38"), (style.bg, "48"), (style.sp, "58")] {
+        if let Some(color) = color {
+            params.push(match mode {
+                Mode::Gui => {
+                    let [r, g, b] = color.gui.to_u8();
+                    format!("{};2;{};{};{}", prefix, r, g, b)
+                }
+                Mode::Cterm => format!("{};5;{}", prefix, color.cterm),
+            });
+        }
+    }
+
+    for (flag, param) in ATTRS {
+        if style.attr.contains(flag) && !params.iter().any(|p| p == param) {
+            params.push(param.to_string());
+        }
+    }
+
+    format!("\x1b[{}m", params.join(";"))
+}
+
+/// Converts a style to inline CSS.
+pub fn css(style: &Style) -> String {
+    let (fg, bg) = if style.attr.contains(HighlightAttr::REVERSE) {
+        (style.bg, style.fg)
+    } else {
+        (style.fg, style.bg)
+    };
+
+    let mut rules = vec![];
+    if let Some(fg) = fg {
+        rules.push(format!("color: {}", fg.gui));
+    }
+    if let Some(bg) = bg {
+        rules.push(format!("background-color: {}", bg.gui));
+    }
+    if style.attr.contains(HighlightAttr::BOLD) {
+        rules.push("font-weight: bold".to_string());
+    }
+    if style.attr.contains(HighlightAttr::ITALIC) {
+        rules.push("font-style: italic".to_string());
+    }
+
+    let underline = [
+        (HighlightAttr::UNDERLINE, "solid"),
+        (HighlightAttr::UNDERCURL, "wavy"),
+        (HighlightAttr::UNDERDOUBLE, "double"),
+        (HighlightAttr::UNDERDOTTED, "dotted"),
+        (HighlightAttr::UNDERDASHED, "dashed"),
+    ]
+    .into_iter()
+    .find(|(attr, _)| style.attr.contains(*attr))
+    .map(|(_, style)| style);
+
+    let mut lines = vec![];
+    if underline.is_some() {
+        lines.push("underline");
+    }
+    if style.attr.contains(HighlightAttr::STRIKETHROUGH) {
+        lines.push("line-through");
+    }
+
+    if !lines.is_empty() {
+        let mut decoration = vec![lines.join(" ")];
+        decoration.extend(underline.map(str::to_string));
+        decoration.extend(style.sp.map(|sp| sp.gui.to_string()));
+        rules.push(format!("text-decoration: {}", decoration.join(" ")));
+    }
+
+    rules.join("; ")
+}
+
+/// Escapes text for HTML.
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::highlight::parse_highlights;
+    use crate::palette::parse_palette;
+
+    #[test]
+    fn test_styles() {
+        let palette = parse_palette(
+            r##"
+mainfg = "#ffffff"
+mainbg = "#000000"
+red = "#ff0000"
+"##,
+        )
+        .unwrap();
+        let highlights = parse_highlights(
+            r#"
+[[section]]
+highlights = [
+  { name = "Normal", fg = "mainfg", bg = "mainbg" },
+  { name = "Error", fg = "red", bg = "NONE", sp = "red", attr = "undercurl" },
+  { name = "Todo", link = "Error" },
+]
+"#,
+            &palette,
+        )
+        .unwrap();
+
+        let styles = Styles::new(&palette, &highlights);
+        let todo = styles.get("Todo").unwrap();
+        assert_eq!(todo.fg, Some(&palette["red"]));
+        assert_eq!(todo.bg, Some(&palette["mainbg"]));
+        assert_eq!(todo.sp, Some(&palette["red"]));
+        assert_eq!(todo.attr, HighlightAttr::UNDERCURL);
+
+        let undefined = styles.get("Keyword").unwrap();
+        assert_eq!(undefined, styles.get("Normal").unwrap());
+    }
+}
@@ -0,0 +1,301 @@
+use crate::conv::Rgb;
+use crate::error;
+use crate::highlight::Palette;
+use crate::palette::lookup;
+use std::io;
+
+/// Palette entries of the 16 terminal colors, in ANSI order.
+pub const ANSI_COLORS: [&str; 16] = [
+    "termblack",
+    "termmaroon",
+    "termgreen",
+    "termolive",
+    "termnavy",
+    "termpurple",
+    "termteal",
+    "termsilver",
+    "termgray",
+    "termred",
+    "termlime",
+    "termyellow",
+    "termblue",
+    "termfuchsia",
+    "termaqua",
+    "termwhite",
+];
+
+/// ANSI color names, as used by the terminals that name them.
+const ANSI_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+const AUTHOR: &str = "wadackel";
+const URL: &str = "https://github.com/wadackel/vim-dogrun";
+
+/// The colors every terminal theme is made of. They format as hex strings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TerminalColors {
+    pub ansi: [Rgb; 16],
+    pub background: Rgb,
+    pub foreground: Rgb,
+    pub cursor: Rgb,
+    pub cursor_text: Rgb,
+    pub selection_background: Rgb,
+    pub selection_foreground: Rgb,
+}
+
+impl TerminalColors {
+    pub fn new(palette: &Palette) -> error::Result<Self> {
+        let gui = |name: &str| lookup(palette, name, "the terminal colors").map(|color| color.gui);
+
+        let mut ansi = [Rgb::default(); 16];
+        for (color, name) in ansi.iter_mut().zip(ANSI_COLORS) {
+            *color = gui(name)?;
+        }
+
+        Ok(Self {
+            ansi,
+            background: gui("mainbg")?,
+            foreground: gui("mainfg")?,
+            cursor: gui("mainfg")?,
+            cursor_text: gui("mainbg")?,
+            selection_background: gui("visualbg")?,
+            selection_foreground: gui("mainfg")?,
+        })
+    }
+
+    /// The normal (0-7) colors.
+    pub fn normal(&self) -> &[Rgb] {
+        &self.ansi[..8]
+    }
+
+    /// The bright (8-15) colors.
+    pub fn bright(&self) -> &[Rgb] {
+        &self.ansi[8..]
+    }
+}
+
+/// Writes a WezTerm color scheme (TOML).
+pub fn write_wezterm<W: io::Write>(
+    mut out: W,
+    colors: &TerminalColors,
+    name: &str,
+) -> io::Result<()> {
+    // [colors] section
+    writeln!(out, "[colors]")?;
+    writeln!(out, "background = \"{}\"", colors.background)?;
+    writeln!(out, "foreground = \"{}\"", colors.foreground)?;
+    writeln!(out, "cursor_bg = \"{}\"", colors.cursor)?;
+    writeln!(out, "cursor_fg = \"{}\"", colors.cursor_text)?;
+    writeln!(out, "cursor_border = \"{}\"", colors.cursor)?;
+    writeln!(out, "selection_bg = \"{}\"", colors.selection_background)?;
+
+    // ansi array (0-7)
+    writeln!(out, "ansi = [")?;
+    for color in colors.normal() {
+        writeln!(out, "  \"{}\",", color)?;
+    }
+    writeln!(out, "]")?;
+
+    // brights array (8-15)
+    writeln!(out, "brights = [")?;
+    for color in colors.bright() {
+        writeln!(out, "  \"{}\",", color)?;
+    }
+    writeln!(out, "]")?;
+
+    // [metadata] section
+    writeln!(out)?;
+    writeln!(out, "[metadata]")?;
+    writeln!(out, "name = \"{}\"", name)?;
+    writeln!(out, "author = \"{}\"", AUTHOR)?;
+    writeln!(out, "origin_url = \"{}\"", URL)?;
+
+    Ok(())
+}
+
+/// Writes an iTerm2 color preset (`.itermcolors` property list).
+pub fn write_iterm<W: io::Write>(mut out: W, colors: &TerminalColors) -> io::Result<()> {
+    let mut entries: Vec<(String, Rgb)> = colors
+        .ansi
+        .iter()
+        .enumerate()
+        .map(|(i, color)| (format!("Ansi {} Color", i), *color))
+        .collect();
+
+    for (key, color) in [
+        ("Background Color", colors.background),
+        ("Bold Color", colors.foreground),
+        ("Cursor Color", colors.cursor),
+        ("Cursor Text Color", colors.cursor_text),
+        ("Foreground Color", colors.foreground),
+        ("Link Color", colors.ansi[14]),
+        ("Selected Text Color", colors.selection_foreground),
+        ("Selection Color", colors.selection_background),
+    ] {
+        entries.push((key.to_string(), color));
+    }
+
+    writeln!(
+        out,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>"#
+    )?;
+
+    for (key, Rgb { r, g, b }) in entries {
+        writeln!(out, "\t<key>{}</key>", key)?;
+        writeln!(out, "\t<dict>")?;
+        writeln!(out, "\t\t<key>Alpha Component</key>")?;
+        writeln!(out, "\t\t<real>1</real>")?;
+        writeln!(out, "\t\t<key>Blue Component</key>")?;
+        writeln!(out, "\t\t<real>{}</real>", b)?;
+        writeln!(out, "\t\t<key>Color Space</key>")?;
+        writeln!(out, "\t\t<string>sRGB</string>")?;
+        writeln!(out, "\t\t<key>Green Component</key>")?;
+        writeln!(out, "\t\t<real>{}</real>", g)?;
+        writeln!(out, "\t\t<key>Red Component</key>")?;
+        writeln!(out, "\t\t<real>{}</real>", r)?;
+        writeln!(out, "\t</dict>")?;
+    }
+
+    writeln!(out, "</dict>")?;
+    writeln!(out, "</plist>")?;
+
+    Ok(())
+}
+
+/// Writes an Alacritty color scheme (TOML, 0.13 or later).
+pub fn write_alacritty<W: io::Write>(
+    mut out: W,
+    colors: &TerminalColors,
+    name: &str,
+) -> io::Result<()> {
+    writeln!(out, "# {}: {}", name, URL)?;
+    writeln!(out)?;
+
+    writeln!(out, "[colors.primary]")?;
+    writeln!(out, "background = \"{}\"", colors.background)?;
+    writeln!(out, "foreground = \"{}\"", colors.foreground)?;
+    writeln!(out)?;
+
+    writeln!(out, "[colors.cursor]")?;
+    writeln!(out, "text = \"{}\"", colors.cursor_text)?;
+    writeln!(out, "cursor = \"{}\"", colors.cursor)?;
+    writeln!(out)?;
+
+    writeln!(out, "[colors.selection]")?;
+    writeln!(out, "text = \"{}\"", colors.selection_foreground)?;
+    writeln!(out, "background = \"{}\"", colors.selection_background)?;
+
+    for (section, ansi) in [("normal", colors.normal()), ("bright", colors.bright())] {
+        writeln!(out)?;
+        writeln!(out, "[colors.{}]", section)?;
+        for (name, color) in ANSI_NAMES.iter().zip(ansi) {
+            writeln!(out, "{} = \"{}\"", name, color)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes a kitty theme (`.conf`).
+pub fn write_kitty<W: io::Write>(
+    mut out: W,
+    colors: &TerminalColors,
+    name: &str,
+) -> io::Result<()> {
+    writeln!(out, "# vim:ft=kitty")?;
+    writeln!(out)?;
+    writeln!(out, "## name: {}", name)?;
+    writeln!(out, "## author: {}", AUTHOR)?;
+    writeln!(out, "## upstream: {}", URL)?;
+    writeln!(out)?;
+
+    writeln!(out, "foreground {}", colors.foreground)?;
+    writeln!(out, "background {}", colors.background)?;
+    writeln!(out, "selection_foreground {}", colors.selection_foreground)?;
+    writeln!(out, "selection_background {}", colors.selection_background)?;
+    writeln!(out, "cursor {}", colors.cursor)?;
+    writeln!(out, "cursor_text_color {}", colors.cursor_text)?;
+    writeln!(out)?;
+
+    for (i, color) in colors.ansi.iter().enumerate() {
+        writeln!(out, "color{} {}", i, color)?;
+    }
+
+    Ok(())
+}
+
+/// Writes a foot color theme (`.ini`). foot expects colors without `#`.
+pub fn write_foot<W: io::Write>(mut out: W, colors: &TerminalColors, name: &str) -> io::Result<()> {
+    let hex = |color: &Rgb| color.to_string().trim_start_matches('#').to_string();
+
+    writeln!(out, "# {}: {}", name, URL)?;
+    writeln!(out)?;
+
+    writeln!(out, "[cursor]")?;
+    writeln!(
+        out,
+        "color={} {}",
+        hex(&colors.cursor_text),
+        hex(&colors.cursor)
+    )?;
+    writeln!(out)?;
+
+    writeln!(out, "[colors]")?;
+    writeln!(out, "background={}", hex(&colors.background))?;
+    writeln!(out, "foreground={}", hex(&colors.foreground))?;
+    writeln!(
+        out,
+        "selection-foreground={}",
+        hex(&colors.selection_foreground)
+    )?;
+    writeln!(
+        out,
+        "selection-background={}",
+        hex(&colors.selection_background)
+    )?;
+
+    for (i, color) in colors.normal().iter().enumerate() {
+        writeln!(out, "regular{}={}", i, hex(color))?;
+    }
+
+    for (i, color) in colors.bright().iter().enumerate() {
+        writeln!(out, "bright{}={}", i, hex(color))?;
+    }
+
+    Ok(())
+}
+
+/// Writes a Ghostty theme.
+pub fn write_ghostty<W: io::Write>(
+    mut out: W,
+    colors: &TerminalColors,
+    name: &str,
+) -> io::Result<()> {
+    writeln!(out, "# {}: {}", name, URL)?;
+    writeln!(out)?;
+
+    for (i, color) in colors.ansi.iter().enumerate() {
+        writeln!(out, "palette = {}={}", i, color)?;
+    }
+
+    writeln!(out, "background = {}", colors.background)?;
+    writeln!(out, "foreground = {}", colors.foreground)?;
+    writeln!(out, "cursor-color = {}", colors.cursor)?;
+    writeln!(out, "cursor-text = {}", colors.cursor_text)?;
+    writeln!(
+        out,
+        "selection-background = {}",
+        colors.selection_background
+    )?;
+    writeln!(
+        out,
+        "selection-foreground = {}",
+        colors.selection_foreground
+    )?;
+
+    Ok(())
+}
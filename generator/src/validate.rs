@@ -0,0 +1,210 @@
+use crate::highlight::{Highlight, HighlightScope, Palette};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Built-in groups that only exist in Neovim, besides the `@` captures and
+/// the `Diagnostic*` groups.
+const NVIM_ONLY: [&str; 22] = [
+    "FloatBorder",
+    "FloatFooter",
+    "FloatShadow",
+    "FloatShadowThrough",
+    "FloatTitle",
+    "LspCodeLens",
+    "LspCodeLensSeparator",
+    "LspInlayHint",
+    "LspReferenceRead",
+    "LspReferenceText",
+    "LspReferenceWrite",
+    "LspSignatureActiveParameter",
+    "MsgArea",
+    "MsgSeparator",
+    "NormalFloat",
+    "NormalNC",
+    "Substitute",
+    "TermCursor",
+    "TermCursorNC",
+    "WinBar",
+    "WinBarNC",
+    "WinSeparator",
+];
+
+/// A mistake in the highlights that the parser lets through.
+#[derive(Debug, PartialEq)]
+pub enum Problem {
+    /// The group is defined more than once in the same scope.
+    Duplicate {
+        name: String,
+        scope: HighlightScope,
+    },
+    /// The group is defined in several scopes, so the later definition
+    /// silently overrides the earlier one where both apply.
+    ConflictingScopes {
+        name: String,
+        scopes: Vec<HighlightScope>,
+    },
+    UnknownColor {
+        name: String,
+        color: String,
+    },
+    /// The group sets no color, attribute or link, and renders as a bare
+    /// `hi Name`.
+    Empty {
+        name: String,
+    },
+    /// A built-in Neovim group is defined for Vim as well.
+    NvimOnly {
+        name: String,
+    },
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::Duplicate { name, scope } => {
+                write!(
+                    f,
+                    "\"{}\" is defined more than once in scope \"{}\"",
+                    name, scope
+                )
+            }
+            Problem::ConflictingScopes { name, scopes } => {
+                let scopes: Vec<_> = scopes.iter().map(|s| format!("\"{}\"", s)).collect();
+                write!(f, "\"{}\" is defined in scopes {}", name, scopes.join(", "))
+            }
+            Problem::UnknownColor { name, color } => {
+                write!(f, "\"{}\" refers to unknown color \"{}\"", name, color)
+            }
+            Problem::Empty { name } => {
+                write!(f, "\"{}\" sets no color, attr or link", name)
+            }
+            Problem::NvimOnly { name } => write!(
+                f,
+                "\"{}\" only exists in Neovim but is defined in scope \"all\"",
+                name
+            ),
+        }
+    }
+}
+
+/// Checks the highlights for problems, in the order they are defined.
+pub fn validate(palette: &Palette, highlights: &[Highlight]) -> Vec<Problem> {
+    let mut problems = vec![];
+
+    let mut scopes: HashMap<&str, Vec<HighlightScope>> = HashMap::new();
+    for hl in highlights.iter() {
+        scopes.entry(hl.name.as_str()).or_default().push(hl.scope);
+    }
+
+    for (i, hl) in highlights.iter().enumerate() {
+        let name = hl.name.clone();
+
+        // report duplicates and conflicts once, at the first definition
+        let first = highlights.iter().position(|other| other.name == hl.name) == Some(i);
+        if first {
+            let defined = &scopes[hl.name.as_str()];
+            let mut distinct: Vec<HighlightScope> = vec![];
+            for scope in defined.iter() {
+                if distinct.contains(scope) {
+                    problems.push(Problem::Duplicate {
+                        name: name.clone(),
+                        scope: *scope,
+                    });
+                } else {
+                    distinct.push(*scope);
+                }
+            }
+
+            if distinct.len() > 1 {
+                problems.push(Problem::ConflictingScopes {
+                    name: name.clone(),
+                    scopes: distinct,
+                });
+            }
+        }
+
+        for (color, allows_none) in [(&hl.fg, true), (&hl.bg, true), (&hl.sp, false)] {
+            if let Some(color) = color {
+                if !(palette.contains_key(color) || allows_none && color == "NONE") {
+                    problems.push(Problem::UnknownColor {
+                        name: name.clone(),
+                        color: color.clone(),
+                    });
+                }
+            }
+        }
+
+        let has_colors = hl.fg.is_some() || hl.bg.is_some() || hl.sp.is_some();
+        if hl.link.is_none() && !has_colors && hl.attr.is_empty() {
+            problems.push(Problem::Empty { name: name.clone() });
+        }
+
+        if hl.scope == HighlightScope::All && is_nvim_only(&hl.name) {
+            problems.push(Problem::NvimOnly { name });
+        }
+    }
+
+    problems
+}
+
+fn is_nvim_only(name: &str) -> bool {
+    name.starts_with('@') || name.starts_with("Diagnostic") || NVIM_ONLY.contains(&name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::highlight::HighlightAttr;
+
+    fn hl(name: &str, fg: Option<&str>, scope: HighlightScope) -> Highlight {
+        Highlight {
+            name: name.to_string(),
+            fg: fg.map(str::to_string),
+            bg: None,
+            sp: None,
+            attr: HighlightAttr::NOTHING,
+            scope,
+            link: None,
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let palette = crate::palette::parse_palette("red = \"#ff0000\"").unwrap();
+        let highlights = vec![
+            hl("Normal", Some("red"), HighlightScope::All),
+            hl("Normal", Some("red"), HighlightScope::All),
+            hl("Comment", Some("red"), HighlightScope::All),
+            hl("Comment", Some("red"), HighlightScope::Nvim),
+            hl("Error", Some("blue"), HighlightScope::All),
+            hl("Empty", None, HighlightScope::All),
+            hl("NormalFloat", Some("red"), HighlightScope::All),
+            hl("DiagnosticError", Some("red"), HighlightScope::Nvim),
+            Highlight::link("Todo", "Comment"),
+        ];
+
+        assert_eq!(
+            validate(&palette, &highlights),
+            vec![
+                Problem::Duplicate {
+                    name: "Normal".into(),
+                    scope: HighlightScope::All,
+                },
+                Problem::ConflictingScopes {
+                    name: "Comment".into(),
+                    scopes: vec![HighlightScope::All, HighlightScope::Nvim],
+                },
+                Problem::UnknownColor {
+                    name: "Error".into(),
+                    color: "blue".into(),
+                },
+                Problem::Empty {
+                    name: "Empty".into(),
+                },
+                Problem::NvimOnly {
+                    name: "NormalFloat".into(),
+                },
+            ]
+        );
+    }
+}
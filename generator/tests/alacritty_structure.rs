@@ -0,0 +1,66 @@
+use dogrun::highlight::get_palette;
+use dogrun::terminal::{write_alacritty, TerminalColors};
+use std::io::Cursor;
+
+#[test]
+fn test_alacritty_toml_structure() {
+    let colors = TerminalColors::new(&get_palette().unwrap()).unwrap();
+    let mut output = Cursor::new(Vec::new());
+
+    write_alacritty(&mut output, &colors, "dogrun").expect("Failed to write Alacritty theme");
+
+    let generated = String::from_utf8(output.into_inner()).expect("Invalid UTF-8");
+    let parsed: toml::Value = toml::from_str(&generated).expect("Failed to parse TOML");
+
+    let section = |name: &str| {
+        parsed
+            .get("colors")
+            .and_then(|colors| colors.get(name))
+            .and_then(|section| section.as_table())
+            .unwrap_or_else(|| panic!("Missing [colors.{}] section", name))
+    };
+    let color = |section_name: &str, key: &str| {
+        section(section_name)
+            .get(key)
+            .and_then(|v| v.as_str())
+            .unwrap_or_else(|| panic!("Missing colors.{}.{}", section_name, key))
+            .to_string()
+    };
+
+    assert_eq!(
+        color("primary", "background"),
+        colors.background.to_string()
+    );
+    assert_eq!(
+        color("primary", "foreground"),
+        colors.foreground.to_string()
+    );
+    assert_eq!(color("cursor", "cursor"), colors.cursor.to_string());
+    assert_eq!(color("cursor", "text"), colors.cursor_text.to_string());
+    assert_eq!(
+        color("selection", "background"),
+        colors.selection_background.to_string()
+    );
+    assert_eq!(
+        color("selection", "text"),
+        colors.selection_foreground.to_string()
+    );
+
+    let names = [
+        "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+    ];
+
+    for (section_name, ansi) in [("normal", colors.normal()), ("bright", colors.bright())] {
+        assert_eq!(section(section_name).len(), 8, "[colors.{}]", section_name);
+
+        for (name, expected) in names.iter().zip(ansi) {
+            assert_eq!(
+                color(section_name, name),
+                expected.to_string(),
+                "{}.{}",
+                section_name,
+                name
+            );
+        }
+    }
+}
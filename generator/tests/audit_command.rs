@@ -0,0 +1,69 @@
+use std::fs;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+fn audit(args: &[&str]) -> Output {
+    let temp_dir = TempDir::new().unwrap();
+    let palette = temp_dir.path().join("palette.toml");
+    let highlights = temp_dir.path().join("highlights.toml");
+
+    fs::write(
+        &palette,
+        r##"
+mainbg = "#000000"
+white = "#ffffff"
+gray = "#555555"
+"##,
+    )
+    .unwrap();
+
+    fs::write(
+        &highlights,
+        r#"
+[[section]]
+name = "general"
+highlights = [
+  { name = "Normal", fg = "white", bg = "mainbg" },
+  { name = "Comment", fg = "gray" },
+]
+"#,
+    )
+    .unwrap();
+
+    Command::new("cargo")
+        .args([
+            "run",
+            "--quiet",
+            "--",
+            "--palette",
+            palette.to_str().unwrap(),
+            "--highlights",
+            highlights.to_str().unwrap(),
+            "audit",
+        ])
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("Failed to run generator")
+}
+
+#[test]
+fn test_audit_reports_low_contrast_groups() {
+    let output = audit(&[]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert_eq!(output.status.code(), Some(1), "{}", stdout);
+    assert!(stdout.contains("dark  gui   Comment"), "{}", stdout);
+    assert!(stdout.contains("dark  cterm Comment"), "{}", stdout);
+    assert!(!stdout.contains("Normal"), "{}", stdout);
+    assert!(stdout.contains("4 of 8 pairs below"), "{}", stdout);
+}
+
+#[test]
+fn test_audit_passes_with_lower_thresholds() {
+    let output = audit(&["--min-contrast", "2", "--min-lc", "15"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("0 of 8 pairs below"), "{}", stdout);
+}
@@ -0,0 +1,332 @@
+use std::fs;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+fn run(args: &[&str]) -> Output {
+    let output = Command::new("cargo")
+        .args(["run", "--quiet", "--"])
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("Failed to execute generator");
+
+    assert!(
+        output.status.success(),
+        "Generator failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    output
+}
+
+#[test]
+fn test_generate_selected_targets() {
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path();
+
+    run(&[
+        "generate",
+        "--dir",
+        temp_path.to_str().unwrap(),
+        "--target",
+        "wezterm,lua",
+    ]);
+
+    assert!(temp_path.join("wezterm/dogrun.toml").exists());
+    assert!(temp_path.join("wezterm/dogrun-light.toml").exists());
+    assert!(temp_path.join("lua/dogrun/init.lua").exists());
+    assert!(temp_path.join("lua/dogrun/palette.lua").exists());
+    assert!(!temp_path.join("colors").exists());
+    assert!(!temp_path.join("autoload").exists());
+}
+
+#[test]
+fn test_export_prints_one_target() {
+    let output = run(&["export", "--target", "wezterm"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    // both variants, and nothing else
+    assert_eq!(stdout.matches("[colors]").count(), 2, "{}", stdout);
+    assert!(stdout.contains("name = \"dogrun\""));
+    assert!(stdout.contains("name = \"dogrun-light\""));
+    assert!(!stdout.contains("hi Normal"));
+}
+
+#[test]
+fn test_diff_reports_changed_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path();
+    let dir = temp_path.to_str().unwrap();
+
+    let diff = || {
+        Command::new("cargo")
+            .args(["run", "--quiet", "--", "diff"])
+            .args(["--dir", dir, "--target", "kitty"])
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .expect("Failed to execute generator")
+    };
+
+    run(&["generate", "--dir", dir, "--target", "kitty"]);
+
+    let output = diff();
+    assert!(output.stdout.is_empty());
+    assert_eq!(output.status.code(), Some(0));
+
+    let path = temp_path.join("kitty/dogrun.conf");
+    let content = fs::read_to_string(&path).unwrap();
+    fs::write(&path, content.replacen("color0 ", "color0 #000000 # ", 1)).unwrap();
+
+    // like diff(1), differences are reported with status 1
+    let output = diff();
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(stdout.contains("--- a/kitty/dogrun.conf"), "{}", stdout);
+    assert!(stdout.contains("+++ b/kitty/dogrun.conf"), "{}", stdout);
+    assert!(stdout.contains("-color0 #000000 # "), "{}", stdout);
+    assert!(!stdout.contains("dogrun-light.conf"), "{}", stdout);
+}
+
+#[test]
+fn test_generate_check() {
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path();
+    let dir = temp_path.to_str().unwrap();
+
+    let check = || {
+        Command::new("cargo")
+            .args(["run", "--quiet", "--", "generate", "--check"])
+            .args(["--dir", dir, "--target", "vim,foot"])
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .expect("Failed to execute generator")
+    };
+
+    // nothing generated yet: every file is reported, none is written
+    let output = check();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout.contains("+++ b/colors/dogrun.vim"), "{}", stdout);
+    assert!(stdout.contains("+++ b/foot/dogrun-light.ini"), "{}", stdout);
+    assert!(!temp_path.join("colors").exists());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("3 generated file(s) out of date"));
+
+    run(&["generate", "--dir", dir, "--target", "vim,foot"]);
+
+    let output = check();
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+
+    // a stale file is reported and left untouched
+    let path = temp_path.join("foot/dogrun.ini");
+    fs::write(&path, "stale\n").unwrap();
+
+    let output = check();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout.contains("-stale"), "{}", stdout);
+    assert!(!stdout.contains("colors/dogrun.vim"), "{}", stdout);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "stale\n");
+}
+
+#[test]
+fn test_generate_writes_nothing_on_failure() {
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path();
+
+    // the fzf target fails on a README without markers
+    fs::write(temp_path.join("README.md"), "# No markers\n").unwrap();
+
+    let output = Command::new("cargo")
+        .args(["run", "--quiet", "--", "generate"])
+        .args(["--dir", temp_path.to_str().unwrap()])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("Failed to execute generator");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("fzf:start"));
+
+    let entries: Vec<_> = fs::read_dir(temp_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(entries, vec!["README.md"]);
+    assert_eq!(
+        fs::read_to_string(temp_path.join("README.md")).unwrap(),
+        "# No markers\n"
+    );
+}
+
+#[test]
+fn test_unknown_color_names_the_writer() {
+    let temp_dir = TempDir::new().unwrap();
+    let palette = temp_dir.path().join("palette.toml");
+    let highlights = temp_dir.path().join("highlights.toml");
+
+    fs::write(&palette, "mainfg = \"#9ea3c0\"\nmainbg = \"#222433\"\n").unwrap();
+    fs::write(
+        &highlights,
+        r#"
+[[section]]
+highlights = [
+  { name = "Normal", fg = "mainfg", bg = "mainbg" },
+]
+"#,
+    )
+    .unwrap();
+
+    let output = Command::new("cargo")
+        .args(["run", "--quiet", "--"])
+        .args(["--palette", palette.to_str().unwrap()])
+        .args(["--highlights", highlights.to_str().unwrap()])
+        .args(["export", "--target", "lightline"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("Failed to execute generator");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        stderr.contains("error: unknown color \"purple\" referenced by the lightline theme"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn test_invalid_highlights_are_rejected() {
+    let temp_dir = TempDir::new().unwrap();
+    let highlights = temp_dir.path().join("highlights.toml");
+
+    fs::write(
+        &highlights,
+        r#"
+[[section]]
+highlights = [
+  { name = "Normal", fg = "mainfg" },
+  { name = "Normal", fg = "mainfg" },
+  { name = "Conflict" },
+]
+"#,
+    )
+    .unwrap();
+
+    let output = Command::new("cargo")
+        .args(["run", "--quiet", "--"])
+        .args(["--highlights", highlights.to_str().unwrap()])
+        .args(["export", "--target", "vim"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("Failed to execute generator");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert!(
+        stderr.contains("\"Normal\" is defined more than once in scope \"all\""),
+        "{}",
+        stderr
+    );
+    assert!(
+        stderr.contains("\"Conflict\" sets no color, attr or link"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn test_preview() {
+    let gui = String::from_utf8(run(&["preview"]).stdout).unwrap();
+    let cterm = String::from_utf8(run(&["preview", "--cterm"]).stdout).unwrap();
+
+    assert!(gui.starts_with("\x1b[0;38;2;158;163;192;48;2;34;36;51m Normal "));
+    // the 256-color version follows on the same line
+    assert!(gui
+        .lines()
+        .all(|line| line.contains(" \x1b[0m \x1b[0;38;5;")));
+    assert!(cterm.starts_with("\x1b[0;38;5;"));
+    assert!(!cterm.contains("\x1b[0;38;2;"));
+    assert_eq!(gui.lines().count(), cterm.lines().count());
+    assert!(gui.lines().any(|line| line.ends_with("\x1b[0m -> Search")));
+}
+
+#[test]
+fn test_preview_sample() {
+    let treesitter = String::from_utf8(run(&["preview", "--sample", "rust"]).stdout).unwrap();
+    let syntax =
+        String::from_utf8(run(&["preview", "--sample", "rust", "--syntax"]).stdout).unwrap();
+
+    let strip = |s: &str| {
+        regex::Regex::new("\x1b\\[[0-9;:]*m")
+            .unwrap()
+            .replace_all(s, "")
+            .to_string()
+    };
+    assert_eq!(strip(&treesitter), include_str!("../samples/sample.rs"));
+    assert_eq!(strip(&syntax), strip(&treesitter));
+    assert_ne!(syntax, treesitter);
+    assert!(treesitter
+        .lines()
+        .all(|line| line.is_empty() || line.ends_with("\x1b[0m")));
+}
+
+#[test]
+fn test_graph() {
+    let dot = String::from_utf8(run(&["graph"]).stdout).unwrap();
+    let mermaid = String::from_utf8(run(&["graph", "--format", "mermaid"]).stdout).unwrap();
+    let from = String::from_utf8(run(&["graph", "--from", "statuslinencbg"]).stdout).unwrap();
+
+    assert!(dot.starts_with("digraph palette {\n"));
+    assert!(dot.contains("  \"red\" -> \"errorfg\";\n"), "{}", dot);
+    assert!(mermaid.starts_with("flowchart LR\n"));
+    // nodes are referred to by id, with the name in the label
+    let id = |name: &str| {
+        let label = format!("[\"{}<br/>", name);
+        let line = mermaid.lines().find(|line| line.contains(&label)).unwrap();
+        line.trim_start().split('[').next().unwrap().to_string()
+    };
+    assert!(
+        mermaid.contains(&format!(
+            "  {} --> {}\n",
+            id("statuslinencbg"),
+            id("xlinebg")
+        )),
+        "{}",
+        mermaid
+    );
+    assert!(
+        from.contains("  \"statuslinencbg\" -> \"xlinebg\";\n"),
+        "{}",
+        from
+    );
+    assert!(!from.contains("\"mainbg\""), "{}", from);
+}
+
+#[test]
+fn test_cterm_options() {
+    let ids = |args: &[&str]| -> Vec<usize> {
+        let stdout = String::from_utf8(run(args).stdout).unwrap();
+        regex::Regex::new(r"cterm[fb]g=(\d+)")
+            .unwrap()
+            .captures_iter(&stdout)
+            .map(|c| c[1].parse().unwrap())
+            .collect()
+    };
+
+    let all = ids(&["export"]);
+    let cube = ids(&["export", "--cterm-colors", "cube"]);
+    let oklab = ids(&[
+        "export",
+        "--cterm-colors",
+        "cube",
+        "--cterm-metric",
+        "oklab",
+    ]);
+
+    assert!(all.iter().any(|id| *id < 16));
+    assert!(cube.iter().all(|id| *id >= 16));
+    assert!(oklab.iter().all(|id| *id >= 16));
+    assert_eq!(all.len(), cube.len());
+}
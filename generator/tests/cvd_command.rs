@@ -0,0 +1,86 @@
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+#[test]
+fn test_cvd_reports_collapsing_pairs() {
+    let temp_dir = TempDir::new().unwrap();
+    let palette = temp_dir.path().join("palette.toml");
+    let highlights = temp_dir.path().join("highlights.toml");
+
+    fs::write(
+        &palette,
+        r##"
+red = "#cc3333"
+green = "#339933"
+blue = "#3333cc"
+"##,
+    )
+    .unwrap();
+
+    fs::write(
+        &highlights,
+        r#"
+[[section]]
+name = "gitsigns.nvim"
+highlights = [
+  { name = "GitSignsAdd", fg = "green" },
+  { name = "GitSignsChange", fg = "blue" },
+  { name = "GitSignsDelete", fg = "red" },
+]
+
+[[section]]
+name = "built-in LSP"
+scope = "nvim"
+highlights = [
+  { name = "DiagnosticInfo", fg = "blue" },
+  { name = "DiagnosticHint", link = "DiagnosticInfo" },
+]
+"#,
+    )
+    .unwrap();
+
+    let output = Command::new("cargo")
+        .args([
+            "run",
+            "--quiet",
+            "--",
+            "--palette",
+            palette.to_str().unwrap(),
+            "--highlights",
+            highlights.to_str().unwrap(),
+            "cvd",
+        ])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("Failed to run generator");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert_eq!(output.status.code(), Some(1), "{}", stdout);
+    assert!(
+        stdout.contains("dark  deuteranopia  GitSignsAdd / GitSignsDelete"),
+        "{}",
+        stdout
+    );
+    assert!(!stdout.contains("tritanopia    GitSignsAdd / GitSignsDelete"));
+    // pairs sharing a color are reported once, not for every deficiency
+    assert!(
+        stdout.contains("dark  normal vision DiagnosticInfo / DiagnosticHint     0.0"),
+        "{}",
+        stdout
+    );
+    assert!(!stdout.contains("deuteranopia  DiagnosticInfo / DiagnosticHint"));
+    assert!(
+        stdout.contains("2 pairs closer than 10 with normal vision"),
+        "{}",
+        stdout
+    );
+    // the other pairs have no groups to compare
+    assert!(
+        stdout.contains(
+            "dark  unresolved    DiffAdd / DiffDelete              DiffAdd isn't defined"
+        ),
+        "{}",
+        stdout
+    );
+}
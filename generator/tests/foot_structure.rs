@@ -0,0 +1,77 @@
+use dogrun::conv::Rgb;
+use dogrun::highlight::get_palette;
+use dogrun::terminal::{write_foot, TerminalColors};
+use std::collections::HashMap;
+use std::io::Cursor;
+
+#[test]
+fn test_foot_ini_structure() {
+    let colors = TerminalColors::new(&get_palette().unwrap()).unwrap();
+    let mut output = Cursor::new(Vec::new());
+
+    write_foot(&mut output, &colors, "dogrun").expect("Failed to write foot theme");
+
+    let generated = String::from_utf8(output.into_inner()).expect("Invalid UTF-8");
+
+    // collect `section.key = value` entries
+    let mut entries = HashMap::new();
+    let mut section = None;
+    for line in generated.lines() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            section = Some(name.to_string());
+            continue;
+        }
+
+        let section = section
+            .as_ref()
+            .unwrap_or_else(|| panic!("Entry outside of a section: {}", line));
+        let (key, value) = line
+            .split_once('=')
+            .unwrap_or_else(|| panic!("Invalid line: {}", line));
+        entries.insert(format!("{}.{}", section, key), value.to_string());
+    }
+
+    let hex_regex = regex::Regex::new(r"^[0-9a-f]{6}$").unwrap();
+    let hex = |color: &Rgb| color.to_string().trim_start_matches('#').to_string();
+
+    for (key, value) in entries.iter() {
+        for color in value.split(' ') {
+            assert!(
+                hex_regex.is_match(color),
+                "{} has invalid color: {}",
+                key,
+                value
+            );
+        }
+    }
+
+    assert_eq!(
+        entries["cursor.color"],
+        format!("{} {}", hex(&colors.cursor_text), hex(&colors.cursor))
+    );
+    assert_eq!(entries["colors.background"], hex(&colors.background));
+    assert_eq!(entries["colors.foreground"], hex(&colors.foreground));
+    assert_eq!(
+        entries["colors.selection-background"],
+        hex(&colors.selection_background)
+    );
+    assert_eq!(
+        entries["colors.selection-foreground"],
+        hex(&colors.selection_foreground)
+    );
+
+    for i in 0..8 {
+        assert_eq!(
+            entries[&format!("colors.regular{}", i)],
+            hex(&colors.normal()[i])
+        );
+        assert_eq!(
+            entries[&format!("colors.bright{}", i)],
+            hex(&colors.bright()[i])
+        );
+    }
+}
@@ -9,7 +9,7 @@ fn test_fzf_export_format_expectations() {
     // This test documents expected format for fzf export
     // Actual generation is tested via README update integration test
 
-    let palette = get_palette();
+    let palette = get_palette().unwrap();
 
     // Verify required palette colors exist
     assert!(
@@ -42,7 +42,7 @@ fn test_fzf_export_format_expectations() {
 
 #[test]
 fn test_palette_colors_are_hex_format() {
-    let palette = get_palette();
+    let palette = get_palette().unwrap();
     let hex_regex = Regex::new(r"^#[0-9a-f]{6}$").unwrap();
 
     let required_colors = vec![
@@ -61,7 +61,7 @@ fn test_palette_colors_are_hex_format() {
             .get(color_name)
             .unwrap_or_else(|| panic!("missing color: {}", color_name));
         assert!(
-            hex_regex.is_match(&color.gui),
+            hex_regex.is_match(&color.gui.to_string()),
             "{} should be valid hex: {}",
             color_name,
             color.gui
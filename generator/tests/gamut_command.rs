@@ -0,0 +1,59 @@
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn gamut(palette: Option<&str>) -> std::process::Output {
+    let mut command = Command::new("cargo");
+    command.args(["run", "--quiet", "--"]);
+    if let Some(palette) = palette {
+        command.args(["--palette", palette]);
+    }
+
+    command
+        .arg("gamut")
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("Failed to run generator")
+}
+
+#[test]
+fn test_gamut_reports_clipped_entries() {
+    let temp_dir = TempDir::new().unwrap();
+    let palette = temp_dir.path().join("palette.toml");
+
+    fs::write(
+        &palette,
+        r##"
+mainfg = "#9ea3c0"
+mainbg = "#222433"
+brightfg = { extends = "mainfg", v = 0.4 }
+dimfg = { extends = "mainfg", v = -0.2 }
+"##,
+    )
+    .unwrap();
+
+    let output = gamut(palette.to_str());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert_eq!(output.status.code(), Some(1), "{}", stdout);
+    assert!(
+        stdout.contains("brightfg  hsv   v  1.153 -> 1.000  clipped by 0.153"),
+        "{}",
+        stdout
+    );
+    assert!(!stdout.contains("dimfg"), "{}", stdout);
+    assert!(
+        stdout.contains("1 of 2 derived entries clipped"),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn test_gamut_bundled_palette() {
+    let output = gamut(None);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.starts_with("0 of "), "{}", stdout);
+}
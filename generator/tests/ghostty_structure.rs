@@ -0,0 +1,59 @@
+use dogrun::highlight::get_palette;
+use dogrun::terminal::{write_ghostty, TerminalColors};
+use std::collections::HashMap;
+use std::io::Cursor;
+
+#[test]
+fn test_ghostty_theme_structure() {
+    let colors = TerminalColors::new(&get_palette().unwrap()).unwrap();
+    let mut output = Cursor::new(Vec::new());
+
+    write_ghostty(&mut output, &colors, "dogrun").expect("Failed to write Ghostty theme");
+
+    let generated = String::from_utf8(output.into_inner()).expect("Invalid UTF-8");
+
+    // every non-comment line is `key = value`, `palette` is repeated
+    let mut options = HashMap::new();
+    let mut palette = HashMap::new();
+    for line in generated.lines() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once(" = ")
+            .unwrap_or_else(|| panic!("Invalid line: {}", line));
+
+        if key == "palette" {
+            let (index, color) = value
+                .split_once('=')
+                .unwrap_or_else(|| panic!("Invalid palette entry: {}", value));
+            let index: usize = index.parse().expect("Invalid palette index");
+            assert!(
+                palette.insert(index, color).is_none(),
+                "Duplicate {}",
+                index
+            );
+        } else {
+            assert!(options.insert(key, value).is_none(), "Duplicate {}", key);
+        }
+    }
+
+    assert_eq!(palette.len(), 16, "palette should have 16 colors");
+    for (i, expected) in colors.ansi.iter().enumerate() {
+        assert_eq!(palette[&i], expected.to_string(), "palette {}", i);
+    }
+
+    assert_eq!(options["background"], colors.background.to_string());
+    assert_eq!(options["foreground"], colors.foreground.to_string());
+    assert_eq!(options["cursor-color"], colors.cursor.to_string());
+    assert_eq!(options["cursor-text"], colors.cursor_text.to_string());
+    assert_eq!(
+        options["selection-background"],
+        colors.selection_background.to_string()
+    );
+    assert_eq!(
+        options["selection-foreground"],
+        colors.selection_foreground.to_string()
+    );
+}
@@ -0,0 +1,132 @@
+use dogrun::highlight::{get_light_palette, get_palette};
+use plist::{Dictionary, Value};
+use std::process::Command;
+use tempfile::TempDir;
+
+const TERM_COLORS: [&str; 16] = [
+    "termblack",
+    "termmaroon",
+    "termgreen",
+    "termolive",
+    "termnavy",
+    "termpurple",
+    "termteal",
+    "termsilver",
+    "termgray",
+    "termred",
+    "termlime",
+    "termyellow",
+    "termblue",
+    "termfuchsia",
+    "termaqua",
+    "termwhite",
+];
+
+fn generate() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path();
+
+    let output = Command::new("cargo")
+        .args([
+            "run",
+            "--quiet",
+            "--",
+            "generate",
+            "--dir",
+            temp_path.to_str().unwrap(),
+            "--target",
+            "iterm",
+        ])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("Failed to execute generator");
+
+    assert!(
+        output.status.success(),
+        "Generator failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    temp_dir
+}
+
+/// Converts an iTerm2 color dictionary back into a hex string.
+fn hex(dict: &Dictionary, key: &str) -> String {
+    let color = dict
+        .get(key)
+        .unwrap_or_else(|| panic!("Missing {}", key))
+        .as_dictionary()
+        .unwrap_or_else(|| panic!("{} is not a dictionary", key));
+
+    assert_eq!(
+        color.get("Color Space").and_then(Value::as_string),
+        Some("sRGB"),
+        "{} has an invalid color space",
+        key
+    );
+    assert_eq!(
+        color.get("Alpha Component").and_then(Value::as_real),
+        Some(1.0),
+        "{} has an invalid alpha",
+        key
+    );
+
+    let component = |name: &str| {
+        let value = color
+            .get(&format!("{} Component", name))
+            .and_then(Value::as_real)
+            .unwrap_or_else(|| panic!("{} is missing its {} component", key, name));
+        assert!((0.0..=1.0).contains(&value), "{} is out of range", key);
+        (value * 255.0).round() as u8
+    };
+
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        component("Red"),
+        component("Green"),
+        component("Blue")
+    )
+}
+
+#[test]
+fn test_iterm_plist_structure() {
+    let temp_dir = generate();
+
+    for (file, palette) in [
+        ("dogrun.itermcolors", get_palette().unwrap()),
+        ("dogrun-light.itermcolors", get_light_palette().unwrap()),
+    ] {
+        let path = temp_dir.path().join("term").join(file);
+        let parsed = Value::from_file(&path).expect("Failed to parse plist");
+        let dict = parsed.as_dictionary().expect("Root is not a dictionary");
+
+        for (i, name) in TERM_COLORS.iter().enumerate() {
+            assert_eq!(
+                hex(dict, &format!("Ansi {} Color", i)),
+                palette[*name].gui.to_string(),
+                "{}: Ansi {} should be {}",
+                file,
+                i,
+                name
+            );
+        }
+
+        for (key, name) in [
+            ("Background Color", "mainbg"),
+            ("Foreground Color", "mainfg"),
+            ("Bold Color", "mainfg"),
+            ("Cursor Color", "mainfg"),
+            ("Cursor Text Color", "mainbg"),
+            ("Selection Color", "visualbg"),
+            ("Selected Text Color", "mainfg"),
+        ] {
+            assert_eq!(
+                hex(dict, key),
+                palette[name].gui.to_string(),
+                "{}: {}",
+                file,
+                key
+            );
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use dogrun::highlight::get_palette;
+use dogrun::terminal::{write_kitty, TerminalColors};
+use std::collections::HashMap;
+use std::io::Cursor;
+
+#[test]
+fn test_kitty_conf_structure() {
+    let colors = TerminalColors::new(&get_palette().unwrap()).unwrap();
+    let mut output = Cursor::new(Vec::new());
+
+    write_kitty(&mut output, &colors, "dogrun").expect("Failed to write kitty theme");
+
+    let generated = String::from_utf8(output.into_inner()).expect("Invalid UTF-8");
+    assert!(generated.contains("## name: dogrun"), "Missing theme name");
+
+    // every non-comment line is `key value`
+    let mut options = HashMap::new();
+    for line in generated.lines() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once(' ')
+            .unwrap_or_else(|| panic!("Invalid line: {}", line));
+        assert!(
+            options.insert(key, value).is_none(),
+            "Duplicate option: {}",
+            key
+        );
+    }
+
+    assert_eq!(options["foreground"], colors.foreground.to_string());
+    assert_eq!(options["background"], colors.background.to_string());
+    assert_eq!(options["cursor"], colors.cursor.to_string());
+    assert_eq!(options["cursor_text_color"], colors.cursor_text.to_string());
+    assert_eq!(
+        options["selection_background"],
+        colors.selection_background.to_string()
+    );
+    assert_eq!(
+        options["selection_foreground"],
+        colors.selection_foreground.to_string()
+    );
+
+    for (i, expected) in colors.ansi.iter().enumerate() {
+        assert_eq!(
+            options[format!("color{}", i).as_str()],
+            expected.to_string()
+        );
+    }
+
+    assert_eq!(options.len(), 22, "Unexpected options: {:?}", options);
+}
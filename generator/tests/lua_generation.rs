@@ -0,0 +1,89 @@
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn generate() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path();
+
+    fs::create_dir_all(temp_path.join("colors")).unwrap();
+    fs::create_dir_all(temp_path.join("autoload/lightline/colorscheme")).unwrap();
+    fs::create_dir_all(temp_path.join("autoload/clap/themes")).unwrap();
+
+    let output = Command::new("cargo")
+        .args([
+            "run",
+            "--quiet",
+            "--",
+            "generate",
+            "--dir",
+            temp_path.to_str().unwrap(),
+        ])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("Failed to execute generator");
+
+    assert!(
+        output.status.success(),
+        "Generator failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    temp_dir
+}
+
+/// Counts block openers and `end` keywords of the generated Lua, which is
+/// written one statement per line.
+fn assert_balanced_blocks(lua: &str) {
+    let mut depth = 0;
+
+    for line in lua.lines().map(str::trim) {
+        if line.starts_with("function ") || (line.starts_with("if ") && line.ends_with(" then")) {
+            depth += 1;
+        } else if line == "end" {
+            depth -= 1;
+        }
+        assert!(depth >= 0, "unbalanced `end`");
+    }
+
+    assert_eq!(depth, 0, "unclosed block");
+}
+
+#[test]
+fn test_lua_colorscheme_structure() {
+    let temp_dir = generate();
+    let lua = fs::read_to_string(temp_dir.path().join("lua/dogrun/init.lua")).unwrap();
+
+    assert_balanced_blocks(&lua);
+
+    assert!(lua.contains("function M.load()"));
+    assert!(lua.contains("vim.g.colors_name = 'dogrun'"));
+    assert!(lua.contains("local hl = vim.api.nvim_set_hl"));
+    assert!(lua.contains("if vim.o.background == 'light' then"));
+    assert!(lua.trim_end().ends_with("return M"));
+
+    // Both variants define the same groups, and version gated groups are
+    // guarded in both of them
+    assert_eq!(
+        lua.matches("if vim.fn.has('nvim-0.8.0') == 1 then").count(),
+        2
+    );
+    assert_eq!(lua.matches("hl(0, 'Normal', {").count(), 2);
+    assert_eq!(lua.matches("hl(0, '@string', {").count(), 2);
+
+    // "NONE" is never passed to nvim_set_hl()
+    assert!(!lua.contains("NONE"));
+}
+
+#[test]
+fn test_lua_palette_structure() {
+    let temp_dir = generate();
+    let lua = fs::read_to_string(temp_dir.path().join("lua/dogrun/palette.lua")).unwrap();
+
+    assert_balanced_blocks(&lua);
+
+    assert!(lua.contains("palettes.dark = {"));
+    assert!(lua.contains("palettes.light = {"));
+    assert!(lua.contains("  purple = '#929be5',"));
+    assert!(lua.contains("return setmetatable("));
+}
@@ -36,7 +36,14 @@ Some other content here.
 
     // Run generator
     let output = Command::new("cargo")
-        .args(["run", "--quiet", "--", "--dir", temp_path.to_str().unwrap()])
+        .args([
+            "run",
+            "--quiet",
+            "--",
+            "generate",
+            "--dir",
+            temp_path.to_str().unwrap(),
+        ])
         .current_dir(env!("CARGO_MANIFEST_DIR"))
         .output()
         .expect("Failed to execute generator");
@@ -149,7 +156,14 @@ Content after
     fs::write(temp_path.join("README.md"), test_readme).unwrap();
 
     let output = Command::new("cargo")
-        .args(["run", "--quiet", "--", "--dir", temp_path.to_str().unwrap()])
+        .args([
+            "run",
+            "--quiet",
+            "--",
+            "generate",
+            "--dir",
+            temp_path.to_str().unwrap(),
+        ])
         .current_dir(env!("CARGO_MANIFEST_DIR"))
         .output()
         .expect("Failed to execute generator");
@@ -196,7 +210,14 @@ End of file.
     fs::write(temp_path.join("README.md"), test_readme).unwrap();
 
     let output = Command::new("cargo")
-        .args(["run", "--quiet", "--", "--dir", temp_path.to_str().unwrap()])
+        .args([
+            "run",
+            "--quiet",
+            "--",
+            "generate",
+            "--dir",
+            temp_path.to_str().unwrap(),
+        ])
         .current_dir(env!("CARGO_MANIFEST_DIR"))
         .output()
         .expect("Failed to execute generator");
@@ -217,3 +238,60 @@ End of file.
     // Verify structure is maintained
     assert!(updated.contains("End of file."));
 }
+
+#[test]
+fn test_readme_fzf_light_section_update() {
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path();
+
+    fs::create_dir_all(temp_path.join("colors")).unwrap();
+    fs::create_dir_all(temp_path.join("autoload/lightline/colorscheme")).unwrap();
+    fs::create_dir_all(temp_path.join("autoload/clap/themes")).unwrap();
+
+    let test_readme = r#"<!-- fzf:start -->
+```bash
+export OLD='dark'
+```
+<!-- fzf:end -->
+
+<!-- fzf-light:start -->
+```bash
+export OLD='light'
+```
+<!-- fzf-light:end -->
+"#;
+
+    fs::write(temp_path.join("README.md"), test_readme).unwrap();
+
+    let output = Command::new("cargo")
+        .args([
+            "run",
+            "--quiet",
+            "--",
+            "generate",
+            "--dir",
+            temp_path.to_str().unwrap(),
+        ])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("Failed to execute generator");
+
+    assert!(output.status.success());
+
+    let updated = fs::read_to_string(temp_path.join("README.md")).unwrap();
+
+    // Both sections are replaced, each with its own background color
+    assert!(!updated.contains("export OLD="));
+    assert_eq!(updated.matches("export FZF_DEFAULT_OPTS=").count(), 2);
+
+    let bg = Regex::new(r",bg:(#[0-9a-f]{6}),").unwrap();
+    let backgrounds: Vec<&str> = bg
+        .captures_iter(&updated)
+        .map(|c| c.get(1).unwrap().as_str())
+        .collect();
+    assert_eq!(backgrounds.len(), 2);
+    assert_ne!(backgrounds[0], backgrounds[1]);
+
+    // The light variant is generated into the wezterm directory as well
+    assert!(temp_path.join("wezterm/dogrun-light.toml").exists());
+}
@@ -0,0 +1,1114 @@
+-- dogrun: Take a sweet dog with you.
+--
+-- Author: wadackel
+-- License: MIT
+--   Copyright (c) 2020 wadackel
+
+local M = {}
+
+function M.load()
+  if vim.g.colors_name then
+    vim.cmd('hi clear')
+  end
+
+  if vim.fn.exists('g:syntax_on') == 1 then
+    vim.cmd('syntax reset')
+  end
+
+  vim.g.colors_name = 'dogrun'
+
+  local hl = vim.api.nvim_set_hl
+
+  if vim.o.background == 'light' then
+    hl(0, 'Normal', { fg = '#464b65', ctermfg = 60, bg = '#d3d4e8', ctermbg = 189 })
+    hl(0, 'Delimiter', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'NonText', { fg = '#b2b4d8', ctermfg = 146 })
+    hl(0, 'VertSplit', { fg = '#babbd8', ctermfg = 146 })
+    hl(0, 'LineNr', { fg = '#babbd8', ctermfg = 146 })
+    hl(0, 'EndOfBuffer', { fg = '#b2b4d8', ctermfg = 146 })
+    hl(0, 'Comment', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'Cursor', { fg = '#d3d4e8', ctermfg = 189, bg = '#464b65', ctermbg = 60 })
+    hl(0, 'CursorIM', { link = 'Cursor' })
+    hl(0, 'SignColumn', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'ColorColumn', { bg = '#c4c6df', ctermbg = 189 })
+    hl(0, 'CursorColumn', { bg = '#c4c6df', ctermbg = 189 })
+    hl(0, 'CursorLine', { bg = '#c4c6df', ctermbg = 189 })
+    hl(0, 'CursorLineNr', { fg = '#8187c4', ctermfg = 104 })
+    hl(0, 'Conceal', { fg = '#74564f', ctermfg = 95, bg = '#d3d4e8', ctermbg = 189 })
+    hl(0, 'NormalFloat', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'FloatBorder', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'WinSeparator', { fg = '#b2b4d8', ctermfg = 146 })
+    hl(0, 'Folded', { fg = '#777caa', ctermfg = 103, bg = '#babbd8', ctermbg = 146 })
+    hl(0, 'FoldColumn', { fg = '#babbd8', ctermfg = 146 })
+    hl(0, 'MatchParen', { bg = '#bfc1dc', ctermbg = 146 })
+    hl(0, 'Directory', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'Underlined', { underline = true })
+    hl(0, 'String', { fg = '#044e25', ctermfg = 22 })
+    hl(0, 'Statement', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'Label', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'Function', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'Constant', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'Boolean', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'Number', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'Float', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'Title', { fg = '#534f34', ctermfg = 239, bold = true })
+    hl(0, 'Keyword', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'Identifier', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'Exception', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'Type', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'TypeDef', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'PreProc', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'Special', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'SpecialKey', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'SpecialChar', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'SpecialComment', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'Error', { fg = '#7a1e28', ctermfg = 52, bg = '#d3d4e8', ctermbg = 189, bold = true })
+    hl(0, 'ErrorMsg', { fg = '#7a1e28', ctermfg = 52, bold = true })
+    hl(0, 'WarningMsg', { fg = '#74564f', ctermfg = 95, bold = true })
+    hl(0, 'MoreMsg', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'ModeMsg', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'Debug', { fg = '#464b65', ctermfg = 60 })
+    hl(0, 'Todo', { fg = '#534f34', ctermfg = 239, bold = true })
+    hl(0, 'Pmenu', { fg = '#464b65', ctermfg = 60, bg = '#babbd8', ctermbg = 146 })
+    hl(0, 'PmenuSel', { fg = '#464b65', ctermfg = 60, bg = '#a8a9f0', ctermbg = 147 })
+    hl(0, 'PmenuMatch', { fg = '#3c4c8d', ctermfg = 61, bold = true })
+    hl(0, 'PmenuSbar', { bg = '#c6c7e0', ctermbg = 189 })
+    hl(0, 'PmenuThumb', { bg = '#959ad0', ctermbg = 104 })
+    hl(0, 'Visual', { bg = '#a8a9f0', ctermbg = 147 })
+    hl(0, 'Search', { fg = '#213a7a', ctermfg = 25, bg = '#5667d8', ctermbg = 62 })
+    hl(0, 'CurSearch', { link = 'Search' })
+    hl(0, 'IncSearch', { fg = '#153771', ctermfg = 25, bg = '#7a7ef9', ctermbg = 105 })
+    hl(0, 'Question', { fg = '#004b39', ctermfg = 23, bold = true })
+    hl(0, 'WildMenu', { fg = '#d3d4e8', ctermfg = 189, bg = '#3c4c8d', ctermbg = 61 })
+    hl(0, 'SpellBad', { fg = '#7a1e28', ctermfg = 52, underline = true })
+    hl(0, 'SpellCap', { underline = true })
+    hl(0, 'SpellLocal', { fg = '#7a1e28', ctermfg = 52, underline = true })
+    hl(0, 'SpellRare', { fg = '#534f34', ctermfg = 239, underline = true })
+    hl(0, 'Added', { bg = '#b2c5ea', ctermbg = 153 })
+    hl(0, 'Removed', { fg = '#9c82ab', ctermfg = 139, bg = '#ccacdc', ctermbg = 182 })
+    hl(0, 'Changed', { bg = '#90b4b0', ctermbg = 109 })
+    hl(0, 'DiffAdd', { bg = '#b2c5ea', ctermbg = 153 })
+    hl(0, 'DiffChange', { bg = '#90b4b0', ctermbg = 109 })
+    hl(0, 'DiffDelete', { fg = '#9c82ab', ctermfg = 139, bg = '#ccacdc', ctermbg = 182 })
+    hl(0, 'DiffText', { bg = '#5a9475', ctermbg = 65 })
+    hl(0, 'QuickFixLine', { fg = '#464b65', ctermfg = 60, bg = '#a8a9f0', ctermbg = 147 })
+    hl(0, 'StatusLine', { fg = '#676d96', ctermfg = 60, bg = '#c6c7e0', ctermbg = 189, bold = true })
+    hl(0, 'StatusLineTerm', { link = 'StatusLine' })
+    hl(0, 'StatusLineNC', { fg = '#999cbe', ctermfg = 146, bg = '#c9cbdf', ctermbg = 189 })
+    hl(0, 'StatusLineTermNC', { link = 'StatusLineNC' })
+    hl(0, 'TabLine', { fg = '#676d96', ctermfg = 60, bg = '#c6c7e0', ctermbg = 189 })
+    hl(0, 'TabLineFill', { fg = '#676d96', ctermfg = 60, bg = '#c6c7e0', ctermbg = 189 })
+    hl(0, 'TabLineSel', { fg = '#d3d4e8', ctermfg = 189, bg = '#3c4c8d', ctermbg = 61, bold = true })
+    hl(0, 'qfFileName', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'qfLineNr', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'DiagnosticError', { fg = '#7a1e28', ctermfg = 52 })
+    hl(0, 'DiagnosticVirtualTextError', { fg = '#7a1e28', ctermfg = 52, bold = true })
+    hl(0, 'DiagnosticUnderlineError', { fg = '#7a1e28', ctermfg = 52, sp = '#7a1e28', undercurl = true })
+    hl(0, 'DiagnosticWarn', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'DiagnosticVirtualTextWarn', { fg = '#74564f', ctermfg = 95, bold = true })
+    hl(0, 'DiagnosticUnderlineWarn', { fg = '#74564f', ctermfg = 95, sp = '#74564f', undercurl = true })
+    hl(0, 'DiagnosticInfo', { fg = '#003826', ctermfg = 23 })
+    hl(0, 'DiagnosticVirtualTextInfo', { fg = '#868bbe', ctermfg = 103, bold = true })
+    hl(0, 'DiagnosticUnderlineInfo', { sp = '#003826', undercurl = true })
+    hl(0, 'DiagnosticHint', { fg = '#003826', ctermfg = 23 })
+    hl(0, 'DiagnosticOk', { fg = '#003826', ctermfg = 23 })
+    hl(0, 'DiagnosticVirtualTextHint', { fg = '#868bbe', ctermfg = 103, bold = true })
+    hl(0, 'DiagnosticUnderlineHint', { sp = '#003826', undercurl = true })
+    hl(0, 'LspSignatureActiveParameter', { italic = true })
+    hl(0, 'LspReferenceText', { bg = '#bfc1dc', ctermbg = 146 })
+    hl(0, 'LspReferenceRead', { bg = '#bfc1dc', ctermbg = 146 })
+    hl(0, 'LspReferenceWrite', { bg = '#bfc1dc', ctermbg = 146 })
+    hl(0, 'htmlTag', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'htmlEndTag', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'htmlSpecialTagName', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'htmlArg', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'jsonQuote', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'yamlBlockMappingKey', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'yamlAnchor', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'pythonStatement', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'pythonBuiltin', { fg = '#005556', ctermfg = 23 })
+    hl(0, 'pythonRepeat', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'pythonOperator', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'pythonDecorator', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'pythonDecoratorName', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'zshVariableDef', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'zshFunction', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'zshKSHFunction', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'cPreCondit', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'cIncluded', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'cStorageClass', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'cppStructure', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'cppSTLnamespace', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'csStorage', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'csModifier', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'csClass', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'csClassType', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'csNewType', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'rubyConstant', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'rubySymbol', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'rubyBlockParameter', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'rubyClassName', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'rubyInstanceVariable', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'typescriptImport', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'typescriptDocRef', { fg = '#868bbe', ctermfg = 103, underline = true })
+    hl(0, 'mkdHeading', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'mkdLink', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'mkdCode', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'mkdCodeStart', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'mkdCodeEnd', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'mkdCodeDelimiter', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'tomlTable', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'rustModPath', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'rustTypedef', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'rustStructure', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'rustMacro', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'rustExternCrate', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'graphqlStructure', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'graphqlDirective', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'graphqlName', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'graphqlTemplateString', { fg = '#464b65', ctermfg = 60 })
+    hl(0, 'vimfilerOpenedFile', { fg = '#616bb0', ctermfg = 61 })
+    hl(0, 'vimfilerClosedFile', { fg = '#616bb0', ctermfg = 61 })
+    hl(0, 'vimfilerNonMark', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'vimfilerLeaf', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'DefxIconsMarkIcon', { fg = '#616bb0', ctermfg = 61 })
+    hl(0, 'DefxIconsDirectory', { fg = '#616bb0', ctermfg = 61 })
+    hl(0, 'DefxIconsParentDirectory', { fg = '#616bb0', ctermfg = 61 })
+    hl(0, 'DefxIconsSymlinkDirectory', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'DefxIconsOpenedTreeIcon', { fg = '#616bb0', ctermfg = 61 })
+    hl(0, 'DefxIconsNestedTreeIcon', { fg = '#616bb0', ctermfg = 61 })
+    hl(0, 'DefxIconsClosedTreeIcon', { fg = '#616bb0', ctermfg = 61 })
+    hl(0, 'Defx_git_Untracked', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'Defx_git_Ignored', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'Defx_git_Unknown', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'Defx_git_Renamed', { fg = '#90b4b0', ctermfg = 109 })
+    hl(0, 'Defx_git_Modified', { fg = '#90b4b0', ctermfg = 109 })
+    hl(0, 'Defx_git_Unmerged', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'Defx_git_Deleted', { fg = '#ccacdc', ctermfg = 182 })
+    hl(0, 'Defx_git_Staged', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'NvimTreeSymlink', { fg = '#2b6a59', ctermfg = 23 })
+    hl(0, 'NvimTreeSymlinkFolderName', { fg = '#2b6a59', ctermfg = 23 })
+    hl(0, 'NvimTreeFolderName', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'NvimTreeRootFolder', { fg = '#9a9ccf', ctermfg = 146, bold = true })
+    hl(0, 'NvimTreeFolderIcon', { fg = '#616bb0', ctermfg = 61 })
+    hl(0, 'NvimTreeFileIcon', { fg = '#616bb0', ctermfg = 61 })
+    hl(0, 'NvimTreeEmptyFolderName', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'NvimTreeOpenedFolderName', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'NvimTreeExecFile', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'NvimTreeOpenedHL', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'NvimTreeSpecialFile', { fg = '#606584', ctermfg = 60, bold = true })
+    hl(0, 'NvimTreeImageFile', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'NvimTreeIndentMarker', { fg = '#9a9ccf', ctermfg = 146 })
+    hl(0, 'NvimTreeModifiedIcon', { fg = '#464b65', ctermfg = 60 })
+    hl(0, 'NvimTreeGitDirtyIcon', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'NvimTreeGitStagedIcon', { fg = '#044e25', ctermfg = 22 })
+    hl(0, 'NvimTreeGitMergeIcon', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'NvimTreeGitRenamedIcon', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'NvimTreeGitNewIcon', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'NvimTreeGitDeletedIcon', { fg = '#5a9475', ctermfg = 65 })
+    hl(0, 'NvimTreeWindowPicker', { fg = '#d3d4e8', ctermfg = 189, bg = '#3c4c8d', ctermbg = 61, bold = true })
+    hl(0, 'NvimTreeNormal', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'NvimTreeLiveFilterPrefix', { fg = '#2b6a59', ctermfg = 23 })
+    hl(0, 'NvimTreeLiveFilterValue', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'NvimTreeBookmarkIcon', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'FylerBlue', { fg = '#006085', ctermfg = 24 })
+    hl(0, 'FylerGreen', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'FylerGrey', { fg = '#464b65', ctermfg = 60 })
+    hl(0, 'FylerRed', { fg = '#7a1e28', ctermfg = 52 })
+    hl(0, 'FylerYellow', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'FylerNormal', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'FylerNormalNC', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'FylerBorder', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'FylerFSDirectoryIcon', { fg = '#616bb0', ctermfg = 61, bold = true })
+    hl(0, 'FylerFSDirectoryName', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'FylerFSFile', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'FylerFSLink', { fg = '#2b6a59', ctermfg = 23 })
+    hl(0, 'FylerGitAdded', { fg = '#464b65', ctermfg = 60 })
+    hl(0, 'FylerGitConflict', {})
+    hl(0, 'FylerGitDeleted', { fg = '#5a9475', ctermfg = 65 })
+    hl(0, 'FylerGitIgnored', {})
+    hl(0, 'FylerGitModified', {})
+    hl(0, 'FylerGitRenamed', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'FylerGitStaged', { fg = '#044e25', ctermfg = 22 })
+    hl(0, 'FylerGitUnstaged', {})
+    hl(0, 'FylerGitUntracked', {})
+    hl(0, 'FylerIndentMarker', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'FylerWinPick', {})
+    hl(0, 'EdaNormal', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'EdaNormalNC', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'EdaBorder', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'EdaTitle', { fg = '#3c4c8d', ctermfg = 61, bold = true })
+    hl(0, 'EdaCursorLine', { bg = '#c4c6df', ctermbg = 189 })
+    hl(0, 'EdaIndentMarker', { fg = '#9a9ccf', ctermfg = 146 })
+    hl(0, 'EdaRootName', { fg = '#9a9ccf', ctermfg = 146, bold = true })
+    hl(0, 'EdaDivider', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'EdaFilterIndicator', { fg = '#2b6a59', ctermfg = 23 })
+    hl(0, 'EdaDirectoryName', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'EdaDirectoryIcon', { fg = '#616bb0', ctermfg = 61 })
+    hl(0, 'EdaOpenedDirectoryName', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'EdaEmptyDirectoryName', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'EdaFileName', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'EdaFileIcon', { fg = '#616bb0', ctermfg = 61 })
+    hl(0, 'EdaSymlink', { fg = '#2b6a59', ctermfg = 23 })
+    hl(0, 'EdaBrokenSymlink', { fg = '#7a1e28', ctermfg = 52 })
+    hl(0, 'EdaSymlinkTarget', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'EdaErrorNode', { fg = '#7a1e28', ctermfg = 52 })
+    hl(0, 'EdaLoadingNode', { fg = '#868bbe', ctermfg = 103, italic = true })
+    hl(0, 'EdaOpenedFile', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'EdaModifiedFile', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'EdaGitUntracked', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'EdaGitUntrackedIcon', { link = 'EdaGitUntracked' })
+    hl(0, 'EdaGitAdded', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'EdaGitAddedIcon', { link = 'EdaGitAdded' })
+    hl(0, 'EdaGitModified', { fg = '#403d1f', ctermfg = 237 })
+    hl(0, 'EdaGitModifiedIcon', { link = 'EdaGitModified' })
+    hl(0, 'EdaGitDeleted', { fg = '#8a448b', ctermfg = 96 })
+    hl(0, 'EdaGitDeletedIcon', { link = 'EdaGitDeleted' })
+    hl(0, 'EdaGitRenamed', { fg = '#403d1f', ctermfg = 237 })
+    hl(0, 'EdaGitRenamedIcon', { link = 'EdaGitRenamed' })
+    hl(0, 'EdaGitStaged', { fg = '#044e25', ctermfg = 22 })
+    hl(0, 'EdaGitStagedIcon', { link = 'EdaGitStaged' })
+    hl(0, 'EdaGitConflict', { fg = '#65463f', ctermfg = 95 })
+    hl(0, 'EdaGitConflictIcon', { link = 'EdaGitConflict' })
+    hl(0, 'EdaGitIgnored', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'EdaGitIgnoredIcon', { link = 'EdaGitIgnored' })
+    hl(0, 'EdaMarked', { fg = '#534f34', ctermfg = 239, bold = true })
+    hl(0, 'EdaCut', { fg = '#868bbe', ctermfg = 103, italic = true })
+    hl(0, 'EdaOpDeleteSign', { fg = '#7a1e28', ctermfg = 52, bold = true })
+    hl(0, 'EdaOpDeletePath', { fg = '#7a1e28', ctermfg = 52 })
+    hl(0, 'EdaOpDeleteText', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'EdaOpCreateSign', { fg = '#004b39', ctermfg = 23, bold = true })
+    hl(0, 'EdaOpCreatePath', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'EdaOpCreateText', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'EdaOpMoveSign', { fg = '#534f34', ctermfg = 239, bold = true })
+    hl(0, 'EdaOpMovePath', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'EdaOpMoveText', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'FernBranchSymbol', { fg = '#616bb0', ctermfg = 61 })
+    hl(0, 'FernBranchText', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'FernLeafSymbol', { fg = '#2b6a59', ctermfg = 23 })
+    hl(0, 'FernLeafText', { fg = '#464b65', ctermfg = 60 })
+    hl(0, 'FernMarked', { fg = '#005556', ctermfg = 23 })
+    hl(0, 'GitSignsAdd', { fg = '#044e25', ctermfg = 22 })
+    hl(0, 'GitSignsChange', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'GitSignsDelete', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'GitSignsChangeDelete', { fg = '#5a9475', ctermfg = 65 })
+    hl(0, 'GitGutterAdd', { fg = '#044e25', ctermfg = 22 })
+    hl(0, 'GitGutterChange', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'GitGutterDelete', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'GitGutterChangeDelete', { fg = '#5a9475', ctermfg = 65 })
+    hl(0, 'fugitiveHeader', { fg = '#004b39', ctermfg = 23, bold = true })
+    hl(0, 'DiffviewDim1', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'DiffviewPrimary', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'DiffviewSecondary', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'DiffviewStatusAdded', { fg = '#006085', ctermfg = 24 })
+    hl(0, 'DiffviewStatusUntracked', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'DiffviewStatusModified', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'DiffviewStatusRenamed', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'DiffviewStatusCopied', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'DiffviewStatusTypeChanged', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'DiffviewStatusUnmerged', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'DiffviewStatusUnknown', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'DiffviewStatusDeleted', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'DiffviewStatusBroken', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'DiffviewStatusIgnored', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'DiffviewFilePanelRootPath', { fg = '#616bb0', ctermfg = 61 })
+    hl(0, 'DiffviewFilePanelTitle', { fg = '#3c4c8d', ctermfg = 61, bold = true })
+    hl(0, 'DiffviewFilePanelCounter', { fg = '#606584', ctermfg = 60, bold = true })
+    hl(0, 'DiffviewFilePanelFileName', { fg = '#464b65', ctermfg = 60 })
+    hl(0, 'DiffviewFilePanelPath', { fg = '#868bbe', ctermfg = 103, bold = true })
+    hl(0, 'DiffviewFilePanelSelected', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'DiffviewFilePanelInsertions', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'DiffviewFilePanelDeletions', { fg = '#8f4b90', ctermfg = 96 })
+    hl(0, 'DiffviewFilePanelConflicts', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'DiffviewHash', { fg = '#616bb0', ctermfg = 61 })
+    hl(0, 'ALEWarningSign', { fg = '#74564f', ctermfg = 95, bold = true })
+    hl(0, 'ALEInfoSign', { fg = '#003826', ctermfg = 23 })
+    hl(0, 'NullLsInfoBorder', { fg = '#868bbe', ctermfg = 103, bg = '#d3d4e8', ctermbg = 189 })
+    hl(0, 'CocErrorSign', { fg = '#7a1e28', ctermfg = 52, bold = true })
+    hl(0, 'CocWarningSign', { fg = '#74564f', ctermfg = 95, bold = true })
+    hl(0, 'CocInfoSign', { fg = '#003826', ctermfg = 23, bold = true })
+    hl(0, 'CocHintSign', { fg = '#003826', ctermfg = 23, bold = true })
+    hl(0, 'LspError', { fg = '#7a1e28', ctermfg = 52 })
+    hl(0, 'LspErrorText', { fg = '#7a1e28', ctermfg = 52, bold = true })
+    hl(0, 'LspErrorHighlight', { sp = '#7a1e28', undercurl = true })
+    hl(0, 'LspErrorVirtualText', { fg = '#7a1e28', ctermfg = 52, bold = true })
+    hl(0, 'LspWarning', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'LspWarningText', { fg = '#74564f', ctermfg = 95, bold = true })
+    hl(0, 'LspWarningHighlight', { sp = '#74564f', undercurl = true })
+    hl(0, 'LspWarningVirtualText', { fg = '#74564f', ctermfg = 95, bold = true })
+    hl(0, 'LspInformation', { fg = '#003826', ctermfg = 23 })
+    hl(0, 'LspInformationText', { fg = '#003826', ctermfg = 23, bold = true })
+    hl(0, 'LspInformationHighlight', { sp = '#003826', undercurl = true })
+    hl(0, 'LspInformationVirtualText', { fg = '#868bbe', ctermfg = 103, bold = true })
+    hl(0, 'LspHint', { fg = '#003826', ctermfg = 23 })
+    hl(0, 'LspHintText', { fg = '#003826', ctermfg = 23, bold = true })
+    hl(0, 'LspHintHighlight', { sp = '#003826', undercurl = true })
+    hl(0, 'LspHintVirtualText', { fg = '#868bbe', ctermfg = 103, bold = true })
+    hl(0, 'LspCodeActionText', { fg = '#616bb0', ctermfg = 61, bold = true })
+    hl(0, 'CmpItemAbbr', { fg = '#464b65', ctermfg = 60 })
+    hl(0, 'CmpItemAbbrMatch', { fg = '#3c4c8d', ctermfg = 61, bold = true })
+    hl(0, 'CmpItemAbbrMatchFuzzy', { link = 'CmpItemAbbrMatch' })
+    hl(0, 'CmpItemAbbrDeprecated', { fg = '#868bbe', ctermfg = 103, strikethrough = true })
+    hl(0, 'CmpItemMenu', { fg = '#868bbe', ctermfg = 103, italic = true })
+    hl(0, 'CmpItemKind', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'CmpItemKindText', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'CmpItemKindVariable', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'CmpItemKindConstant', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'CmpItemKindEnum', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'CmpItemKindInterface', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'CmpItemKindClass', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'CmpItemKindFunction', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'CmpItemKindMethod', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'CmpItemKindModule', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'CmpItemKindConstructor', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'CmpItemKindKeyword', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'CmpItemKindProperty', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'CmpItemKindField', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'CmpItemKindUnit', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'BlinkCmpMenu', { fg = '#464b65', ctermfg = 60 })
+    hl(0, 'BlinkCmpMenuSelection', { bg = '#a8a9f0', ctermbg = 147 })
+    hl(0, 'BlinkCmpLabelMatch', { fg = '#3c4c8d', ctermfg = 61, bold = true })
+    hl(0, 'BlinkCmpLabelDeprecated', { fg = '#868bbe', ctermfg = 103, strikethrough = true })
+    hl(0, 'BlinkCmpKind', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'BlinkCmpKindText', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'BlinkCmpKindVariable', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'BlinkCmpKindConstant', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'BlinkCmpKindEnum', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'BlinkCmpKindInterface', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'BlinkCmpKindClass', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'BlinkCmpKindFunction', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'BlinkCmpKindMethod', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'BlinkCmpKindModule', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'BlinkCmpKindConstructor', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'BlinkCmpKindKeyword', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'BlinkCmpKindProperty', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'BlinkCmpKindField', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'BlinkCmpKindUnit', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'BlinkCmpSource', { fg = '#868bbe', ctermfg = 103, italic = true })
+    hl(0, 'BlinkCmpDocSeparator', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'BlinkCmpMenuBorder', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'BlinkCmpDocBorder', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'BlinkCmpSignatureHelpBorder', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'FloatTitle', { fg = '#3c4c8d', ctermfg = 61, bg = '#d3d4e8', ctermbg = 189 })
+    hl(0, 'TelescopeNormal', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'TelescopeTitle', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'TelescopeMatching', { fg = '#29314c', ctermfg = 236, bold = true })
+    hl(0, 'TelescopeBorder', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'TelescopePromptPrefix', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'TelescopePromptCounter', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'TelescopeMultiIcon', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'TelescopeMultiSelection', { fg = '#534f34', ctermfg = 239 })
+    hl(0, 'SnacksNormal', { fg = '#606584', ctermfg = 60 })
+    hl(0, 'SnacksPickerPrompt', { fg = '#004b39', ctermfg = 23 })
+    hl(0, 'SnacksPickerMatch', { fg = '#3c4c8d', ctermfg = 61 })
+    hl(0, 'SnacksPickerDir', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'CopilotSuggestion', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'CleverFChar', { fg = '#213a7a', ctermfg = 25, bg = '#5667d8', ctermbg = 62, underline = true })
+    hl(0, 'MiniJump', { fg = '#213a7a', ctermfg = 25, bg = '#5667d8', ctermbg = 62, underline = true })
+    hl(0, 'ConflictMarkerBegin', { bg = '#2b6a59', ctermbg = 23, bold = true })
+    hl(0, 'ConflictMarkerOurs', { bg = '#96baad', ctermbg = 109 })
+    hl(0, 'ConflictMarkerTheirs', { bg = '#a4c1d6', ctermbg = 110 })
+    hl(0, 'ConflictMarkerEnd', { bg = '#5285a3', ctermbg = 67, bold = true })
+    hl(0, 'ConflictMarkerSeparator', { fg = '#b2b4d8', ctermfg = 146, bold = true })
+    hl(0, 'EasyMotionTarget', { fg = '#534f34', ctermfg = 239, bold = true })
+    hl(0, 'EasyMotionShade', { fg = '#868bbe', ctermfg = 103, bg = '#d3d4e8', ctermbg = 189 })
+    hl(0, 'EasyMotionIncCursor', { fg = '#464b65', ctermfg = 60, bg = '#d3d4e8', ctermbg = 189 })
+    hl(0, 'HopNextKey', { fg = '#004b39', ctermfg = 23, bold = true })
+    hl(0, 'HopNextKey1', { link = 'HopNextKey' })
+    hl(0, 'HopNextKey2', { fg = '#2b6a59', ctermfg = 23, bold = true })
+    hl(0, 'HopUnmatched', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'FlashPrompt', { fg = '#3c4c8d', ctermfg = 61, bold = true })
+    hl(0, 'FlashPromptIcon', { fg = '#004b39', ctermfg = 23, bold = true })
+    hl(0, 'FlashLabel', { fg = '#004b39', ctermfg = 23, bold = true })
+    hl(0, 'FidgetTitle', { fg = '#004b39', ctermfg = 23, bold = true })
+    hl(0, 'FidgetTask', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'HlSearchLens', { fg = '#868bbe', ctermfg = 103, italic = true })
+    hl(0, 'HlSearchLensNear', { link = 'HlSearchLens' })
+    hl(0, 'NotifyBackground', { bg = '#d3d4e8', ctermbg = 189 })
+    hl(0, 'NotifyERRORBorder', { fg = '#7d4445', ctermfg = 95 })
+    hl(0, 'NotifyWARNBorder', { fg = '#8a7d78', ctermfg = 8 })
+    hl(0, 'NotifyINFOBorder', { fg = '#477165', ctermfg = 6 })
+    hl(0, 'NotifyDEBUGBorder', { fg = '#686a72', ctermfg = 242 })
+    hl(0, 'NotifyTRACEBorder', { fg = '#477165', ctermfg = 6 })
+    hl(0, 'NotifyERRORIcon', { fg = '#7a1e28', ctermfg = 52 })
+    hl(0, 'NotifyWARNIcon', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'NotifyINFOIcon', { fg = '#003826', ctermfg = 23 })
+    hl(0, 'NotifyDEBUGIcon', { fg = '#464b65', ctermfg = 60 })
+    hl(0, 'NotifyTRACEIcon', { fg = '#003826', ctermfg = 23 })
+    hl(0, 'NotifyERRORTitle', { fg = '#7a1e28', ctermfg = 52 })
+    hl(0, 'NotifyWARNTitle', { fg = '#74564f', ctermfg = 95 })
+    hl(0, 'NotifyINFOTitle', { fg = '#003826', ctermfg = 23 })
+    hl(0, 'NotifyDEBUGTitle', { fg = '#464b65', ctermfg = 60 })
+    hl(0, 'NotifyTRACETitle', { fg = '#003826', ctermfg = 23 })
+    hl(0, 'NotifyERRORBody', { fg = '#464b65', ctermfg = 60 })
+    hl(0, 'NotifyWARNBody', { fg = '#464b65', ctermfg = 60 })
+    hl(0, 'NotifyINFOBody', { fg = '#464b65', ctermfg = 60 })
+    hl(0, 'NotifyDEBUGBody', { fg = '#464b65', ctermfg = 60 })
+    hl(0, 'NotifyTRACEBody', { fg = '#464b65', ctermfg = 60 })
+    hl(0, 'AvanteTitle', { fg = '#d3d4e8', ctermfg = 189, bg = '#3c4c8d', ctermbg = 61 })
+    hl(0, 'AvanteReversedTitle', { fg = '#3c4c8d', ctermfg = 61, bg = '#d3d4e8', ctermbg = 189 })
+    hl(0, 'AvanteSubtitle', { fg = '#d3d4e8', ctermfg = 189, bg = '#004b39', ctermbg = 23 })
+    hl(0, 'AvanteReversedSubtitle', { fg = '#004b39', ctermfg = 23, bg = '#d3d4e8', ctermbg = 189 })
+    hl(0, 'AvanteThirdTitle', { fg = '#464b65', ctermfg = 60, bg = '#babbd8', ctermbg = 146 })
+    hl(0, 'AvanteReversedThirdTitle', { fg = '#babbd8', ctermfg = 146, bg = '#d3d4e8', ctermbg = 189 })
+    hl(0, 'AvantePopupHint', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'AvanteInlineHint', { fg = '#868bbe', ctermfg = 103 })
+    hl(0, 'AvanteSidebarWinSeparator', { fg = '#b2b4d8', ctermfg = 146 })
+    hl(0, 'AvanteSidebarWinHorizontalSeparator', { fg = '#d3d4e8', ctermfg = 189, bg = '#d3d4e8', ctermbg = 189 })
+    if vim.fn.has('nvim-0.8.0') == 1 then
+      hl(0, '@string', { fg = '#044e25', ctermfg = 22 })
+      hl(0, '@string.regex', { link = '@string' })
+      hl(0, '@string.escape', { fg = '#8f4b90', ctermfg = 96 })
+      hl(0, '@string.special.url', { fg = '#868bbe', ctermfg = 103 })
+      hl(0, '@text.title', { fg = '#534f34', ctermfg = 239, bold = true })
+      hl(0, '@text.reference', { fg = '#3c4c8d', ctermfg = 61 })
+      hl(0, '@text.uri', { fg = '#868bbe', ctermfg = 103 })
+      hl(0, '@text.strong', { bold = true })
+      hl(0, '@text.literal', { fg = '#004b39', ctermfg = 23 })
+      hl(0, '@parameter', { fg = '#3c4c8d', ctermfg = 61 })
+      hl(0, '@property', { fg = '#3c4c8d', ctermfg = 61 })
+      hl(0, '@keyword', { fg = '#8f4b90', ctermfg = 96 })
+      hl(0, '@operator', { fg = '#3c4c8d', ctermfg = 61 })
+      hl(0, '@module', { fg = '#464b65', ctermfg = 60 })
+      hl(0, '@type', { fg = '#74564f', ctermfg = 95 })
+      hl(0, '@type.builtin', { link = '@type' })
+      hl(0, '@function.tsx', { fg = '#464b65', ctermfg = 60 })
+      hl(0, '@punctuation.special.typescript', { fg = '#606584', ctermfg = 60 })
+      hl(0, '@include', { fg = '#3c4c8d', ctermfg = 61 })
+      hl(0, '@variable', { fg = '#464b65', ctermfg = 60 })
+      hl(0, '@variable.builtin', { fg = '#74564f', ctermfg = 95 })
+      hl(0, '@constant.builtin', { fg = '#004b39', ctermfg = 23 })
+      hl(0, '@constructor', { fg = '#464b65', ctermfg = 60 })
+      hl(0, '@tag', { fg = '#464b65', ctermfg = 60 })
+      hl(0, '@tag.delimiter', { fg = '#3c4c8d', ctermfg = 61 })
+      hl(0, '@tag.attribute', { fg = '#3c4c8d', ctermfg = 61 })
+      hl(0, '@tag.builtin.tsx', { fg = '#464b65', ctermfg = 60 })
+      hl(0, '@markup.heading', { fg = '#534f34', ctermfg = 239, bold = true })
+      hl(0, '@markup.strong', { bold = true })
+      hl(0, '@markup.list', { fg = '#868bbe', ctermfg = 103 })
+      hl(0, '@markup.raw', { fg = '#004b39', ctermfg = 23 })
+      hl(0, '@markup.link', { fg = '#3c4c8d', ctermfg = 61 })
+      hl(0, '@markup.link.url', { fg = '#606584', ctermfg = 60 })
+      hl(0, '@markup.quote', { fg = '#868bbe', ctermfg = 103 })
+      hl(0, '@lsp.type.class', { fg = '#464b65', ctermfg = 60 })
+      hl(0, '@lsp.type.interface', { fg = '#74564f', ctermfg = 95 })
+      hl(0, '@lsp.type.parameter', { fg = '#3c4c8d', ctermfg = 61 })
+      hl(0, '@lsp.type.property', { fg = '#3c4c8d', ctermfg = 61 })
+      hl(0, '@lsp.type.struct', { fg = '#464b65', ctermfg = 60 })
+      hl(0, '@lsp.type.type', { fg = '#74564f', ctermfg = 95 })
+      hl(0, '@lsp.type.typeParameter', { fg = '#464b65', ctermfg = 60 })
+      hl(0, '@lsp.type.variable', { fg = '#464b65', ctermfg = 60 })
+      hl(0, '@lsp.type.member', { fg = '#3c4c8d', ctermfg = 61 })
+      hl(0, '@lsp.type.namespace', { fg = '#464b65', ctermfg = 60 })
+    end
+    vim.g.terminal_color_0 = '#edeef9'
+    vim.g.terminal_color_1 = '#893338'
+    vim.g.terminal_color_2 = '#044e25'
+    vim.g.terminal_color_3 = '#6a654d'
+    vim.g.terminal_color_4 = '#317193'
+    vim.g.terminal_color_5 = '#5c69bb'
+    vim.g.terminal_color_6 = '#004b39'
+    vim.g.terminal_color_7 = '#464b65'
+    vim.g.terminal_color_8 = '#868bbe'
+    vim.g.terminal_color_9 = '#8f4b90'
+    vim.g.terminal_color_10 = '#044e25'
+    vim.g.terminal_color_11 = '#534f34'
+    vim.g.terminal_color_12 = '#006085'
+    vim.g.terminal_color_13 = '#3c4c8d'
+    vim.g.terminal_color_14 = '#005556'
+    vim.g.terminal_color_15 = '#464b65'
+    vim.g.terminal_color_background = vim.g.terminal_color_0
+    vim.g.terminal_color_foreground = vim.g.terminal_color_7
+    vim.g.defx_icons_gui_colors = {
+      brown = 'c94b4c',
+      aqua = '226667',
+      blue = '366a86',
+      darkBlue = '638193',
+      purple = '616bb0',
+      lightPurple = '474f79',
+      red = '893338',
+      beige = '868685',
+      yellow = '6a654d',
+      orange = '614139',
+      darkOrange = 'ab877e',
+      pink = '9f619f',
+      salmon = 'aa57aa',
+      green = '396d48',
+      lightGreen = '196734',
+      white = '5b5f77',
+    }
+    vim.g.defx_icons_term_colors = {
+      brown = 167,
+      aqua = 23,
+      blue = 24,
+      darkBlue = 67,
+      purple = 61,
+      lightPurple = 60,
+      red = 1,
+      beige = 102,
+      yellow = 241,
+      orange = 95,
+      darkOrange = 138,
+      pink = 133,
+      salmon = 133,
+      green = 29,
+      lightGreen = 22,
+      white = 60,
+    }
+  else
+    hl(0, 'Normal', { fg = '#9ea3c0', ctermfg = 146, bg = '#222433', ctermbg = 235 })
+    hl(0, 'Delimiter', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'NonText', { fg = '#373b59', ctermfg = 60 })
+    hl(0, 'VertSplit', { fg = '#33364d', ctermfg = 237 })
+    hl(0, 'LineNr', { fg = '#33364d', ctermfg = 237 })
+    hl(0, 'EndOfBuffer', { fg = '#373b59', ctermfg = 60 })
+    hl(0, 'Comment', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'Cursor', { fg = '#222433', ctermfg = 235, bg = '#9ea3c0', ctermbg = 146 })
+    hl(0, 'CursorIM', { link = 'Cursor' })
+    hl(0, 'SignColumn', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'ColorColumn', { bg = '#2b2e41', ctermbg = 236 })
+    hl(0, 'CursorColumn', { bg = '#2b2e41', ctermbg = 236 })
+    hl(0, 'CursorLine', { bg = '#2b2e41', ctermbg = 236 })
+    hl(0, 'CursorLineNr', { fg = '#575f99', ctermfg = 61 })
+    hl(0, 'Conceal', { fg = '#ac8b83', ctermfg = 138, bg = '#222433', ctermbg = 235 })
+    hl(0, 'NormalFloat', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'FloatBorder', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'WinSeparator', { fg = '#373b59', ctermfg = 60 })
+    hl(0, 'Folded', { fg = '#666c99', ctermfg = 60, bg = '#33364d', ctermbg = 237 })
+    hl(0, 'FoldColumn', { fg = '#33364d', ctermfg = 237 })
+    hl(0, 'MatchParen', { bg = '#2f3248', ctermbg = 236 })
+    hl(0, 'Directory', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'Underlined', { underline = true })
+    hl(0, 'String', { fg = '#7cbe8c', ctermfg = 108 })
+    hl(0, 'Statement', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'Label', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'Function', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'Constant', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'Boolean', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'Number', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'Float', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'Title', { fg = '#a8a384', ctermfg = 144, bold = true })
+    hl(0, 'Keyword', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'Identifier', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'Exception', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'Type', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'TypeDef', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'PreProc', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'Special', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'SpecialKey', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'SpecialChar', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'SpecialComment', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'Error', { fg = '#ff9494', ctermfg = 210, bg = '#222433', ctermbg = 235, bold = true })
+    hl(0, 'ErrorMsg', { fg = '#ff9494', ctermfg = 210, bold = true })
+    hl(0, 'WarningMsg', { fg = '#ac8b83', ctermfg = 138, bold = true })
+    hl(0, 'MoreMsg', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'ModeMsg', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'Debug', { fg = '#9ea3c0', ctermfg = 146 })
+    hl(0, 'Todo', { fg = '#a8a384', ctermfg = 144, bold = true })
+    hl(0, 'Pmenu', { fg = '#9ea3c0', ctermfg = 146, bg = '#33364d', ctermbg = 237 })
+    hl(0, 'PmenuSel', { fg = '#9ea3c0', ctermfg = 146, bg = '#363e7a', ctermbg = 60 })
+    hl(0, 'PmenuMatch', { fg = '#929be5', ctermfg = 104, bold = true })
+    hl(0, 'PmenuSbar', { bg = '#2b2d40', ctermbg = 236 })
+    hl(0, 'PmenuThumb', { bg = '#484f80', ctermbg = 60 })
+    hl(0, 'Visual', { bg = '#363e7a', ctermbg = 60 })
+    hl(0, 'Search', { fg = '#a7b1ff', ctermfg = 147, bg = '#6472e5', ctermbg = 63 })
+    hl(0, 'CurSearch', { link = 'Search' })
+    hl(0, 'IncSearch', { fg = '#a7b8ff', ctermfg = 147, bg = '#4957cc', ctermbg = 62 })
+    hl(0, 'Question', { fg = '#73c1a9', ctermfg = 79, bold = true })
+    hl(0, 'WildMenu', { fg = '#222433', ctermfg = 235, bg = '#929be5', ctermbg = 104 })
+    hl(0, 'SpellBad', { fg = '#ff9494', ctermfg = 210, underline = true })
+    hl(0, 'SpellCap', { underline = true })
+    hl(0, 'SpellLocal', { fg = '#ff9494', ctermfg = 210, underline = true })
+    hl(0, 'SpellRare', { fg = '#a8a384', ctermfg = 144, underline = true })
+    hl(0, 'Added', { bg = '#1d3350', ctermbg = 236 })
+    hl(0, 'Removed', { fg = '#735a81', ctermfg = 96, bg = '#4c325b', ctermbg = 53 })
+    hl(0, 'Changed', { bg = '#2a4b48', ctermbg = 23 })
+    hl(0, 'DiffAdd', { bg = '#1d3350', ctermbg = 236 })
+    hl(0, 'DiffChange', { bg = '#2a4b48', ctermbg = 23 })
+    hl(0, 'DiffDelete', { fg = '#735a81', ctermfg = 96, bg = '#4c325b', ctermbg = 53 })
+    hl(0, 'DiffText', { bg = '#387154', ctermbg = 29 })
+    hl(0, 'QuickFixLine', { fg = '#9ea3c0', ctermfg = 146, bg = '#363e7a', ctermbg = 60 })
+    hl(0, 'StatusLine', { fg = '#777ca6', ctermfg = 103, bg = '#2b2d40', ctermbg = 236, bold = true })
+    hl(0, 'StatusLineTerm', { link = 'StatusLine' })
+    hl(0, 'StatusLineNC', { fg = '#4c506e', ctermfg = 60, bg = '#292b3b', ctermbg = 236 })
+    hl(0, 'StatusLineTermNC', { link = 'StatusLineNC' })
+    hl(0, 'TabLine', { fg = '#777ca6', ctermfg = 103, bg = '#2b2d40', ctermbg = 236 })
+    hl(0, 'TabLineFill', { fg = '#777ca6', ctermfg = 103, bg = '#2b2d40', ctermbg = 236 })
+    hl(0, 'TabLineSel', { fg = '#222433', ctermfg = 235, bg = '#929be5', ctermbg = 104, bold = true })
+    hl(0, 'qfFileName', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'qfLineNr', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'DiagnosticError', { fg = '#ff9494', ctermfg = 210 })
+    hl(0, 'DiagnosticVirtualTextError', { fg = '#ff9494', ctermfg = 210, bold = true })
+    hl(0, 'DiagnosticUnderlineError', { fg = '#ff9494', ctermfg = 210, sp = '#ff9494', undercurl = true })
+    hl(0, 'DiagnosticWarn', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'DiagnosticVirtualTextWarn', { fg = '#ac8b83', ctermfg = 138, bold = true })
+    hl(0, 'DiagnosticUnderlineWarn', { fg = '#ac8b83', ctermfg = 138, sp = '#ac8b83', undercurl = true })
+    hl(0, 'DiagnosticInfo', { fg = '#82dabf', ctermfg = 115 })
+    hl(0, 'DiagnosticVirtualTextInfo', { fg = '#565d8c', ctermfg = 60, bold = true })
+    hl(0, 'DiagnosticUnderlineInfo', { sp = '#82dabf', undercurl = true })
+    hl(0, 'DiagnosticHint', { fg = '#82dabf', ctermfg = 115 })
+    hl(0, 'DiagnosticOk', { fg = '#82dabf', ctermfg = 115 })
+    hl(0, 'DiagnosticVirtualTextHint', { fg = '#565d8c', ctermfg = 60, bold = true })
+    hl(0, 'DiagnosticUnderlineHint', { sp = '#82dabf', undercurl = true })
+    hl(0, 'LspSignatureActiveParameter', { italic = true })
+    hl(0, 'LspReferenceText', { bg = '#2f3248', ctermbg = 236 })
+    hl(0, 'LspReferenceRead', { bg = '#2f3248', ctermbg = 236 })
+    hl(0, 'LspReferenceWrite', { bg = '#2f3248', ctermbg = 236 })
+    hl(0, 'htmlTag', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'htmlEndTag', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'htmlSpecialTagName', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'htmlArg', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'jsonQuote', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'yamlBlockMappingKey', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'yamlAnchor', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'pythonStatement', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'pythonBuiltin', { fg = '#59b6b6', ctermfg = 73 })
+    hl(0, 'pythonRepeat', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'pythonOperator', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'pythonDecorator', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'pythonDecoratorName', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'zshVariableDef', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'zshFunction', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'zshKSHFunction', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'cPreCondit', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'cIncluded', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'cStorageClass', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'cppStructure', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'cppSTLnamespace', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'csStorage', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'csModifier', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'csClass', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'csClassType', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'csNewType', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'rubyConstant', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'rubySymbol', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'rubyBlockParameter', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'rubyClassName', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'rubyInstanceVariable', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'typescriptImport', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'typescriptDocRef', { fg = '#565d8c', ctermfg = 60, underline = true })
+    hl(0, 'mkdHeading', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'mkdLink', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'mkdCode', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'mkdCodeStart', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'mkdCodeEnd', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'mkdCodeDelimiter', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'tomlTable', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'rustModPath', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'rustTypedef', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'rustStructure', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'rustMacro', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'rustExternCrate', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'graphqlStructure', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'graphqlDirective', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'graphqlName', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'graphqlTemplateString', { fg = '#9ea3c0', ctermfg = 146 })
+    hl(0, 'vimfilerOpenedFile', { fg = '#7079bf', ctermfg = 104 })
+    hl(0, 'vimfilerClosedFile', { fg = '#7079bf', ctermfg = 104 })
+    hl(0, 'vimfilerNonMark', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'vimfilerLeaf', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'DefxIconsMarkIcon', { fg = '#7079bf', ctermfg = 104 })
+    hl(0, 'DefxIconsDirectory', { fg = '#7079bf', ctermfg = 104 })
+    hl(0, 'DefxIconsParentDirectory', { fg = '#7079bf', ctermfg = 104 })
+    hl(0, 'DefxIconsSymlinkDirectory', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'DefxIconsOpenedTreeIcon', { fg = '#7079bf', ctermfg = 104 })
+    hl(0, 'DefxIconsNestedTreeIcon', { fg = '#7079bf', ctermfg = 104 })
+    hl(0, 'DefxIconsClosedTreeIcon', { fg = '#7079bf', ctermfg = 104 })
+    hl(0, 'Defx_git_Untracked', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'Defx_git_Ignored', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'Defx_git_Unknown', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'Defx_git_Renamed', { fg = '#2a4b48', ctermfg = 23 })
+    hl(0, 'Defx_git_Modified', { fg = '#2a4b48', ctermfg = 23 })
+    hl(0, 'Defx_git_Unmerged', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'Defx_git_Deleted', { fg = '#4c325b', ctermfg = 53 })
+    hl(0, 'Defx_git_Staged', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'NvimTreeSymlink', { fg = '#5c9b88', ctermfg = 72 })
+    hl(0, 'NvimTreeSymlinkFolderName', { fg = '#5c9b88', ctermfg = 72 })
+    hl(0, 'NvimTreeFolderName', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'NvimTreeRootFolder', { fg = '#484d7a', ctermfg = 60, bold = true })
+    hl(0, 'NvimTreeFolderIcon', { fg = '#7079bf', ctermfg = 104 })
+    hl(0, 'NvimTreeFileIcon', { fg = '#7079bf', ctermfg = 104 })
+    hl(0, 'NvimTreeEmptyFolderName', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'NvimTreeOpenedFolderName', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'NvimTreeExecFile', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'NvimTreeOpenedHL', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'NvimTreeSpecialFile', { fg = '#8186a6', ctermfg = 103, bold = true })
+    hl(0, 'NvimTreeImageFile', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'NvimTreeIndentMarker', { fg = '#484d7a', ctermfg = 60 })
+    hl(0, 'NvimTreeModifiedIcon', { fg = '#9ea3c0', ctermfg = 146 })
+    hl(0, 'NvimTreeGitDirtyIcon', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'NvimTreeGitStagedIcon', { fg = '#7cbe8c', ctermfg = 108 })
+    hl(0, 'NvimTreeGitMergeIcon', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'NvimTreeGitRenamedIcon', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'NvimTreeGitNewIcon', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'NvimTreeGitDeletedIcon', { fg = '#387154', ctermfg = 29 })
+    hl(0, 'NvimTreeWindowPicker', { fg = '#222433', ctermfg = 235, bg = '#929be5', ctermbg = 104, bold = true })
+    hl(0, 'NvimTreeNormal', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'NvimTreeLiveFilterPrefix', { fg = '#5c9b88', ctermfg = 72 })
+    hl(0, 'NvimTreeLiveFilterValue', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'NvimTreeBookmarkIcon', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'FylerBlue', { fg = '#589ec6', ctermfg = 74 })
+    hl(0, 'FylerGreen', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'FylerGrey', { fg = '#9ea3c0', ctermfg = 146 })
+    hl(0, 'FylerRed', { fg = '#ff9494', ctermfg = 210 })
+    hl(0, 'FylerYellow', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'FylerNormal', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'FylerNormalNC', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'FylerBorder', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'FylerFSDirectoryIcon', { fg = '#7079bf', ctermfg = 104, bold = true })
+    hl(0, 'FylerFSDirectoryName', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'FylerFSFile', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'FylerFSLink', { fg = '#5c9b88', ctermfg = 72 })
+    hl(0, 'FylerGitAdded', { fg = '#9ea3c0', ctermfg = 146 })
+    hl(0, 'FylerGitConflict', {})
+    hl(0, 'FylerGitDeleted', { fg = '#387154', ctermfg = 29 })
+    hl(0, 'FylerGitIgnored', {})
+    hl(0, 'FylerGitModified', {})
+    hl(0, 'FylerGitRenamed', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'FylerGitStaged', { fg = '#7cbe8c', ctermfg = 108 })
+    hl(0, 'FylerGitUnstaged', {})
+    hl(0, 'FylerGitUntracked', {})
+    hl(0, 'FylerIndentMarker', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'FylerWinPick', {})
+    hl(0, 'EdaNormal', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'EdaNormalNC', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'EdaBorder', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'EdaTitle', { fg = '#929be5', ctermfg = 104, bold = true })
+    hl(0, 'EdaCursorLine', { bg = '#2b2e41', ctermbg = 236 })
+    hl(0, 'EdaIndentMarker', { fg = '#484d7a', ctermfg = 60 })
+    hl(0, 'EdaRootName', { fg = '#484d7a', ctermfg = 60, bold = true })
+    hl(0, 'EdaDivider', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'EdaFilterIndicator', { fg = '#5c9b88', ctermfg = 72 })
+    hl(0, 'EdaDirectoryName', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'EdaDirectoryIcon', { fg = '#7079bf', ctermfg = 104 })
+    hl(0, 'EdaOpenedDirectoryName', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'EdaEmptyDirectoryName', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'EdaFileName', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'EdaFileIcon', { fg = '#7079bf', ctermfg = 104 })
+    hl(0, 'EdaSymlink', { fg = '#5c9b88', ctermfg = 72 })
+    hl(0, 'EdaBrokenSymlink', { fg = '#ff9494', ctermfg = 210 })
+    hl(0, 'EdaSymlinkTarget', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'EdaErrorNode', { fg = '#ff9494', ctermfg = 210 })
+    hl(0, 'EdaLoadingNode', { fg = '#565d8c', ctermfg = 60, italic = true })
+    hl(0, 'EdaOpenedFile', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'EdaModifiedFile', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'EdaGitUntracked', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'EdaGitUntrackedIcon', { link = 'EdaGitUntracked' })
+    hl(0, 'EdaGitAdded', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'EdaGitAddedIcon', { link = 'EdaGitAdded' })
+    hl(0, 'EdaGitModified', { fg = '#bfb996', ctermfg = 144 })
+    hl(0, 'EdaGitModifiedIcon', { link = 'EdaGitModified' })
+    hl(0, 'EdaGitDeleted', { fg = '#c076c0', ctermfg = 176 })
+    hl(0, 'EdaGitDeletedIcon', { link = 'EdaGitDeleted' })
+    hl(0, 'EdaGitRenamed', { fg = '#bfb996', ctermfg = 144 })
+    hl(0, 'EdaGitRenamedIcon', { link = 'EdaGitRenamed' })
+    hl(0, 'EdaGitStaged', { fg = '#7cbe8c', ctermfg = 108 })
+    hl(0, 'EdaGitStagedIcon', { link = 'EdaGitStaged' })
+    hl(0, 'EdaGitConflict', { fg = '#c09b93', ctermfg = 181 })
+    hl(0, 'EdaGitConflictIcon', { link = 'EdaGitConflict' })
+    hl(0, 'EdaGitIgnored', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'EdaGitIgnoredIcon', { link = 'EdaGitIgnored' })
+    hl(0, 'EdaMarked', { fg = '#a8a384', ctermfg = 144, bold = true })
+    hl(0, 'EdaCut', { fg = '#565d8c', ctermfg = 60, italic = true })
+    hl(0, 'EdaOpDeleteSign', { fg = '#ff9494', ctermfg = 210, bold = true })
+    hl(0, 'EdaOpDeletePath', { fg = '#ff9494', ctermfg = 210 })
+    hl(0, 'EdaOpDeleteText', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'EdaOpCreateSign', { fg = '#73c1a9', ctermfg = 79, bold = true })
+    hl(0, 'EdaOpCreatePath', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'EdaOpCreateText', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'EdaOpMoveSign', { fg = '#a8a384', ctermfg = 144, bold = true })
+    hl(0, 'EdaOpMovePath', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'EdaOpMoveText', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'FernBranchSymbol', { fg = '#7079bf', ctermfg = 104 })
+    hl(0, 'FernBranchText', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'FernLeafSymbol', { fg = '#5c9b88', ctermfg = 72 })
+    hl(0, 'FernLeafText', { fg = '#9ea3c0', ctermfg = 146 })
+    hl(0, 'FernMarked', { fg = '#59b6b6', ctermfg = 73 })
+    hl(0, 'GitSignsAdd', { fg = '#7cbe8c', ctermfg = 108 })
+    hl(0, 'GitSignsChange', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'GitSignsDelete', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'GitSignsChangeDelete', { fg = '#387154', ctermfg = 29 })
+    hl(0, 'GitGutterAdd', { fg = '#7cbe8c', ctermfg = 108 })
+    hl(0, 'GitGutterChange', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'GitGutterDelete', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'GitGutterChangeDelete', { fg = '#387154', ctermfg = 29 })
+    hl(0, 'fugitiveHeader', { fg = '#73c1a9', ctermfg = 79, bold = true })
+    hl(0, 'DiffviewDim1', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'DiffviewPrimary', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'DiffviewSecondary', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'DiffviewStatusAdded', { fg = '#589ec6', ctermfg = 74 })
+    hl(0, 'DiffviewStatusUntracked', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'DiffviewStatusModified', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'DiffviewStatusRenamed', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'DiffviewStatusCopied', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'DiffviewStatusTypeChanged', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'DiffviewStatusUnmerged', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'DiffviewStatusUnknown', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'DiffviewStatusDeleted', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'DiffviewStatusBroken', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'DiffviewStatusIgnored', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'DiffviewFilePanelRootPath', { fg = '#7079bf', ctermfg = 104 })
+    hl(0, 'DiffviewFilePanelTitle', { fg = '#929be5', ctermfg = 104, bold = true })
+    hl(0, 'DiffviewFilePanelCounter', { fg = '#8186a6', ctermfg = 103, bold = true })
+    hl(0, 'DiffviewFilePanelFileName', { fg = '#9ea3c0', ctermfg = 146 })
+    hl(0, 'DiffviewFilePanelPath', { fg = '#565d8c', ctermfg = 60, bold = true })
+    hl(0, 'DiffviewFilePanelSelected', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'DiffviewFilePanelInsertions', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'DiffviewFilePanelDeletions', { fg = '#b871b8', ctermfg = 133 })
+    hl(0, 'DiffviewFilePanelConflicts', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'DiffviewHash', { fg = '#7079bf', ctermfg = 104 })
+    hl(0, 'ALEWarningSign', { fg = '#ac8b83', ctermfg = 138, bold = true })
+    hl(0, 'ALEInfoSign', { fg = '#82dabf', ctermfg = 115 })
+    hl(0, 'NullLsInfoBorder', { fg = '#565d8c', ctermfg = 60, bg = '#222433', ctermbg = 235 })
+    hl(0, 'CocErrorSign', { fg = '#ff9494', ctermfg = 210, bold = true })
+    hl(0, 'CocWarningSign', { fg = '#ac8b83', ctermfg = 138, bold = true })
+    hl(0, 'CocInfoSign', { fg = '#82dabf', ctermfg = 115, bold = true })
+    hl(0, 'CocHintSign', { fg = '#82dabf', ctermfg = 115, bold = true })
+    hl(0, 'LspError', { fg = '#ff9494', ctermfg = 210 })
+    hl(0, 'LspErrorText', { fg = '#ff9494', ctermfg = 210, bold = true })
+    hl(0, 'LspErrorHighlight', { sp = '#ff9494', undercurl = true })
+    hl(0, 'LspErrorVirtualText', { fg = '#ff9494', ctermfg = 210, bold = true })
+    hl(0, 'LspWarning', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'LspWarningText', { fg = '#ac8b83', ctermfg = 138, bold = true })
+    hl(0, 'LspWarningHighlight', { sp = '#ac8b83', undercurl = true })
+    hl(0, 'LspWarningVirtualText', { fg = '#ac8b83', ctermfg = 138, bold = true })
+    hl(0, 'LspInformation', { fg = '#82dabf', ctermfg = 115 })
+    hl(0, 'LspInformationText', { fg = '#82dabf', ctermfg = 115, bold = true })
+    hl(0, 'LspInformationHighlight', { sp = '#82dabf', undercurl = true })
+    hl(0, 'LspInformationVirtualText', { fg = '#565d8c', ctermfg = 60, bold = true })
+    hl(0, 'LspHint', { fg = '#82dabf', ctermfg = 115 })
+    hl(0, 'LspHintText', { fg = '#82dabf', ctermfg = 115, bold = true })
+    hl(0, 'LspHintHighlight', { sp = '#82dabf', undercurl = true })
+    hl(0, 'LspHintVirtualText', { fg = '#565d8c', ctermfg = 60, bold = true })
+    hl(0, 'LspCodeActionText', { fg = '#7079bf', ctermfg = 104, bold = true })
+    hl(0, 'CmpItemAbbr', { fg = '#9ea3c0', ctermfg = 146 })
+    hl(0, 'CmpItemAbbrMatch', { fg = '#929be5', ctermfg = 104, bold = true })
+    hl(0, 'CmpItemAbbrMatchFuzzy', { link = 'CmpItemAbbrMatch' })
+    hl(0, 'CmpItemAbbrDeprecated', { fg = '#565d8c', ctermfg = 60, strikethrough = true })
+    hl(0, 'CmpItemMenu', { fg = '#565d8c', ctermfg = 60, italic = true })
+    hl(0, 'CmpItemKind', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'CmpItemKindText', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'CmpItemKindVariable', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'CmpItemKindConstant', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'CmpItemKindEnum', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'CmpItemKindInterface', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'CmpItemKindClass', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'CmpItemKindFunction', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'CmpItemKindMethod', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'CmpItemKindModule', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'CmpItemKindConstructor', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'CmpItemKindKeyword', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'CmpItemKindProperty', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'CmpItemKindField', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'CmpItemKindUnit', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'BlinkCmpMenu', { fg = '#9ea3c0', ctermfg = 146 })
+    hl(0, 'BlinkCmpMenuSelection', { bg = '#363e7a', ctermbg = 60 })
+    hl(0, 'BlinkCmpLabelMatch', { fg = '#929be5', ctermfg = 104, bold = true })
+    hl(0, 'BlinkCmpLabelDeprecated', { fg = '#565d8c', ctermfg = 60, strikethrough = true })
+    hl(0, 'BlinkCmpKind', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'BlinkCmpKindText', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'BlinkCmpKindVariable', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'BlinkCmpKindConstant', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'BlinkCmpKindEnum', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'BlinkCmpKindInterface', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'BlinkCmpKindClass', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'BlinkCmpKindFunction', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'BlinkCmpKindMethod', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'BlinkCmpKindModule', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'BlinkCmpKindConstructor', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'BlinkCmpKindKeyword', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'BlinkCmpKindProperty', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'BlinkCmpKindField', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'BlinkCmpKindUnit', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'BlinkCmpSource', { fg = '#565d8c', ctermfg = 60, italic = true })
+    hl(0, 'BlinkCmpDocSeparator', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'BlinkCmpMenuBorder', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'BlinkCmpDocBorder', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'BlinkCmpSignatureHelpBorder', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'FloatTitle', { fg = '#929be5', ctermfg = 104, bg = '#222433', ctermbg = 235 })
+    hl(0, 'TelescopeNormal', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'TelescopeTitle', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'TelescopeMatching', { fg = '#bdc3e6', ctermfg = 189, bold = true })
+    hl(0, 'TelescopeBorder', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'TelescopePromptPrefix', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'TelescopePromptCounter', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'TelescopeMultiIcon', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'TelescopeMultiSelection', { fg = '#a8a384', ctermfg = 144 })
+    hl(0, 'SnacksNormal', { fg = '#8186a6', ctermfg = 103 })
+    hl(0, 'SnacksPickerPrompt', { fg = '#73c1a9', ctermfg = 79 })
+    hl(0, 'SnacksPickerMatch', { fg = '#929be5', ctermfg = 104 })
+    hl(0, 'SnacksPickerDir', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'CopilotSuggestion', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'CleverFChar', { fg = '#a7b1ff', ctermfg = 147, bg = '#6472e5', ctermbg = 63, underline = true })
+    hl(0, 'MiniJump', { fg = '#a7b1ff', ctermfg = 147, bg = '#6472e5', ctermbg = 63, underline = true })
+    hl(0, 'ConflictMarkerBegin', { bg = '#5c9b88', ctermbg = 72, bold = true })
+    hl(0, 'ConflictMarkerOurs', { bg = '#27473d', ctermbg = 23 })
+    hl(0, 'ConflictMarkerTheirs', { bg = '#1e3b4c', ctermbg = 237 })
+    hl(0, 'ConflictMarkerEnd', { bg = '#417593', ctermbg = 31, bold = true })
+    hl(0, 'ConflictMarkerSeparator', { fg = '#373b59', ctermfg = 60, bold = true })
+    hl(0, 'EasyMotionTarget', { fg = '#a8a384', ctermfg = 144, bold = true })
+    hl(0, 'EasyMotionShade', { fg = '#565d8c', ctermfg = 60, bg = '#222433', ctermbg = 235 })
+    hl(0, 'EasyMotionIncCursor', { fg = '#9ea3c0', ctermfg = 146, bg = '#222433', ctermbg = 235 })
+    hl(0, 'HopNextKey', { fg = '#73c1a9', ctermfg = 79, bold = true })
+    hl(0, 'HopNextKey1', { link = 'HopNextKey' })
+    hl(0, 'HopNextKey2', { fg = '#5c9b88', ctermfg = 72, bold = true })
+    hl(0, 'HopUnmatched', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'FlashPrompt', { fg = '#929be5', ctermfg = 104, bold = true })
+    hl(0, 'FlashPromptIcon', { fg = '#73c1a9', ctermfg = 79, bold = true })
+    hl(0, 'FlashLabel', { fg = '#73c1a9', ctermfg = 79, bold = true })
+    hl(0, 'FidgetTitle', { fg = '#73c1a9', ctermfg = 79, bold = true })
+    hl(0, 'FidgetTask', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'HlSearchLens', { fg = '#565d8c', ctermfg = 60, italic = true })
+    hl(0, 'HlSearchLensNear', { link = 'HlSearchLens' })
+    hl(0, 'NotifyBackground', { bg = '#222433', ctermbg = 235 })
+    hl(0, 'NotifyERRORBorder', { fg = '#cc8b8b', ctermfg = 174 })
+    hl(0, 'NotifyWARNBorder', { fg = '#796c68', ctermfg = 242 })
+    hl(0, 'NotifyINFOBorder', { fg = '#638e81', ctermfg = 66 })
+    hl(0, 'NotifyDEBUGBorder', { fg = '#82848d', ctermfg = 102 })
+    hl(0, 'NotifyTRACEBorder', { fg = '#638e81', ctermfg = 66 })
+    hl(0, 'NotifyERRORIcon', { fg = '#ff9494', ctermfg = 210 })
+    hl(0, 'NotifyWARNIcon', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'NotifyINFOIcon', { fg = '#82dabf', ctermfg = 115 })
+    hl(0, 'NotifyDEBUGIcon', { fg = '#9ea3c0', ctermfg = 146 })
+    hl(0, 'NotifyTRACEIcon', { fg = '#82dabf', ctermfg = 115 })
+    hl(0, 'NotifyERRORTitle', { fg = '#ff9494', ctermfg = 210 })
+    hl(0, 'NotifyWARNTitle', { fg = '#ac8b83', ctermfg = 138 })
+    hl(0, 'NotifyINFOTitle', { fg = '#82dabf', ctermfg = 115 })
+    hl(0, 'NotifyDEBUGTitle', { fg = '#9ea3c0', ctermfg = 146 })
+    hl(0, 'NotifyTRACETitle', { fg = '#82dabf', ctermfg = 115 })
+    hl(0, 'NotifyERRORBody', { fg = '#9ea3c0', ctermfg = 146 })
+    hl(0, 'NotifyWARNBody', { fg = '#9ea3c0', ctermfg = 146 })
+    hl(0, 'NotifyINFOBody', { fg = '#9ea3c0', ctermfg = 146 })
+    hl(0, 'NotifyDEBUGBody', { fg = '#9ea3c0', ctermfg = 146 })
+    hl(0, 'NotifyTRACEBody', { fg = '#9ea3c0', ctermfg = 146 })
+    hl(0, 'AvanteTitle', { fg = '#222433', ctermfg = 235, bg = '#929be5', ctermbg = 104 })
+    hl(0, 'AvanteReversedTitle', { fg = '#929be5', ctermfg = 104, bg = '#222433', ctermbg = 235 })
+    hl(0, 'AvanteSubtitle', { fg = '#222433', ctermfg = 235, bg = '#73c1a9', ctermbg = 79 })
+    hl(0, 'AvanteReversedSubtitle', { fg = '#73c1a9', ctermfg = 79, bg = '#222433', ctermbg = 235 })
+    hl(0, 'AvanteThirdTitle', { fg = '#9ea3c0', ctermfg = 146, bg = '#33364d', ctermbg = 237 })
+    hl(0, 'AvanteReversedThirdTitle', { fg = '#33364d', ctermfg = 237, bg = '#222433', ctermbg = 235 })
+    hl(0, 'AvantePopupHint', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'AvanteInlineHint', { fg = '#565d8c', ctermfg = 60 })
+    hl(0, 'AvanteSidebarWinSeparator', { fg = '#373b59', ctermfg = 60 })
+    hl(0, 'AvanteSidebarWinHorizontalSeparator', { fg = '#222433', ctermfg = 235, bg = '#222433', ctermbg = 235 })
+    if vim.fn.has('nvim-0.8.0') == 1 then
+      hl(0, '@string', { fg = '#7cbe8c', ctermfg = 108 })
+      hl(0, '@string.regex', { link = '@string' })
+      hl(0, '@string.escape', { fg = '#b871b8', ctermfg = 133 })
+      hl(0, '@string.special.url', { fg = '#565d8c', ctermfg = 60 })
+      hl(0, '@text.title', { fg = '#a8a384', ctermfg = 144, bold = true })
+      hl(0, '@text.reference', { fg = '#929be5', ctermfg = 104 })
+      hl(0, '@text.uri', { fg = '#565d8c', ctermfg = 60 })
+      hl(0, '@text.strong', { bold = true })
+      hl(0, '@text.literal', { fg = '#73c1a9', ctermfg = 79 })
+      hl(0, '@parameter', { fg = '#929be5', ctermfg = 104 })
+      hl(0, '@property', { fg = '#929be5', ctermfg = 104 })
+      hl(0, '@keyword', { fg = '#b871b8', ctermfg = 133 })
+      hl(0, '@operator', { fg = '#929be5', ctermfg = 104 })
+      hl(0, '@module', { fg = '#9ea3c0', ctermfg = 146 })
+      hl(0, '@type', { fg = '#ac8b83', ctermfg = 138 })
+      hl(0, '@type.builtin', { link = '@type' })
+      hl(0, '@function.tsx', { fg = '#9ea3c0', ctermfg = 146 })
+      hl(0, '@punctuation.special.typescript', { fg = '#8186a6', ctermfg = 103 })
+      hl(0, '@include', { fg = '#929be5', ctermfg = 104 })
+      hl(0, '@variable', { fg = '#9ea3c0', ctermfg = 146 })
+      hl(0, '@variable.builtin', { fg = '#ac8b83', ctermfg = 138 })
+      hl(0, '@constant.builtin', { fg = '#73c1a9', ctermfg = 79 })
+      hl(0, '@constructor', { fg = '#9ea3c0', ctermfg = 146 })
+      hl(0, '@tag', { fg = '#9ea3c0', ctermfg = 146 })
+      hl(0, '@tag.delimiter', { fg = '#929be5', ctermfg = 104 })
+      hl(0, '@tag.attribute', { fg = '#929be5', ctermfg = 104 })
+      hl(0, '@tag.builtin.tsx', { fg = '#9ea3c0', ctermfg = 146 })
+      hl(0, '@markup.heading', { fg = '#a8a384', ctermfg = 144, bold = true })
+      hl(0, '@markup.strong', { bold = true })
+      hl(0, '@markup.list', { fg = '#565d8c', ctermfg = 60 })
+      hl(0, '@markup.raw', { fg = '#73c1a9', ctermfg = 79 })
+      hl(0, '@markup.link', { fg = '#929be5', ctermfg = 104 })
+      hl(0, '@markup.link.url', { fg = '#8186a6', ctermfg = 103 })
+      hl(0, '@markup.quote', { fg = '#565d8c', ctermfg = 60 })
+      hl(0, '@lsp.type.class', { fg = '#9ea3c0', ctermfg = 146 })
+      hl(0, '@lsp.type.interface', { fg = '#ac8b83', ctermfg = 138 })
+      hl(0, '@lsp.type.parameter', { fg = '#929be5', ctermfg = 104 })
+      hl(0, '@lsp.type.property', { fg = '#929be5', ctermfg = 104 })
+      hl(0, '@lsp.type.struct', { fg = '#9ea3c0', ctermfg = 146 })
+      hl(0, '@lsp.type.type', { fg = '#ac8b83', ctermfg = 138 })
+      hl(0, '@lsp.type.typeParameter', { fg = '#9ea3c0', ctermfg = 146 })
+      hl(0, '@lsp.type.variable', { fg = '#9ea3c0', ctermfg = 146 })
+      hl(0, '@lsp.type.member', { fg = '#929be5', ctermfg = 104 })
+      hl(0, '@lsp.type.namespace', { fg = '#9ea3c0', ctermfg = 146 })
+    end
+    vim.g.terminal_color_0 = '#11121a'
+    vim.g.terminal_color_1 = '#e68585'
+    vim.g.terminal_color_2 = '#7cbe8c'
+    vim.g.terminal_color_3 = '#8f8a70'
+    vim.g.terminal_color_4 = '#4d8aad'
+    vim.g.terminal_color_5 = '#6d78cc'
+    vim.g.terminal_color_6 = '#73c1a9'
+    vim.g.terminal_color_7 = '#9ea3c0'
+    vim.g.terminal_color_8 = '#565d8c'
+    vim.g.terminal_color_9 = '#b871b8'
+    vim.g.terminal_color_10 = '#7cbe8c'
+    vim.g.terminal_color_11 = '#a8a384'
+    vim.g.terminal_color_12 = '#589ec6'
+    vim.g.terminal_color_13 = '#929be5'
+    vim.g.terminal_color_14 = '#59b6b6'
+    vim.g.terminal_color_15 = '#9ea3c0'
+    vim.g.terminal_color_background = vim.g.terminal_color_0
+    vim.g.terminal_color_foreground = vim.g.terminal_color_7
+    vim.g.defx_icons_gui_colors = {
+      brown = 'cc4e4e',
+      aqua = '5c9d9d',
+      blue = '5e90ad',
+      darkBlue = '577586',
+      purple = '7079bf',
+      lightPurple = '969ccc',
+      red = 'e68585',
+      beige = '686867',
+      yellow = '8f8a70',
+      orange = 'c6a096',
+      darkOrange = '795850',
+      pink = '9f619f',
+      salmon = 'ab58ab',
+      green = '639870',
+      lightGreen = '5ba56d',
+      white = '898da7',
+    }
+    vim.g.defx_icons_term_colors = {
+      brown = 167,
+      aqua = 73,
+      blue = 67,
+      darkBlue = 67,
+      purple = 104,
+      lightPurple = 146,
+      red = 174,
+      beige = 242,
+      yellow = 101,
+      orange = 181,
+      darkOrange = 95,
+      pink = 133,
+      salmon = 133,
+      green = 65,
+      lightGreen = 71,
+      white = 103,
+    }
+  end
+
+  vim.g.fzf_colors = {
+    ['fg'] = { 'fg', 'Normal' },
+    ['bg'] = { 'bg', 'Normal' },
+    ['hl'] = { 'fg', 'Comment' },
+    ['fg+'] = { 'fg', 'CursorLine' },
+    ['bg+'] = { 'bg', 'CursorLine' },
+    ['hl+'] = { 'fg', 'Statement' },
+    ['info'] = { 'fg', 'Comment' },
+    ['gutter'] = { 'bg', 'Normal' },
+    ['border'] = { 'fg', 'Ignore' },
+    ['prompt'] = { 'fg', 'Label' },
+    ['pointer'] = { 'fg', 'Boolean' },
+    ['marker'] = { 'fg', 'Boolean' },
+    ['spinner'] = { 'fg', 'Title' },
+    ['header'] = { 'fg', 'Comment' },
+  }
+end
+
+return M
@@ -0,0 +1,256 @@
+-- dogrun palette
+--
+-- Author: wadackel
+-- License: MIT
+--   Copyright (c) 2020 wadackel
+--
+-- local palette = require('dogrun.palette')
+-- palette.purple       -- color for the current 'background'
+-- palette.dark.purple  -- color of the dark variant
+-- palette.light.purple -- color of the light variant
+
+local palettes = {}
+
+palettes.dark = {
+  black = '#000000',
+  blue = '#589ec6',
+  cursorlinebg = '#2b2e41',
+  cursorlinenrfg = '#575f99',
+  cyan = '#59b6b6',
+  darkbg = '#2b2d40',
+  darkblue = '#417593',
+  darkcyan = '#408383',
+  darkestblue = '#1e3b4c',
+  darkestpurple = '#484d7a',
+  darkestteal = '#27473d',
+  darkfg = '#373b59',
+  darkgreen = '#3e5844',
+  darkorange = '#937c77',
+  darkpink = '#5f3f5f',
+  darkpurple = '#7079bf',
+  darkred = '#cc7676',
+  darkteal = '#5c9b88',
+  darkyellow = '#8f8d85',
+  debugbg = '#222433',
+  debugborder = '#82848d',
+  debugfg = '#9ea3c0',
+  defxiconaqua = '#5c9d9d',
+  defxiconbeige = '#686867',
+  defxiconblue = '#5e90ad',
+  defxiconbrown = '#cc4e4e',
+  defxicondarkblue = '#577586',
+  defxicondarkorange = '#795850',
+  defxicongreen = '#639870',
+  defxiconlightgreen = '#5ba56d',
+  defxiconlightpurple = '#969ccc',
+  defxiconorange = '#c6a096',
+  defxiconpink = '#9f619f',
+  defxiconpurple = '#7079bf',
+  defxiconred = '#e68585',
+  defxiconsalmon = '#ab58ab',
+  defxiconwhite = '#898da7',
+  defxiconyellow = '#8f8a70',
+  diffaddbg = '#1d3350',
+  diffchangebg = '#2a4b48',
+  diffdeletebg = '#4c325b',
+  diffdeletefg = '#735a81',
+  difftextbg = '#387154',
+  emphasisbg = '#2b2d40',
+  emphasisfg = '#bdc3e6',
+  errorbg = '#222433',
+  errorborder = '#cc8b8b',
+  errorfg = '#ff9494',
+  foldbg = '#33364d',
+  foldfg = '#666c99',
+  green = '#7cbe8c',
+  incsearchbg = '#4957cc',
+  incsearchfg = '#a7b8ff',
+  infobg = '#222433',
+  infoborder = '#638e81',
+  infofg = '#82dabf',
+  lightbg = '#444866',
+  lightfg = '#8186a6',
+  lightorange = '#c09b93',
+  lightpink = '#c076c0',
+  lightyellow = '#bfb996',
+  linenrfg = '#33364d',
+  mainbg = '#222433',
+  mainfg = '#9ea3c0',
+  matchparenbg = '#2f3248',
+  morefg = '#73c1a9',
+  orange = '#ac8b83',
+  pink = '#b871b8',
+  pmenubar = '#2b2d40',
+  pmenubg = '#33364d',
+  pmenufg = '#9ea3c0',
+  pmenumatchfg = '#929be5',
+  pmenuselbg = '#363e7a',
+  pmenuselfg = '#9ea3c0',
+  pmenuthumb = '#484f80',
+  purple = '#929be5',
+  red = '#ff9494',
+  searchbg = '#6472e5',
+  searchfg = '#a7b1ff',
+  statuslinebg = '#2b2d40',
+  statuslinefg = '#777ca6',
+  statuslinencbg = '#292b3b',
+  statuslinencfg = '#4c506e',
+  tablineselbg = '#929be5',
+  tablineselfg = '#222433',
+  teal = '#73c1a9',
+  termaqua = '#59b6b6',
+  termblack = '#11121a',
+  termblue = '#589ec6',
+  termfuchsia = '#929be5',
+  termgray = '#565d8c',
+  termgreen = '#7cbe8c',
+  termlime = '#7cbe8c',
+  termmaroon = '#e68585',
+  termnavy = '#4d8aad',
+  termolive = '#8f8a70',
+  termpurple = '#6d78cc',
+  termred = '#b871b8',
+  termsilver = '#9ea3c0',
+  termteal = '#73c1a9',
+  termwhite = '#9ea3c0',
+  termyellow = '#a8a384',
+  visualbg = '#363e7a',
+  warningbg = '#222433',
+  warningborder = '#796c68',
+  warningfg = '#ac8b83',
+  weakbg = '#33364d',
+  weakfg = '#565d8c',
+  white = '#ffffff',
+  xlinebg = '#292b3b',
+  xlineedgebg = '#2b2d40',
+  xlineedgefg = '#777ca6',
+  xlinefg = '#4c506e',
+  xlinegradientbg = '#292b3b',
+  xlinegradientfg = '#4c506e',
+  yellow = '#a8a384',
+}
+
+palettes.light = {
+  black = '#000000',
+  blue = '#006085',
+  cursorlinebg = '#c4c6df',
+  cursorlinenrfg = '#8187c4',
+  cyan = '#005556',
+  darkbg = '#c6c7e0',
+  darkblue = '#5285a3',
+  darkcyan = '#3d8080',
+  darkestblue = '#a4c1d6',
+  darkestpurple = '#9a9ccf',
+  darkestteal = '#96baad',
+  darkfg = '#b2b4d8',
+  darkgreen = '#89a58f',
+  darkorange = '#7e6863',
+  darkpink = '#c19cc0',
+  darkpurple = '#616bb0',
+  darkred = '#98484a',
+  darkteal = '#2b6a59',
+  darkyellow = '#64625a',
+  debugbg = '#d3d4e8',
+  debugborder = '#686a72',
+  debugfg = '#464b65',
+  defxiconaqua = '#226667',
+  defxiconbeige = '#868685',
+  defxiconblue = '#366a86',
+  defxiconbrown = '#c94b4c',
+  defxicondarkblue = '#638193',
+  defxicondarkorange = '#ab877e',
+  defxicongreen = '#396d48',
+  defxiconlightgreen = '#196734',
+  defxiconlightpurple = '#474f79',
+  defxiconorange = '#614139',
+  defxiconpink = '#9f619f',
+  defxiconpurple = '#616bb0',
+  defxiconred = '#893338',
+  defxiconsalmon = '#aa57aa',
+  defxiconwhite = '#5b5f77',
+  defxiconyellow = '#6a654d',
+  diffaddbg = '#b2c5ea',
+  diffchangebg = '#90b4b0',
+  diffdeletebg = '#ccacdc',
+  diffdeletefg = '#9c82ab',
+  difftextbg = '#5a9475',
+  emphasisbg = '#c6c7e0',
+  emphasisfg = '#29314c',
+  errorbg = '#d3d4e8',
+  errorborder = '#7d4445',
+  errorfg = '#7a1e28',
+  foldbg = '#babbd8',
+  foldfg = '#777caa',
+  green = '#044e25',
+  incsearchbg = '#7a7ef9',
+  incsearchfg = '#153771',
+  infobg = '#d3d4e8',
+  infoborder = '#477165',
+  infofg = '#003826',
+  lightbg = '#a2a5c8',
+  lightfg = '#606584',
+  lightorange = '#65463f',
+  lightpink = '#8a448b',
+  lightyellow = '#403d1f',
+  linenrfg = '#babbd8',
+  mainbg = '#d3d4e8',
+  mainfg = '#464b65',
+  matchparenbg = '#bfc1dc',
+  morefg = '#004b39',
+  orange = '#74564f',
+  pink = '#8f4b90',
+  pmenubar = '#c6c7e0',
+  pmenubg = '#babbd8',
+  pmenufg = '#464b65',
+  pmenumatchfg = '#3c4c8d',
+  pmenuselbg = '#a8a9f0',
+  pmenuselfg = '#464b65',
+  pmenuthumb = '#959ad0',
+  purple = '#3c4c8d',
+  red = '#7a1e28',
+  searchbg = '#5667d8',
+  searchfg = '#213a7a',
+  statuslinebg = '#c6c7e0',
+  statuslinefg = '#676d96',
+  statuslinencbg = '#c9cbdf',
+  statuslinencfg = '#999cbe',
+  tablineselbg = '#3c4c8d',
+  tablineselfg = '#d3d4e8',
+  teal = '#004b39',
+  termaqua = '#005556',
+  termblack = '#edeef9',
+  termblue = '#006085',
+  termfuchsia = '#3c4c8d',
+  termgray = '#868bbe',
+  termgreen = '#044e25',
+  termlime = '#044e25',
+  termmaroon = '#893338',
+  termnavy = '#317193',
+  termolive = '#6a654d',
+  termpurple = '#5c69bb',
+  termred = '#8f4b90',
+  termsilver = '#464b65',
+  termteal = '#004b39',
+  termwhite = '#464b65',
+  termyellow = '#534f34',
+  visualbg = '#a8a9f0',
+  warningbg = '#d3d4e8',
+  warningborder = '#8a7d78',
+  warningfg = '#74564f',
+  weakbg = '#babbd8',
+  weakfg = '#868bbe',
+  white = '#ffffff',
+  xlinebg = '#c9cbdf',
+  xlineedgebg = '#c6c7e0',
+  xlineedgefg = '#676d96',
+  xlinefg = '#999cbe',
+  xlinegradientbg = '#c9cbdf',
+  xlinegradientfg = '#999cbe',
+  yellow = '#534f34',
+}
+
+return setmetatable({ dark = palettes.dark, light = palettes.light }, {
+  __index = function(_, key)
+    return palettes[vim.o.background == 'light' and 'light' or 'dark'][key]
+  end,
+})